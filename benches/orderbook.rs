@@ -3,7 +3,7 @@
 //! Run with: `cargo bench`
 
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use kalshi_trading::orderbook::Orderbook;
+use kalshi_trading::orderbook::{ArrayOrderbook, Orderbook};
 use kalshi_trading::types::order::Side;
 
 fn bench_orderbook_delta(c: &mut Criterion) {
@@ -65,10 +65,61 @@ fn bench_orderbook_spread(c: &mut Criterion) {
     });
 }
 
+fn bench_backend_comparison(c: &mut Criterion) {
+    let mut group = c.benchmark_group("orderbook_backend");
+
+    // A realistic ladder: 200 levels a penny apart on each side
+    let mut tree = Orderbook::new("BENCH");
+    let mut array = ArrayOrderbook::with_backend("BENCH");
+    for i in 0..200i64 {
+        tree.set_level(3_000 + i * 10, 100 + i, Side::Yes);
+        tree.set_level(5_100 + i * 10, 100 + i, Side::No);
+        array.set_level(3_000 + i * 10, 100 + i, Side::Yes);
+        array.set_level(5_100 + i * 10, 100 + i, Side::No);
+    }
+
+    group.bench_function("btree_delta", |b| {
+        b.iter(|| {
+            tree.apply_delta(black_box(4_500), black_box(10), black_box(Side::Yes));
+            tree.apply_delta(black_box(4_500), black_box(-10), black_box(Side::Yes));
+        });
+    });
+    group.bench_function("array_delta", |b| {
+        b.iter(|| {
+            array.apply_delta(black_box(4_500), black_box(10), black_box(Side::Yes));
+            array.apply_delta(black_box(4_500), black_box(-10), black_box(Side::Yes));
+        });
+    });
+
+    // Remove and restore the best bid, forcing a best-price update
+    group.bench_function("btree_replace_best", |b| {
+        b.iter(|| {
+            tree.set_level(black_box(4_990), 0, Side::Yes);
+            tree.set_level(black_box(4_990), 299, Side::Yes);
+        });
+    });
+    group.bench_function("array_replace_best", |b| {
+        b.iter(|| {
+            array.set_level(black_box(4_990), 0, Side::Yes);
+            array.set_level(black_box(4_990), 299, Side::Yes);
+        });
+    });
+
+    group.bench_function("btree_top_of_book", |b| {
+        b.iter(|| black_box(tree.top_of_book()));
+    });
+    group.bench_function("array_top_of_book", |b| {
+        b.iter(|| black_box(array.top_of_book()));
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_orderbook_delta,
     bench_orderbook_best_bid,
-    bench_orderbook_spread
+    bench_orderbook_spread,
+    bench_backend_comparison
 );
 criterion_main!(benches);
@@ -7,31 +7,18 @@
 //!   KALSHI_ENV=demo  # Use demo environment (default: production)
 
 use kalshi_trading::config::Environment;
+use kalshi_trading::types::PriceExt;
 use kalshi_trading::{Config, KalshiClient};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // Read credentials from environment
-    let api_key = std::env::var("KALSHI_API_KEY").expect("Set KALSHI_API_KEY environment variable");
-    let key_path = std::env::var("KALSHI_PRIVATE_KEY_PATH")
-        .expect("Set KALSHI_PRIVATE_KEY_PATH environment variable");
+    // Read credentials and environment from KALSHI_* variables
+    let config = Config::from_env()?;
 
-    let private_key = std::fs::read_to_string(&key_path)?;
-
-    // Determine environment (default to production)
-    let env = match std::env::var("KALSHI_ENV")
-        .unwrap_or_default()
-        .to_lowercase()
-        .as_str()
-    {
-        "demo" => Environment::Demo,
-        _ => Environment::Production,
-    };
-
-    println!("API Key: {}", api_key);
+    println!("API Key: {}", config.api_key_id());
     println!(
         "Key format: {}",
-        if private_key.contains("BEGIN RSA PRIVATE KEY") {
+        if config.private_key_pem().contains("BEGIN RSA PRIVATE KEY") {
             "PKCS#1"
         } else {
             "PKCS#8"
@@ -39,15 +26,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     );
     println!(
         "Environment: {}",
-        match env {
+        match config.environment() {
             Environment::Demo => "Demo",
             Environment::Production => "Production",
         }
     );
 
-    // Create config
-    let config = Config::new(&api_key, &private_key).with_environment(env);
-
     println!("Base URL: {}", config.rest_base_url());
 
     // Create client
@@ -70,7 +54,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Ok(balance) => println!(
             "Balance: {} centi-cents (${:.2})",
             balance.balance,
-            balance.balance as f64 / 10000.0
+            balance.balance.to_dollars()
         ),
         Err(e) => println!("Auth error: {}", e),
     }
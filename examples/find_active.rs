@@ -5,11 +5,7 @@ use kalshi_trading::{Config, KalshiClient};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let api_key = std::env::var("KALSHI_API_KEY")?;
-    let key_path = std::env::var("KALSHI_PRIVATE_KEY_PATH")?;
-    let private_key = std::fs::read_to_string(&key_path)?;
-
-    let config = Config::new(&api_key, &private_key).with_environment(Environment::Production);
+    let config = Config::from_env()?.with_environment(Environment::Production);
     let client = KalshiClient::new(config)?;
 
     let markets = client.rest().get_markets(Some("open"), None, None).await?;
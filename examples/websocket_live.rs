@@ -8,7 +8,6 @@
 //!   KALSHI_TICKER=TICKER  # Specific market ticker (default: auto-selects active market)
 
 use kalshi_trading::client::websocket::WebSocketClient;
-use kalshi_trading::config::Environment;
 use kalshi_trading::orderbook::Orderbook;
 use kalshi_trading::types::messages::WsMessage;
 use kalshi_trading::types::{format_count, format_dollars, parse_count, parse_dollars};
@@ -25,28 +24,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         )
         .init();
 
-    // Read credentials from environment
-    let api_key = std::env::var("KALSHI_API_KEY").expect("Set KALSHI_API_KEY environment variable");
-    let key_path = std::env::var("KALSHI_PRIVATE_KEY_PATH")
-        .expect("Set KALSHI_PRIVATE_KEY_PATH environment variable");
-
-    let private_key = std::fs::read_to_string(&key_path)?;
-
-    // Determine environment
-    let env = match std::env::var("KALSHI_ENV")
-        .unwrap_or_default()
-        .to_lowercase()
-        .as_str()
-    {
-        "demo" => Environment::Demo,
-        _ => Environment::Production,
-    };
+    // Read credentials and environment from KALSHI_* variables
+    let config = Config::from_env()?;
 
     println!("=== Kalshi WebSocket Live Test ===\n");
 
     // Create REST client to find an active market
-    let config = Config::new(&api_key, &private_key).with_environment(env.clone());
-    let rest_client = KalshiClient::new(config)?;
+    let rest_client = KalshiClient::new(config.clone())?;
 
     // Get ticker from env or find an active market
     let ticker = match std::env::var("KALSHI_TICKER") {
@@ -82,17 +66,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("\nConnecting to WebSocket...");
 
-    // Create WebSocket config
-    let ws_config = Config::new(&api_key, &private_key).with_environment(env);
-
     // Connect to WebSocket
-    let mut ws_client = WebSocketClient::connect(&ws_config).await?;
+    let mut ws_client = WebSocketClient::connect(&config).await?;
     println!("Connected!\n");
 
     // Subscribe to orderbook deltas for the selected market
     println!("Subscribing to orderbook for {}...", ticker);
-    let sid = ws_client.subscribe_orderbook(&[&ticker]).await?;
-    println!("Subscribed with sid: {}\n", sid);
+    let msg_ids = ws_client.subscribe_orderbook(&[&ticker]).await?;
+    println!("Subscribe sent with message ids: {:?}\n", msg_ids);
 
     // Also subscribe to ticker updates
     println!("Subscribing to ticker...");
@@ -214,6 +195,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         println!("[UNSUBSCRIBED] sid: {}", unsub.sid);
                     }
 
+                    WsMessage::SubscriptionsList(list) => {
+                        for sub in &list.subscriptions {
+                            println!("[SUBSCRIPTION] sid: {}, channel: {}", sub.sid, sub.channel);
+                        }
+                    }
+
+                    WsMessage::SubscriptionUpdated(update) => {
+                        println!(
+                            "[UPDATED] sid: {:?}, markets: {:?}",
+                            update.sid, update.market_tickers
+                        );
+                    }
+
                     _ => {
                         // Other message types
                     }
@@ -0,0 +1,99 @@
+//! Activity totals across a set of markets.
+
+use crate::types::market::Market;
+use crate::types::{Quantity, COUNT_SCALE};
+
+/// Volume and open interest summed over a set of markets.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivitySummary {
+    /// Total contracts traded (scaled by 100)
+    pub volume_fp: Quantity,
+    /// Total open interest (scaled by 100)
+    pub open_interest_fp: Quantity,
+    /// Total volume valued at each market's last price, in ten-thousandths
+    /// of a dollar
+    ///
+    /// An estimate: markets report contract volume only, and those without
+    /// a last price contribute nothing.
+    pub dollar_volume: i64,
+    /// Ticker of the market with the highest volume, the first one on ties
+    ///
+    /// `None` if no market has traded.
+    pub most_active: Option<String>,
+}
+
+/// Sum volume, open interest and dollar volume across markets and find the
+/// most active one.
+///
+/// Missing volume or open interest counts as zero.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::analytics::market_activity_summary;
+///
+/// let summary = market_activity_summary(&[]);
+/// assert_eq!(summary.volume_fp, 0);
+/// assert_eq!(summary.most_active, None);
+/// ```
+#[must_use]
+pub fn market_activity_summary(markets: &[Market]) -> ActivitySummary {
+    let mut summary = ActivitySummary::default();
+    let mut dollar_volume = 0i128;
+    let mut top: Option<(&Market, Quantity)> = None;
+
+    for market in markets {
+        let volume = market.volume_fp.unwrap_or(0);
+        summary.volume_fp += volume;
+        summary.open_interest_fp += market.open_interest_fp.unwrap_or(0);
+        if let Some(price) = market.last_price_dollars {
+            dollar_volume += i128::from(volume) * i128::from(price);
+        }
+        if volume > 0 && top.map_or(true, |(_, best)| volume > best) {
+            top = Some((market, volume));
+        }
+    }
+
+    let scale = i128::from(COUNT_SCALE);
+    summary.dollar_volume = ((dollar_volume + scale / 2) / scale) as i64;
+    summary.most_active = top.map(|(market, _)| market.ticker.clone());
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::testing::market_json;
+    use crate::types::{parse_count, parse_dollars};
+
+    fn market(ticker: &str, volume: Option<&str>, open_interest: &str, last: &str) -> Market {
+        let mut market: Market = serde_json::from_str(&market_json(ticker, "EV")).unwrap();
+        market.volume_fp = volume.map(|v| parse_count(v).unwrap());
+        market.open_interest_fp = Some(parse_count(open_interest).unwrap());
+        market.last_price_dollars = Some(parse_dollars(last).unwrap());
+        market
+    }
+
+    #[test]
+    fn test_market_activity_summary() {
+        let markets = [
+            market("A", Some("100.00"), "40.00", "0.2500"),
+            market("B", Some("250.00"), "10.00", "0.6000"),
+            market("C", None, "5.00", "0.9000"),
+            market("D", Some("250.00"), "0.00", "0.1000"),
+        ];
+
+        let summary = market_activity_summary(&markets);
+        assert_eq!(summary.volume_fp, 60_000);
+        assert_eq!(summary.open_interest_fp, 5_500);
+        // 100 * $0.25 + 250 * $0.60 + 250 * $0.10 = $200
+        assert_eq!(summary.dollar_volume, 2_000_000);
+        // B and D tie on volume; the first wins
+        assert_eq!(summary.most_active.as_deref(), Some("B"));
+
+        let quiet = [market("Q", Some("0.00"), "1.00", "0.5000")];
+        let summary = market_activity_summary(&quiet);
+        assert_eq!(summary.open_interest_fp, 100);
+        assert_eq!(summary.most_active, None);
+    }
+}
@@ -0,0 +1,79 @@
+//! Fee totals split by maker and taker fills.
+
+use crate::types::order::Order;
+
+/// Fees paid across a set of orders, in ten-thousandths of a dollar.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    /// Fees on fills where the order rested on the book
+    pub maker_fees: i64,
+    /// Fees on fills where the order took liquidity
+    pub taker_fees: i64,
+}
+
+impl FeeBreakdown {
+    /// Total fees paid
+    #[must_use]
+    pub const fn total(&self) -> i64 {
+        self.maker_fees + self.taker_fees
+    }
+
+    /// Fraction of fees paid as maker, from 0.0 to 1.0
+    ///
+    /// Returns `None` if no fees were paid.
+    #[must_use]
+    pub fn maker_ratio(&self) -> Option<f64> {
+        let total = self.total();
+        (total != 0).then(|| self.maker_fees as f64 / total as f64)
+    }
+}
+
+/// Sum maker and taker fees across orders.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::analytics::fee_attribution;
+///
+/// let fees = fee_attribution(&[]);
+/// assert_eq!(fees.total(), 0);
+/// assert_eq!(fees.maker_ratio(), None);
+/// ```
+#[must_use]
+pub fn fee_attribution(orders: &[Order]) -> FeeBreakdown {
+    orders
+        .iter()
+        .fold(FeeBreakdown::default(), |acc, order| FeeBreakdown {
+            maker_fees: acc.maker_fees + order.maker_fees_dollars,
+            taker_fees: acc.taker_fees + order.taker_fees_dollars,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::testing::order_json;
+
+    fn order(maker_fees: &str, taker_fees: &str) -> Order {
+        let mut order = order_json("o1", "10.00", "0.00", Some("10.00"));
+        order["status"] = "executed".into();
+        order["maker_fees_dollars"] = maker_fees.into();
+        order["taker_fees_dollars"] = taker_fees.into();
+        serde_json::from_value(order).unwrap()
+    }
+
+    #[test]
+    fn test_fee_attribution() {
+        let orders = [
+            order("0.0200", "0.0000"),
+            order("0.0000", "0.0700"),
+            order("0.0100", "0.0200"),
+        ];
+
+        let fees = fee_attribution(&orders);
+        assert_eq!(fees.maker_fees, 300);
+        assert_eq!(fees.taker_fees, 900);
+        assert_eq!(fees.total(), 1_200);
+        assert_eq!(fees.maker_ratio(), Some(0.25));
+    }
+}
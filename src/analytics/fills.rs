@@ -0,0 +1,68 @@
+//! Fill rate across submitted orders.
+
+use crate::types::order::Order;
+
+/// Fraction of ordered quantity that filled, from 0.0 to 1.0.
+///
+/// Divides the summed `fill_count_fp` by the summed `initial_count_fp`. An
+/// order reporting no initial count (zero, or absent from the response) is
+/// sized as its filled plus remaining quantity instead. Returns 0.0 if
+/// nothing was ordered.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::analytics::fill_rate;
+///
+/// assert_eq!(fill_rate(&[]), 0.0);
+/// ```
+#[must_use]
+pub fn fill_rate(orders: &[Order]) -> f64 {
+    let (filled, ordered) = orders
+        .iter()
+        .fold((0i64, 0i64), |(filled, ordered), order| {
+            let initial = if order.initial_count_fp > 0 {
+                order.initial_count_fp
+            } else {
+                order.fill_count_fp + order.remaining_count_fp
+            };
+            (filled + order.fill_count_fp, ordered + initial)
+        });
+
+    if ordered > 0 {
+        filled as f64 / ordered as f64
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::testing::order_json;
+
+    fn order(filled: &str, remaining: &str, initial: Option<&str>) -> Order {
+        serde_json::from_value(order_json("o1", filled, remaining, initial)).unwrap()
+    }
+
+    #[test]
+    fn test_fill_rate() {
+        let orders = [
+            order("10.00", "0.00", Some("10.00")),
+            order("5.00", "15.00", Some("20.00")),
+            order("0.00", "10.00", Some("10.00")),
+        ];
+        // 15 of 40 contracts filled
+        assert_eq!(fill_rate(&orders), 0.375);
+
+        // Without an initial count, filled + remaining sizes the order
+        let orders = [
+            order("10.00", "0.00", Some("10.00")),
+            order("6.00", "4.00", None),
+        ];
+        assert_eq!(fill_rate(&orders), 0.8);
+
+        let unfilled = [order("0.00", "0.00", Some("0.00"))];
+        assert_eq!(fill_rate(&unfilled), 0.0);
+    }
+}
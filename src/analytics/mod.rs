@@ -0,0 +1,44 @@
+//! Trading analytics built on top of the API types.
+//!
+//! These helpers consume data you already receive (fills, trades, orders,
+//! markets) and derive running statistics locally, without extra API calls.
+//!
+//! # Components
+//!
+//! - [`market_activity_summary`] - Volume and open interest totals across markets
+//! - [`fill_rate`] - Fraction of ordered quantity that filled
+//! - [`fee_attribution`] - Maker vs taker fee totals across orders
+//! - [`PositionBuilder`] - Live net position and average entry from fills
+//! - [`realized_slippage`] - Volume-weighted fill prices against a decision price
+//! - [`TradeTape`] - Recent public trades and effective spread
+//! - [`TickerCache`] - Latest last/bid/ask/volume per market from ticker updates
+//!
+//! # Example
+//!
+//! ```rust
+//! use kalshi_trading::analytics::PositionBuilder;
+//!
+//! let mut position = PositionBuilder::new("KXBTC-25JAN");
+//!
+//! // In your WebSocket loop:
+//! // if let WsMessage::Fill(fill) = &msg { position.apply(&fill.msg); }
+//!
+//! assert_eq!(position.position_fp(), 0);
+//! assert_eq!(position.average_price(), None);
+//! ```
+
+pub mod activity;
+pub mod fees;
+pub mod fills;
+pub mod position;
+pub mod slippage;
+pub mod tape;
+pub mod ticker;
+
+pub use activity::{market_activity_summary, ActivitySummary};
+pub use fees::{fee_attribution, FeeBreakdown};
+pub use fills::fill_rate;
+pub use position::PositionBuilder;
+pub use slippage::realized_slippage;
+pub use tape::TradeTape;
+pub use ticker::TickerCache;
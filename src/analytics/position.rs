@@ -0,0 +1,186 @@
+//! Incremental position tracking from the fill stream.
+
+use crate::types::market::average_cost_trade;
+use crate::types::messages::FillData;
+use crate::types::order::Side;
+use crate::types::{Price, Quantity, COUNT_SCALE};
+
+/// Running net position and average entry price for one market.
+///
+/// Positions are expressed in yes contracts: positive means long yes,
+/// negative means long no. Buying no at `$0.70` is treated as selling yes
+/// at `$0.30`, so a single average (in yes-price terms) covers both sides.
+///
+/// Fills that reduce the position realize P&L against the average entry;
+/// a fill that crosses zero closes the old position and opens the remainder
+/// at the fill price.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::analytics::PositionBuilder;
+///
+/// let position = PositionBuilder::new("KXBTC-25JAN");
+/// assert!(position.is_flat());
+/// ```
+#[derive(Debug, Clone)]
+pub struct PositionBuilder {
+    market_ticker: String,
+    /// Net yes contracts, scaled by 100
+    position_fp: Quantity,
+    /// Sum of `count_fp * price` for the open position
+    cost_basis: i64,
+    /// Realized P&L in `count_fp * price` units
+    realized: i64,
+    /// Total fees in ten-thousandths of a dollar
+    fees: i64,
+}
+
+impl PositionBuilder {
+    /// Create a flat position for a market
+    #[must_use]
+    pub fn new(market_ticker: impl Into<String>) -> Self {
+        Self {
+            market_ticker: market_ticker.into(),
+            position_fp: 0,
+            cost_basis: 0,
+            realized: 0,
+            fees: 0,
+        }
+    }
+
+    /// Get the market ticker
+    #[must_use]
+    pub fn market_ticker(&self) -> &str {
+        &self.market_ticker
+    }
+
+    /// Apply a fill
+    ///
+    /// Returns `false` (and ignores the fill) if it is for another market, so
+    /// the whole fill stream can be fed to every builder.
+    pub fn apply(&mut self, fill: &FillData) -> bool {
+        if fill.market_ticker != self.market_ticker {
+            return false;
+        }
+
+        let delta = match fill.purchased_side {
+            Side::Yes => fill.count_fp,
+            Side::No => -fill.count_fp,
+        };
+        self.apply_trade(delta, fill.yes_price_dollars);
+        self.fees += fill.fee_cost;
+        true
+    }
+
+    /// Apply a signed yes-contract change at a yes price
+    fn apply_trade(&mut self, delta: Quantity, price: Price) {
+        let (position_fp, cost_basis, realized) =
+            average_cost_trade(self.position_fp, self.cost_basis, delta, price);
+        self.position_fp = position_fp;
+        self.cost_basis = cost_basis;
+        self.realized += realized;
+    }
+
+    /// Net yes contracts scaled by 100 (negative when long no)
+    #[must_use]
+    pub const fn position_fp(&self) -> Quantity {
+        self.position_fp
+    }
+
+    /// Check if there is no open position
+    #[must_use]
+    pub const fn is_flat(&self) -> bool {
+        self.position_fp == 0
+    }
+
+    /// Average entry in yes-price terms, rounded to the nearest unit
+    ///
+    /// Returns `None` when flat.
+    #[must_use]
+    pub fn average_price(&self) -> Option<Price> {
+        let open = self.position_fp.abs();
+        if open == 0 {
+            return None;
+        }
+        Some((self.cost_basis + open / 2) / open)
+    }
+
+    /// Realized P&L in ten-thousandths of a dollar, before fees
+    #[must_use]
+    pub const fn realized_pnl(&self) -> i64 {
+        self.realized / COUNT_SCALE
+    }
+
+    /// Total fees paid in ten-thousandths of a dollar
+    #[must_use]
+    pub const fn fees(&self) -> i64 {
+        self.fees
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::order::Action;
+
+    fn fill(side: Side, action: Action, yes_price: Price, count_fp: Quantity) -> FillData {
+        let purchased_side = match action {
+            Action::Buy => side,
+            Action::Sell => side.opposite(),
+        };
+        FillData {
+            trade_id: "t".to_string(),
+            order_id: "o".to_string(),
+            market_ticker: "TEST".to_string(),
+            is_taker: true,
+            side,
+            yes_price_dollars: yes_price,
+            count_fp,
+            fee_cost: 10,
+            action,
+            ts: 0,
+            client_order_id: None,
+            post_position_fp: 0,
+            purchased_side,
+            subaccount: None,
+        }
+    }
+
+    #[test]
+    fn test_running_average() {
+        let mut position = PositionBuilder::new("TEST");
+
+        // Buy 10 yes at $0.40, then 10 at $0.60
+        position.apply(&fill(Side::Yes, Action::Buy, 4_000, 1_000));
+        position.apply(&fill(Side::Yes, Action::Buy, 6_000, 1_000));
+        assert_eq!(position.position_fp(), 2_000);
+        assert_eq!(position.average_price(), Some(5_000));
+
+        // Sell 5 yes at $0.70: realize 5 * $0.20
+        position.apply(&fill(Side::Yes, Action::Sell, 7_000, 500));
+        assert_eq!(position.position_fp(), 1_500);
+        assert_eq!(position.average_price(), Some(5_000));
+        assert_eq!(position.realized_pnl(), 10_000);
+
+        // Buy 20 no at $0.70 (yes $0.30): close 15 at a loss, flip to 5 no
+        position.apply(&fill(Side::No, Action::Buy, 3_000, 2_000));
+        assert_eq!(position.position_fp(), -500);
+        assert_eq!(position.average_price(), Some(3_000));
+        assert_eq!(position.realized_pnl(), 10_000 - 30_000);
+
+        // Sell the 5 no at yes $0.20: the no side gains $0.10 each
+        position.apply(&fill(Side::No, Action::Sell, 2_000, 500));
+        assert!(position.is_flat());
+        assert_eq!(position.average_price(), None);
+        assert_eq!(position.realized_pnl(), 10_000 - 30_000 + 5_000);
+        assert_eq!(position.fees(), 50);
+    }
+
+    #[test]
+    fn test_ignores_other_markets() {
+        let mut position = PositionBuilder::new("OTHER");
+        assert!(!position.apply(&fill(Side::Yes, Action::Buy, 4_000, 1_000)));
+        assert!(position.is_flat());
+    }
+}
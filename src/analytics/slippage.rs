@@ -0,0 +1,87 @@
+//! Execution quality against a decision price.
+
+use crate::types::market::Fill;
+use crate::types::{Action, Price};
+
+/// Volume-weighted slippage of fills against an expected price, in centi-cents.
+///
+/// Each fill is priced on the side it traded (`no_price_dollars` for no fills,
+/// `yes_price_dollars` otherwise), so `expected_price` must be quoted on that
+/// side too. Positive means worse than expected: buys that paid more or sells
+/// that received less. The average is weighted by `count_fp` and rounded to
+/// the nearest centi-cent. Returns 0 if there are no fills.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::analytics::realized_slippage;
+///
+/// assert_eq!(realized_slippage(&[], 5_000), 0);
+/// ```
+#[must_use]
+pub fn realized_slippage(fills: &[Fill], expected_price: Price) -> i64 {
+    let (weighted, volume) = fills.iter().fold((0i64, 0i64), |(weighted, volume), fill| {
+        let price = if fill.side == "no" {
+            fill.no_price_dollars
+        } else {
+            fill.yes_price_dollars
+        };
+        let slippage = if fill.action == Action::Sell {
+            expected_price - price
+        } else {
+            price - expected_price
+        };
+        (weighted + slippage * fill.count_fp, volume + fill.count_fp)
+    });
+
+    if volume == 0 {
+        return 0;
+    }
+    (2 * weighted + volume).div_euclid(2 * volume)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(side: &str, action: &str, count: &str, yes_price: &str) -> Fill {
+        let no_price = format!("{:.4}", 1.0 - yes_price.parse::<f64>().unwrap());
+        serde_json::from_str(&format!(
+            r#"{{
+                "fill_id": "f1", "trade_id": "t1", "order_id": "o1", "ticker": "TEST",
+                "market_ticker": "TEST", "side": "{}", "action": "{}", "count_fp": "{}",
+                "yes_price_dollars": "{}", "no_price_dollars": "{}", "is_taker": true,
+                "fee_cost": "0.0000"
+            }}"#,
+            side, action, count, yes_price, no_price
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_realized_slippage() {
+        // Bought 10 at 0.5100 and 30 at 0.5300 against 0.5000:
+        // (10 * 100 + 30 * 300) / 40 = 250
+        let buys = [
+            fill("yes", "buy", "10.00", "0.5100"),
+            fill("yes", "buy", "30.00", "0.5300"),
+        ];
+        assert_eq!(realized_slippage(&buys, 5_000), 250);
+
+        // Filling better than expected is negative slippage
+        assert_eq!(realized_slippage(&buys, 5_500), -250);
+
+        // Sold no at 0.3800 (yes 0.6200) against 0.4000: received 200 less
+        let sells = [fill("no", "sell", "5.00", "0.6200")];
+        assert_eq!(realized_slippage(&sells, 4_000), 200);
+
+        // Rounds to the nearest centi-cent
+        let mixed = [
+            fill("yes", "buy", "1.00", "0.5001"),
+            fill("yes", "buy", "2.00", "0.5000"),
+        ];
+        assert_eq!(realized_slippage(&mixed, 5_000), 0);
+
+        assert_eq!(realized_slippage(&[], 5_000), 0);
+    }
+}
@@ -0,0 +1,169 @@
+//! Rolling record of recent public trades.
+
+use std::collections::VecDeque;
+
+use crate::types::messages::TradeData;
+use crate::types::Price;
+
+/// The most recent trades for one market, oldest first.
+///
+/// Holds at most `capacity` trades; older trades are dropped as new ones
+/// arrive.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::analytics::TradeTape;
+///
+/// let tape = TradeTape::new("KXBTC-25JAN", 500);
+/// assert!(tape.is_empty());
+/// assert_eq!(tape.effective_spread(5_000), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TradeTape {
+    market_ticker: String,
+    capacity: usize,
+    trades: VecDeque<TradeData>,
+}
+
+impl TradeTape {
+    /// Create an empty tape keeping up to `capacity` trades
+    #[must_use]
+    pub fn new(market_ticker: impl Into<String>, capacity: usize) -> Self {
+        Self {
+            market_ticker: market_ticker.into(),
+            capacity,
+            trades: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Get the market ticker
+    #[must_use]
+    pub fn market_ticker(&self) -> &str {
+        &self.market_ticker
+    }
+
+    /// Record a trade
+    ///
+    /// Returns `false` (and ignores the trade) if it is for another market.
+    pub fn apply(&mut self, trade: &TradeData) -> bool {
+        if trade.market_ticker != self.market_ticker {
+            return false;
+        }
+        if self.capacity == 0 {
+            return true;
+        }
+        if self.trades.len() == self.capacity {
+            self.trades.pop_front();
+        }
+        self.trades.push_back(trade.clone());
+        true
+    }
+
+    /// Number of trades held
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.trades.len()
+    }
+
+    /// Check if no trades are held
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.trades.is_empty()
+    }
+
+    /// Most recent trade
+    #[must_use]
+    pub fn last(&self) -> Option<&TradeData> {
+        self.trades.back()
+    }
+
+    /// Iterate over held trades, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &TradeData> {
+        self.trades.iter()
+    }
+
+    /// Effective spread of the last trade against a reference mid
+    ///
+    /// Computed as `2 * |yes_price - mid|` in the same units as `mid`.
+    /// Returns `None` if the tape is empty.
+    #[must_use]
+    pub fn effective_spread(&self, mid: Price) -> Option<f64> {
+        self.last()
+            .map(|trade| effective_spread(trade.yes_price_dollars, mid))
+    }
+
+    /// Average effective spread of the last `window` trades against `mid`
+    ///
+    /// Uses every held trade if fewer than `window` are available. Returns
+    /// `None` if the tape is empty or `window` is zero.
+    #[must_use]
+    pub fn average_effective_spread(&self, mid: Price, window: usize) -> Option<f64> {
+        let n = window.min(self.trades.len());
+        if n == 0 {
+            return None;
+        }
+        let total: f64 = self
+            .trades
+            .iter()
+            .rev()
+            .take(n)
+            .map(|trade| effective_spread(trade.yes_price_dollars, mid))
+            .sum();
+        Some(total / n as f64)
+    }
+}
+
+fn effective_spread(price: Price, mid: Price) -> f64 {
+    (2 * (price - mid).abs()) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::order::Side;
+
+    fn trade(market_ticker: &str, yes_price: Price) -> TradeData {
+        TradeData {
+            trade_id: "t".to_string(),
+            market_ticker: market_ticker.to_string(),
+            yes_price_dollars: yes_price,
+            no_price_dollars: 10_000 - yes_price,
+            count_fp: 100,
+            taker_side: Side::Yes,
+            ts: 0,
+        }
+    }
+
+    #[test]
+    fn test_effective_spread() {
+        let mut tape = TradeTape::new("TEST", 3);
+        assert_eq!(tape.effective_spread(5_000), None);
+
+        // Above mid, then below mid
+        tape.apply(&trade("TEST", 5_200));
+        assert_eq!(tape.effective_spread(5_000), Some(400.0));
+        tape.apply(&trade("TEST", 4_900));
+        assert_eq!(tape.effective_spread(5_000), Some(200.0));
+
+        assert_eq!(tape.average_effective_spread(5_000, 2), Some(300.0));
+        assert_eq!(tape.average_effective_spread(5_000, 10), Some(300.0));
+        assert_eq!(tape.average_effective_spread(5_000, 1), Some(200.0));
+        assert_eq!(tape.average_effective_spread(5_000, 0), None);
+    }
+
+    #[test]
+    fn test_capacity_and_filtering() {
+        let mut tape = TradeTape::new("TEST", 2);
+
+        assert!(!tape.apply(&trade("OTHER", 5_000)));
+        assert!(tape.is_empty());
+
+        for price in [4_000, 5_000, 6_000] {
+            assert!(tape.apply(&trade("TEST", price)));
+        }
+        let prices: Vec<_> = tape.iter().map(|t| t.yes_price_dollars).collect();
+        assert_eq!(prices, [5_000, 6_000]);
+        assert_eq!(tape.len(), 2);
+    }
+}
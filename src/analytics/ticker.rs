@@ -0,0 +1,152 @@
+//! Latest ticker values per market from the `ticker` channel.
+
+use rustc_hash::FxHashMap;
+
+use crate::types::messages::{TickerData, WsMessage};
+use crate::types::{Price, Quantity};
+
+/// Last price, top of book, and volume for every market seen on the
+/// `ticker` channel.
+///
+/// A lighter alternative to full orderbooks when only top-of-book prices are
+/// needed. Updates older than the stored one (by `ts`) are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::analytics::TickerCache;
+///
+/// let mut cache = TickerCache::new();
+///
+/// // In your WebSocket loop:
+/// // cache.process_message(&msg);
+///
+/// assert_eq!(cache.last_price("KXBTC-25JAN"), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TickerCache {
+    tickers: FxHashMap<String, TickerData>,
+}
+
+impl TickerCache {
+    /// Create an empty cache
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a WebSocket message
+    ///
+    /// Returns `true` if it was a ticker update that changed the cache.
+    pub fn process_message(&mut self, message: &WsMessage) -> bool {
+        match message {
+            WsMessage::Ticker(ticker) => self.apply(&ticker.msg),
+            _ => false,
+        }
+    }
+
+    /// Store a ticker update
+    ///
+    /// Returns `false` if an update with a later `ts` is already stored.
+    pub fn apply(&mut self, ticker: &TickerData) -> bool {
+        if let Some(current) = self.tickers.get_mut(&ticker.market_ticker) {
+            if current.ts > ticker.ts {
+                return false;
+            }
+            *current = ticker.clone();
+        } else {
+            self.tickers
+                .insert(ticker.market_ticker.clone(), ticker.clone());
+        }
+        true
+    }
+
+    /// Get the latest update for a market
+    #[must_use]
+    pub fn get(&self, market_ticker: &str) -> Option<&TickerData> {
+        self.tickers.get(market_ticker)
+    }
+
+    /// Last traded price
+    #[must_use]
+    pub fn last_price(&self, market_ticker: &str) -> Option<Price> {
+        self.get(market_ticker).map(|t| t.price_dollars)
+    }
+
+    /// Best yes bid
+    #[must_use]
+    pub fn yes_bid(&self, market_ticker: &str) -> Option<Price> {
+        self.get(market_ticker).map(|t| t.yes_bid_dollars)
+    }
+
+    /// Best yes ask
+    #[must_use]
+    pub fn yes_ask(&self, market_ticker: &str) -> Option<Price> {
+        self.get(market_ticker).map(|t| t.yes_ask_dollars)
+    }
+
+    /// Contracts traded, scaled by 100
+    #[must_use]
+    pub fn volume(&self, market_ticker: &str) -> Option<Quantity> {
+        self.get(market_ticker).map(|t| t.volume_fp)
+    }
+
+    /// Number of markets tracked
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tickers.len()
+    }
+
+    /// Check if no markets are tracked
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tickers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticker(market: &str, price: &str, bid: &str, ask: &str, volume: &str, ts: i64) -> WsMessage {
+        serde_json::from_str(&format!(
+            r#"{{"type":"ticker","sid":1,"msg":{{
+                "market_ticker":"{}","market_id":"id","price_dollars":"{}",
+                "yes_bid_dollars":"{}","yes_ask_dollars":"{}","volume_fp":"{}",
+                "open_interest_fp":"0.00","dollar_volume":0,"dollar_open_interest":0,
+                "ts":{},"time":""}}}}"#,
+            market, price, bid, ask, volume, ts
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reads_latest_values() {
+        let mut cache = TickerCache::new();
+        assert!(cache.is_empty());
+
+        assert!(cache.process_message(&ticker("A", "0.5000", "0.4900", "0.5100", "10.00", 1)));
+        assert!(cache.process_message(&ticker("B", "0.2000", "0.1900", "0.2200", "3.00", 1)));
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.last_price("A"), Some(5_000));
+        assert_eq!(cache.yes_bid("A"), Some(4_900));
+        assert_eq!(cache.yes_ask("A"), Some(5_100));
+        assert_eq!(cache.volume("A"), Some(1_000));
+
+        // Live update
+        assert!(cache.process_message(&ticker("A", "0.5200", "0.5100", "0.5300", "12.00", 2)));
+        assert_eq!(cache.last_price("A"), Some(5_200));
+        assert_eq!(cache.volume("A"), Some(1_200));
+        assert_eq!(cache.last_price("B"), Some(2_000));
+        assert_eq!(cache.last_price("C"), None);
+    }
+
+    #[test]
+    fn test_ignores_stale_updates() {
+        let mut cache = TickerCache::new();
+        cache.process_message(&ticker("A", "0.5000", "0.4900", "0.5100", "10.00", 5));
+
+        assert!(!cache.process_message(&ticker("A", "0.4000", "0.3900", "0.4100", "9.00", 4)));
+        assert_eq!(cache.last_price("A"), Some(5_000));
+    }
+}
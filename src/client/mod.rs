@@ -5,11 +5,21 @@
 //! - [`rest`] - HTTP client for REST API endpoints
 //! - [`websocket`] - WebSocket client for real-time data
 //! - [`auth`] - RSA-PSS authentication utilities
+//! - [`recorder`] - Raw WebSocket message capture
+//! - [`oco`] - Client-side one-cancels-other order pairs
 
 pub mod auth;
+pub mod oco;
+mod rate_limit;
+pub mod recorder;
 pub mod rest;
 pub mod websocket;
 
-pub use auth::Signer;
+#[cfg(test)]
+pub(crate) mod testing;
+
+pub use auth::{Signer, SigningScheme};
+pub use oco::{OcoManager, OcoPair, OcoTriggered};
+pub use recorder::MessageRecorder;
 pub use rest::RestClient;
-pub use websocket::WebSocketClient;
+pub use websocket::{WebSocketClient, WsHandle, WsReader, WsWriter};
@@ -0,0 +1,359 @@
+//! Client-side one-cancels-other order pairs.
+//!
+//! Kalshi has no native OCO order type, so [`OcoManager`] submits both legs
+//! as ordinary orders and cancels the survivor once it sees the other leg
+//! fill on the `fill` or `user_orders` channel.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kalshi_trading::client::OcoManager;
+//! use kalshi_trading::types::{Action, CreateOrderRequest, Side};
+//! use kalshi_trading::{Config, KalshiClient};
+//!
+//! # async fn example() -> kalshi_trading::Result<()> {
+//! let client = KalshiClient::new(Config::new("api-key", "private-key-pem"))?;
+//! let mut ws = client.websocket().await?;
+//! ws.subscribe_fills(None).await?;
+//! ws.subscribe_user_orders().await?;
+//!
+//! let oco = OcoManager::new();
+//! let take_profit = CreateOrderRequest::limit("TICKER", Side::Yes, Action::Sell, 10, 7000);
+//! let stop = CreateOrderRequest::limit("TICKER", Side::No, Action::Buy, 10, 6500);
+//! oco.submit_oco(client.rest(), &take_profit, &stop).await?;
+//!
+//! while let Some(msg) = ws.next().await {
+//!     if let Some(triggered) = oco.on_message(client.rest(), &msg?).await? {
+//!         println!("{} filled, canceled {}", triggered.filled_order_id, triggered.canceled_order_id);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+
+use crate::client::rest::RestClient;
+use crate::error::{ApiErrorKind, Error};
+use crate::types::{CreateOrderRequest, Order, WsMessage};
+
+/// The orders created by [`OcoManager::submit_oco`]
+#[derive(Debug, Clone)]
+pub struct OcoPair {
+    /// The first leg as returned by the exchange
+    pub first: Order,
+    /// The second leg, or `None` if the first leg filled on submission and
+    /// the second was never sent
+    pub second: Option<Order>,
+}
+
+/// A fill on one leg that canceled its sibling
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OcoTriggered {
+    /// The leg that filled
+    pub filled_order_id: String,
+    /// The sibling that was canceled
+    pub canceled_order_id: String,
+}
+
+/// Tracks one-cancels-other order pairs and cancels the sibling of any leg
+/// that fills.
+///
+/// The manager does not read the WebSocket itself; feed it every message
+/// from a connection subscribed to `fill` and/or `user_orders` via
+/// [`Self::on_message`]. Any fill counts, so a partial fill on one leg
+/// cancels the other.
+#[derive(Debug, Default)]
+pub struct OcoManager {
+    /// Both directions of every live pair, keyed by order id
+    siblings: Mutex<FxHashMap<String, String>>,
+}
+
+impl OcoManager {
+    /// Create a manager with no tracked pairs
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Submit two orders as a one-cancels-other pair.
+    ///
+    /// Both requests are validated before anything is sent. If the second
+    /// leg is rejected, the first is canceled (best effort) and the error is
+    /// returned. If either leg fills on submission, the other is canceled
+    /// immediately and the pair is not tracked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either request is invalid, either order cannot be
+    /// created, or an immediate sibling cancel fails.
+    pub async fn submit_oco(
+        &self,
+        client: &RestClient,
+        order_a: &CreateOrderRequest,
+        order_b: &CreateOrderRequest,
+    ) -> Result<OcoPair, Error> {
+        order_a.validate()?;
+        order_b.validate()?;
+
+        let first = client.create_order(order_a).await?.order;
+        if first.fill_count_fp > 0 {
+            return Ok(OcoPair {
+                first,
+                second: None,
+            });
+        }
+
+        let second = match client.create_order(order_b).await {
+            Ok(response) => response.order,
+            Err(err) => {
+                let _ = client.cancel_order(&first.order_id).await;
+                return Err(err);
+            }
+        };
+
+        if second.fill_count_fp > 0 {
+            cancel_sibling(client, &first.order_id).await?;
+        } else {
+            let mut siblings = self.siblings.lock();
+            siblings.insert(first.order_id.clone(), second.order_id.clone());
+            siblings.insert(second.order_id.clone(), first.order_id.clone());
+        }
+
+        Ok(OcoPair {
+            first,
+            second: Some(second),
+        })
+    }
+
+    /// Process a WebSocket message, canceling the sibling if it reports a
+    /// fill on a tracked leg.
+    ///
+    /// A tracked leg that is canceled without filling drops the pair and
+    /// leaves the other leg resting. Messages for untracked orders are
+    /// ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sibling cancel fails. The pair stays tracked,
+    /// so the next update for either leg retries the cancel. A sibling that
+    /// is already gone counts as canceled.
+    pub async fn on_message(
+        &self,
+        client: &RestClient,
+        message: &WsMessage,
+    ) -> Result<Option<OcoTriggered>, Error> {
+        let filled_order_id = match message {
+            WsMessage::Fill(fill) if fill.msg.count_fp > 0 => &fill.msg.order_id,
+            WsMessage::UserOrder(update) if update.msg.fill_count_fp > 0 => &update.msg.order_id,
+            WsMessage::UserOrder(update) if update.msg.status == "canceled" => {
+                self.forget(&update.msg.order_id);
+                return Ok(None);
+            }
+            _ => return Ok(None),
+        };
+
+        let Some(sibling) = self.forget(filled_order_id) else {
+            return Ok(None);
+        };
+
+        if let Err(err) = cancel_sibling(client, &sibling).await {
+            let mut siblings = self.siblings.lock();
+            siblings.insert(filled_order_id.clone(), sibling.clone());
+            siblings.insert(sibling, filled_order_id.clone());
+            return Err(err);
+        }
+
+        Ok(Some(OcoTriggered {
+            filled_order_id: filled_order_id.clone(),
+            canceled_order_id: sibling,
+        }))
+    }
+
+    /// Get the sibling of a tracked leg
+    #[must_use]
+    pub fn sibling_of(&self, order_id: &str) -> Option<String> {
+        self.siblings.lock().get(order_id).cloned()
+    }
+
+    /// Stop tracking the pair containing `order_id` without canceling
+    /// anything, returning the sibling's order id
+    pub fn forget(&self, order_id: &str) -> Option<String> {
+        let mut siblings = self.siblings.lock();
+        let sibling = siblings.remove(order_id)?;
+        siblings.remove(&sibling);
+        Some(sibling)
+    }
+
+    /// Get the number of tracked pairs
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.siblings.lock().len() / 2
+    }
+
+    /// Check if no pairs are tracked
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.siblings.lock().is_empty()
+    }
+}
+
+/// Cancel an order, treating one the exchange no longer knows as canceled
+async fn cancel_sibling(client: &RestClient, order_id: &str) -> Result<(), Error> {
+    match client.cancel_order(order_id).await {
+        Ok(_) => Ok(()),
+        Err(Error::Api(err)) if err.kind() == ApiErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::client::testing::{
+        mock_client, resting_order_json, test_private_key_pem, MockResponse, MockServer,
+    };
+    use crate::config::Config;
+    use crate::types::{Action, Side};
+
+    fn legs() -> (CreateOrderRequest, CreateOrderRequest) {
+        (
+            CreateOrderRequest::limit("T", Side::Yes, Action::Sell, 1, 7000),
+            CreateOrderRequest::limit("T", Side::No, Action::Buy, 1, 6500),
+        )
+    }
+
+    fn ws_message(value: serde_json::Value) -> WsMessage {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn fill(order_id: &str) -> WsMessage {
+        ws_message(serde_json::json!({
+            "type": "fill", "sid": 1,
+            "msg": {
+                "trade_id": "t1", "order_id": order_id, "market_ticker": "T",
+                "is_taker": false, "side": "yes", "yes_price_dollars": "0.7000",
+                "count_fp": "1.00", "fee_cost": "0.0000", "action": "sell", "ts": 1,
+                "post_position_fp": "0.00", "purchased_side": "yes"
+            }
+        }))
+    }
+
+    fn user_order(order_id: &str, status: &str, fill_count: &str) -> WsMessage {
+        ws_message(serde_json::json!({
+            "type": "user_order", "sid": 2,
+            "msg": {
+                "order_id": order_id, "user_id": "u1", "ticker": "T", "status": status,
+                "side": "yes", "is_yes": true, "yes_price_dollars": "0.7000",
+                "fill_count_fp": fill_count, "remaining_count_fp": "1.00",
+                "initial_count_fp": "1.00", "taker_fill_cost_dollars": "0.0000",
+                "maker_fill_cost_dollars": "0.0000", "taker_fees_dollars": "0.0000",
+                "maker_fees_dollars": "0.0000", "client_order_id": "",
+                "created_time": "2026-01-01T00:00:00Z"
+            }
+        }))
+    }
+
+    fn deletes(server: &MockServer) -> Vec<String> {
+        server
+            .requests()
+            .into_iter()
+            .filter(|r| r.method == "DELETE")
+            .map(|r| r.path)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_fill_cancels_sibling() {
+        let created = AtomicUsize::new(0);
+        let server = MockServer::start(move |req| match req.method.as_str() {
+            "POST" => {
+                let id = format!("o{}", created.fetch_add(1, Ordering::SeqCst) + 1);
+                MockResponse::json(format!(r#"{{"order":{}}}"#, resting_order_json(&id)))
+            }
+            "DELETE" => {
+                let id = req.path.rsplit('/').next().unwrap();
+                MockResponse::json(format!(
+                    r#"{{"order":{},"reduced_by_fp":"1.00"}}"#,
+                    resting_order_json(id)
+                ))
+            }
+            other => panic!("unexpected request {:?}", other),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+        let oco = OcoManager::new();
+        let (a, b) = legs();
+
+        let pair = oco.submit_oco(&client, &a, &b).await.unwrap();
+        assert_eq!(pair.first.order_id, "o1");
+        assert_eq!(pair.second.unwrap().order_id, "o2");
+        assert_eq!(oco.len(), 1);
+        assert_eq!(oco.sibling_of("o1").as_deref(), Some("o2"));
+
+        // Unrelated traffic is ignored
+        assert_eq!(oco.on_message(&client, &fill("other")).await.unwrap(), None);
+        assert_eq!(
+            oco.on_message(&client, &user_order("o1", "resting", "0.00"))
+                .await
+                .unwrap(),
+            None
+        );
+        assert!(deletes(&server).is_empty());
+
+        let triggered = oco.on_message(&client, &fill("o2")).await.unwrap();
+        assert_eq!(
+            triggered,
+            Some(OcoTriggered {
+                filled_order_id: "o2".to_string(),
+                canceled_order_id: "o1".to_string(),
+            })
+        );
+        assert!(oco.is_empty());
+        assert_eq!(deletes(&server), vec!["/portfolio/orders/o1"]);
+
+        // A later update for the same fill does not cancel again
+        let update = user_order("o2", "executed", "1.00");
+        assert_eq!(oco.on_message(&client, &update).await.unwrap(), None);
+        assert_eq!(deletes(&server).len(), 1);
+
+        // A partial fill reported on user_orders triggers too
+        oco.submit_oco(&client, &a, &b).await.unwrap();
+        let update = user_order("o3", "resting", "0.50");
+        let triggered = oco.on_message(&client, &update).await.unwrap().unwrap();
+        assert_eq!(triggered.canceled_order_id, "o4");
+        assert_eq!(deletes(&server).last().unwrap(), "/portfolio/orders/o4");
+
+        // A leg canceled without fills drops the pair and leaves the sibling
+        oco.submit_oco(&client, &a, &b).await.unwrap();
+        let update = user_order("o5", "canceled", "0.00");
+        assert_eq!(oco.on_message(&client, &update).await.unwrap(), None);
+        assert!(oco.is_empty());
+        assert_eq!(deletes(&server).len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_second_leg_cancels_first() {
+        let created = AtomicUsize::new(0);
+        let server = MockServer::start(move |req| match req.method.as_str() {
+            "POST" if created.fetch_add(1, Ordering::SeqCst) == 0 => {
+                MockResponse::json(format!(r#"{{"order":{}}}"#, resting_order_json("o1")))
+            }
+            "POST" => MockResponse::status(400, r#"{"code":"invalid_price","message":"no"}"#),
+            "DELETE" => MockResponse::status(404, r#"{"code":"not_found","message":"gone"}"#),
+            other => panic!("unexpected request {:?}", other),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+        let oco = OcoManager::new();
+        let (a, b) = legs();
+
+        let err = oco.submit_oco(&client, &a, &b).await.unwrap_err();
+        assert!(matches!(err, Error::Api(ref e) if e.kind() == ApiErrorKind::InvalidPrice));
+        assert_eq!(deletes(&server), vec!["/portfolio/orders/o1"]);
+        assert!(oco.is_empty());
+    }
+}
@@ -0,0 +1,92 @@
+//! Client-side pacing of REST requests.
+//!
+//! [`RateLimiter`] is a token bucket holding one second's worth of requests.
+//! Callers that find it empty reserve the next token and sleep until it is
+//! due, so concurrent requests queue in arrival order instead of all retrying
+//! at once.
+
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tokio::time::Instant;
+
+/// Token bucket limiting requests to a fixed rate per second
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    per_second: u32,
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Available tokens; negative when callers are waiting on reservations
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter allowing `per_second` requests, starting full
+    ///
+    /// A rate of 0 is treated as 1.
+    pub(crate) fn new(per_second: u32) -> Self {
+        let per_second = per_second.max(1);
+        Self {
+            per_second,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(per_second),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Requests allowed per second
+    #[cfg(test)]
+    pub(crate) fn per_second(&self) -> u32 {
+        self.per_second
+    }
+
+    /// Wait until a request may be sent
+    pub(crate) async fn acquire(&self) {
+        let wait = {
+            let mut bucket = self.bucket.lock();
+            let rate = f64::from(self.per_second);
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+            bucket.tokens = (bucket.tokens + elapsed * rate).min(rate);
+            bucket.last_refill = now;
+
+            bucket.tokens -= 1.0;
+            if bucket.tokens >= 0.0 {
+                None
+            } else {
+                Some(Duration::from_secs_f64(-bucket.tokens / rate))
+            }
+        };
+
+        if let Some(wait) = wait {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_burst_then_paced() {
+        let limiter = RateLimiter::new(10);
+        assert_eq!(limiter.per_second(), 10);
+
+        let start = Instant::now();
+        for _ in 0..10 {
+            limiter.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(50));
+
+        // The bucket is empty, so the next two wait one interval each
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(190));
+    }
+}
@@ -0,0 +1,370 @@
+//! Raw WebSocket message capture for replay and debugging.
+//!
+//! [`MessageRecorder`] appends every text frame received by a
+//! [`WebSocketClient`](super::WebSocketClient) to a JSON-lines file, one frame
+//! per line, exactly as sent by the exchange.
+//!
+//! # Backpressure
+//!
+//! Frames are handed to a dedicated writer thread through a bounded queue, so
+//! recording never blocks the async receive loop on disk I/O. If the disk
+//! cannot keep up and the queue fills, further frames are dropped and counted
+//! by [`MessageRecorder::dropped`] rather than stalling reads or buffering an
+//! unbounded backlog in memory.
+//!
+//! # Rotation
+//!
+//! With [`MessageRecorder::with_rotation`], a new file is started once the
+//! current one reaches a size or age limit. Files are named by the time they
+//! were opened, so a directory listing sorts in capture order.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kalshi_trading::client::MessageRecorder;
+//! use std::time::Duration;
+//!
+//! # fn example() -> kalshi_trading::Result<()> {
+//! let recorder = MessageRecorder::new("captures")?
+//!     .with_rotation(64 * 1024 * 1024, Duration::from_secs(3600));
+//! # let _ = recorder;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+
+use crate::error::Error;
+
+/// Frames queued for the writer thread before new ones are dropped
+const QUEUE_CAPACITY: usize = 16 * 1024;
+
+/// Records raw WebSocket frames to JSON-lines files
+#[derive(Debug)]
+pub struct MessageRecorder {
+    tx: Option<SyncSender<Command>>,
+    handle: Option<JoinHandle<()>>,
+    shared: Arc<Shared>,
+    /// Frames dropped because the queue was full
+    dropped: u64,
+    /// Whether the previous frame was dropped, so overflow is logged once
+    dropping: bool,
+}
+
+/// State the writer thread reports back to the handle
+#[derive(Debug)]
+struct Shared {
+    path: Mutex<PathBuf>,
+    /// The error that stopped the writer thread
+    error: Mutex<Option<io::Error>>,
+}
+
+#[derive(Debug)]
+enum Command {
+    Frame(String),
+    Rotation(u64, Duration),
+    Flush(SyncSender<io::Result<()>>),
+}
+
+impl MessageRecorder {
+    /// Create a recorder writing into `dir`
+    ///
+    /// The directory and first file are created before this returns; frames
+    /// are then written by a dedicated thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or first file cannot be created, or
+    /// the writer thread cannot be started.
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self, Error> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        let (path, file) = open_file(&dir, 0)?;
+
+        let shared = Arc::new(Shared {
+            path: Mutex::new(path),
+            error: Mutex::new(None),
+        });
+        let writer = Writer {
+            dir,
+            file,
+            bytes: 0,
+            opened_at: Instant::now(),
+            file_index: 1,
+            max_bytes: None,
+            max_duration: None,
+            shared: Arc::clone(&shared),
+        };
+        let (tx, rx) = mpsc::sync_channel(QUEUE_CAPACITY);
+        let handle = thread::Builder::new()
+            .name("kalshi-recorder".to_string())
+            .spawn(move || writer.run(rx))?;
+
+        Ok(Self {
+            tx: Some(tx),
+            handle: Some(handle),
+            shared,
+            dropped: 0,
+            dropping: false,
+        })
+    }
+
+    /// Start a new file once the current one exceeds `max_bytes` or has been
+    /// open for `max_duration`
+    ///
+    /// A single frame larger than `max_bytes` is still written whole.
+    #[must_use]
+    pub fn with_rotation(self, max_bytes: u64, max_duration: Duration) -> Self {
+        if let Some(tx) = &self.tx {
+            // A stopped writer reports its error on the next record
+            let _ = tx.send(Command::Rotation(max_bytes, max_duration));
+        }
+        self
+    }
+
+    /// Path of the file currently being written
+    #[must_use]
+    pub fn current_path(&self) -> PathBuf {
+        self.shared.path.lock().clone()
+    }
+
+    /// Frames dropped so far because the writer fell behind
+    #[must_use]
+    pub const fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Queue one raw frame to be appended as a line
+    ///
+    /// Never blocks. If the writer thread has fallen more than 16,384 frames
+    /// behind, the frame is dropped and counted in
+    /// [`dropped`](Self::dropped) instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error that stopped the writer thread, if writing or
+    /// rotating failed.
+    pub fn record(&mut self, raw: &str) -> Result<(), Error> {
+        let Some(tx) = &self.tx else {
+            return Err(self.writer_error());
+        };
+        match tx.try_send(Command::Frame(raw.to_string())) {
+            Ok(()) => {
+                self.dropping = false;
+                Ok(())
+            }
+            Err(TrySendError::Full(_)) => {
+                self.dropped += 1;
+                if !self.dropping {
+                    self.dropping = true;
+                    tracing::warn!(
+                        dropped = self.dropped,
+                        "recorder queue full, dropping frames"
+                    );
+                }
+                Ok(())
+            }
+            Err(TrySendError::Disconnected(_)) => Err(self.writer_error()),
+        }
+    }
+
+    /// Write all queued frames to disk
+    ///
+    /// Blocks the calling thread until the writer has caught up, so avoid
+    /// calling it from a hot async loop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the flush fails or the writer thread has stopped.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let (reply_tx, reply_rx) = mpsc::sync_channel(1);
+        if let Some(tx) = &self.tx {
+            // On failure the reply sender is dropped with the command
+            let _ = tx.send(Command::Flush(reply_tx));
+        }
+        match reply_rx.recv() {
+            Ok(result) => Ok(result?),
+            Err(_) => Err(self.writer_error()),
+        }
+    }
+
+    fn writer_error(&self) -> Error {
+        self.shared
+            .error
+            .lock()
+            .take()
+            .unwrap_or_else(|| io::Error::other("recorder writer stopped"))
+            .into()
+    }
+}
+
+impl Drop for MessageRecorder {
+    fn drop(&mut self) {
+        // Closing the queue lets the writer drain it and flush
+        self.tx = None;
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// The writer thread's side of a [`MessageRecorder`]
+struct Writer {
+    dir: PathBuf,
+    file: BufWriter<File>,
+    /// Bytes written to the current file
+    bytes: u64,
+    /// When the current file was opened
+    opened_at: Instant,
+    /// Files opened so far, used to keep names unique within a millisecond
+    file_index: u32,
+    max_bytes: Option<u64>,
+    max_duration: Option<Duration>,
+    shared: Arc<Shared>,
+}
+
+impl Writer {
+    fn run(mut self, rx: Receiver<Command>) {
+        for command in rx {
+            let result = match command {
+                Command::Frame(raw) => self.write(&raw),
+                Command::Rotation(max_bytes, max_duration) => {
+                    self.max_bytes = Some(max_bytes);
+                    self.max_duration = Some(max_duration);
+                    Ok(())
+                }
+                Command::Flush(reply) => {
+                    let _ = reply.send(self.file.flush());
+                    Ok(())
+                }
+            };
+            if let Err(e) = result {
+                tracing::warn!(error = %e, "recorder stopped");
+                *self.shared.error.lock() = Some(e);
+                return;
+            }
+        }
+        let _ = self.file.flush();
+    }
+
+    fn write(&mut self, raw: &str) -> io::Result<()> {
+        let len = raw.len() as u64 + 1;
+        if self.should_rotate(len) {
+            self.rotate()?;
+        }
+
+        self.file.write_all(raw.as_bytes())?;
+        self.file.write_all(b"\n")?;
+        self.bytes += len;
+        Ok(())
+    }
+
+    fn should_rotate(&self, next_len: u64) -> bool {
+        // Never rotate away from an empty file
+        if self.bytes == 0 {
+            return false;
+        }
+        let too_big = self
+            .max_bytes
+            .is_some_and(|max| self.bytes + next_len > max);
+        let too_old = self
+            .max_duration
+            .is_some_and(|max| self.opened_at.elapsed() >= max);
+        too_big || too_old
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let (path, file) = open_file(&self.dir, self.file_index)?;
+        self.file = file;
+        *self.shared.path.lock() = path;
+        self.bytes = 0;
+        self.opened_at = Instant::now();
+        self.file_index += 1;
+        Ok(())
+    }
+}
+
+/// Open `<dir>/<unix_ms>-<index>.jsonl`
+fn open_file(dir: &Path, index: u32) -> io::Result<(PathBuf, BufWriter<File>)> {
+    let ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or(0);
+    let path = dir.join(format!("{}-{:04}.jsonl", ms, index));
+    let file = File::create(&path)?;
+    Ok((path, BufWriter::new(file)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn files_in(dir: &Path) -> Vec<PathBuf> {
+        let mut files: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_records_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut recorder = MessageRecorder::new(dir.path()).unwrap();
+
+        recorder.record(r#"{"type":"a"}"#).unwrap();
+        recorder.record(r#"{"type":"b"}"#).unwrap();
+        recorder.flush().unwrap();
+
+        let contents = fs::read_to_string(recorder.current_path()).unwrap();
+        assert_eq!(contents, "{\"type\":\"a\"}\n{\"type\":\"b\"}\n");
+        assert_eq!(files_in(dir.path()).len(), 1);
+    }
+
+    #[test]
+    fn test_rotates_by_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut recorder = MessageRecorder::new(dir.path())
+            .unwrap()
+            .with_rotation(64, Duration::from_secs(3600));
+
+        // 31 bytes per line, so two fit in each 64-byte file
+        let frame = r#"{"type":"trade","seq":1234567}"#;
+        for _ in 0..5 {
+            recorder.record(frame).unwrap();
+        }
+        drop(recorder);
+
+        let files = files_in(dir.path());
+        assert_eq!(files.len(), 3);
+        let lines: usize = files
+            .iter()
+            .map(|path| fs::read_to_string(path).unwrap().lines().count())
+            .sum();
+        assert_eq!(lines, 5);
+    }
+
+    #[test]
+    fn test_rotates_by_time() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut recorder = MessageRecorder::new(dir.path())
+            .unwrap()
+            .with_rotation(u64::MAX, Duration::ZERO);
+
+        recorder.record("{}").unwrap();
+        recorder.record("{}").unwrap();
+        drop(recorder);
+
+        assert_eq!(files_in(dir.path()).len(), 2);
+    }
+}
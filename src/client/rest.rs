@@ -21,14 +21,31 @@
 //! # }
 //! ```
 
+use std::future::Future;
+use std::time::{Duration, SystemTime};
+
+#[cfg(feature = "chrono")]
+use std::time::Instant;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+#[cfg(feature = "chrono")]
+use parking_lot::RwLock;
 use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
-use reqwest::Client;
+use reqwest::{Client, Method};
+#[cfg(feature = "chrono")]
+use rustc_hash::FxHashMap;
 
 use crate::client::auth::{AuthHeaders, Signer};
+use crate::client::rate_limit::RateLimiter;
 use crate::config::Config;
 use crate::error::{ApiError, Error};
+use crate::orderbook::{Orderbook, OrderbookManager, ReconcileReport};
 use crate::types::market::*;
 use crate::types::order::*;
+use crate::types::rfq::*;
+use crate::types::{COUNT_SCALE, DOLLAR_SCALE};
 
 /// HTTP client for Kalshi REST API
 #[derive(Debug)]
@@ -37,8 +54,27 @@ pub struct RestClient {
     base_url: String,
     api_key_id: String,
     signer: Signer,
+    subaccount: Option<u32>,
+    lenient_json: bool,
+    max_retries: u32,
+    retry_base_delay: Duration,
+    /// Pacing for GET requests, from the configured rate-limit tier
+    read_limiter: Option<RateLimiter>,
+    /// Pacing for POST, PUT and DELETE requests
+    write_limiter: Option<RateLimiter>,
+    /// Market close times fetched by [`Self::create_order_before_close`],
+    /// with when each was fetched
+    #[cfg(feature = "chrono")]
+    close_times: RwLock<FxHashMap<String, (DateTime<Utc>, Instant)>>,
 }
 
+/// Maximum response bytes quoted in lenient-mode JSON errors
+const JSON_CONTEXT_LIMIT: usize = 512;
+
+/// How long a cached market close time is used before it is fetched again
+#[cfg(feature = "chrono")]
+const CLOSE_TIME_TTL: Duration = Duration::from_secs(60);
+
 impl RestClient {
     /// Create a new REST client
     ///
@@ -51,15 +87,37 @@ impl RestClient {
     /// Returns an error if the private key cannot be parsed or the HTTP client
     /// cannot be initialized.
     pub fn new(config: &Config) -> Result<Self, Error> {
-        let signer = Signer::new(config.private_key_pem())?;
+        let signer = Signer::with_scheme(config.private_key_pem(), config.signing_scheme())?;
 
-        let client = Client::builder().timeout(config.timeout()).build()?;
+        let mut builder = Client::builder().timeout(config.timeout());
+        if let Some(connect_timeout) = config.connect_timeout() {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        for certificate in config.root_certificates() {
+            builder = builder.add_root_certificate(certificate.clone());
+        }
+        if let Some(identity) = config.tls_client_cert() {
+            builder = builder.identity(identity.clone());
+        }
+        let client = builder.build()?;
 
         Ok(Self {
             client,
-            base_url: config.rest_base_url().to_string(),
+            base_url: config.effective_rest_base_url().to_string(),
             api_key_id: config.api_key_id().to_string(),
             signer,
+            subaccount: config.subaccount(),
+            lenient_json: config.lenient_json(),
+            max_retries: config.max_retries(),
+            retry_base_delay: config.retry_base_delay(),
+            read_limiter: config
+                .rate_limit_tier()
+                .map(|tier| RateLimiter::new(tier.reads_per_second())),
+            write_limiter: config
+                .rate_limit_tier()
+                .map(|tier| RateLimiter::new(tier.writes_per_second())),
+            #[cfg(feature = "chrono")]
+            close_times: RwLock::new(FxHashMap::default()),
         })
     }
 
@@ -102,13 +160,7 @@ impl RestClient {
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let full_path = format!("/trade-api/v2{}", path);
-        let headers = self.auth_headers("GET", &full_path)?;
-
-        let response = self.client.get(&url).headers(headers).send().await?;
-
-        self.handle_response(response).await
+        self.request::<T, ()>(Method::GET, path, None).await
     }
 
     /// Make a POST request to the API
@@ -126,19 +178,7 @@ impl RestClient {
         T: serde::de::DeserializeOwned,
         B: serde::Serialize,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let full_path = format!("/trade-api/v2{}", path);
-        let headers = self.auth_headers("POST", &full_path)?;
-
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(body)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        self.request(Method::POST, path, Some(body)).await
     }
 
     /// Make a DELETE request to the API
@@ -154,13 +194,7 @@ impl RestClient {
     where
         T: serde::de::DeserializeOwned,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let full_path = format!("/trade-api/v2{}", path);
-        let headers = self.auth_headers("DELETE", &full_path)?;
-
-        let response = self.client.delete(&url).headers(headers).send().await?;
-
-        self.handle_response(response).await
+        self.request::<T, ()>(Method::DELETE, path, None).await
     }
 
     /// Make a DELETE request with a JSON body
@@ -169,40 +203,65 @@ impl RestClient {
         T: serde::de::DeserializeOwned,
         B: serde::Serialize,
     {
-        let url = format!("{}{}", self.base_url, path);
-        let full_path = format!("/trade-api/v2{}", path);
-        let headers = self.auth_headers("DELETE", &full_path)?;
-
-        let response = self
-            .client
-            .delete(&url)
-            .headers(headers)
-            .json(body)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        self.request(Method::DELETE, path, Some(body)).await
     }
 
     /// Make a PUT request to the API
     pub async fn put<T, B>(&self, path: &str, body: &B) -> Result<T, Error>
+    where
+        T: serde::de::DeserializeOwned,
+        B: serde::Serialize,
+    {
+        self.request(Method::PUT, path, Some(body)).await
+    }
+
+    /// Send a signed request, retrying on 429 when allowed
+    ///
+    /// Each attempt waits for the rate limiter, if any, and is signed afresh
+    /// since signatures are timestamped.
+    async fn request<T, B>(&self, method: Method, path: &str, body: Option<&B>) -> Result<T, Error>
     where
         T: serde::de::DeserializeOwned,
         B: serde::Serialize,
     {
         let url = format!("{}{}", self.base_url, path);
         let full_path = format!("/trade-api/v2{}", path);
-        let headers = self.auth_headers("PUT", &full_path)?;
+        let retries = if self.max_retries > 0 && is_retryable(&method, body) {
+            self.max_retries
+        } else {
+            0
+        };
 
-        let response = self
-            .client
-            .put(&url)
-            .headers(headers)
-            .json(body)
-            .send()
-            .await?;
+        let limiter = if method == Method::GET {
+            self.read_limiter.as_ref()
+        } else {
+            self.write_limiter.as_ref()
+        };
 
-        self.handle_response(response).await
+        let mut attempt = 0;
+        loop {
+            if let Some(limiter) = limiter {
+                limiter.acquire().await;
+            }
+            let headers = self.auth_headers(method.as_str(), &full_path)?;
+            let mut request = self.client.request(method.clone(), &url).headers(headers);
+            if let Some(body) = body {
+                request = request.json(body);
+            }
+            let response = request.send().await?;
+
+            match self.handle_response(response).await {
+                Err(Error::RateLimited { retry_after_ms }) if attempt < retries => {
+                    let delay = retry_after_ms.map_or_else(
+                        || self.retry_base_delay.saturating_mul(1 << attempt.min(16)),
+                        Duration::from_millis,
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
     }
 
     /// Handle the HTTP response, checking for errors
@@ -218,7 +277,7 @@ impl RestClient {
                 .headers()
                 .get("Retry-After")
                 .and_then(|v| v.to_str().ok())
-                .and_then(|s| s.parse().ok());
+                .and_then(parse_retry_after);
 
             return Err(Error::RateLimited {
                 retry_after_ms: retry_after,
@@ -231,6 +290,11 @@ impl RestClient {
 
             // Try to parse as API error
             if let Ok(error_response) = serde_json::from_str::<serde_json::Value>(&body) {
+                // Kalshi nests the details as {"error": {"code", "message"}}
+                let error_response = match error_response.get("error") {
+                    Some(nested) if nested.is_object() => nested.clone(),
+                    _ => error_response,
+                };
                 let message = error_response
                     .get("message")
                     .or_else(|| error_response.get("error"))
@@ -255,7 +319,11 @@ impl RestClient {
 
         // Deserialize successful response
         let body = response.text().await?;
-        serde_json::from_str(&body).map_err(Error::from)
+        if self.lenient_json {
+            decode_with_context(&body)
+        } else {
+            serde_json::from_str(&body).map_err(Error::from)
+        }
     }
 
     /// Get the base URL
@@ -265,6 +333,47 @@ impl RestClient {
     }
 }
 
+/// Check if a request may be resent after a 429
+///
+/// GETs are always safe. Other methods are only retried when the body carries
+/// a non-empty `client_order_id`, which the exchange uses to deduplicate.
+fn is_retryable<B: serde::Serialize>(method: &Method, body: Option<&B>) -> bool {
+    if method == Method::GET {
+        return true;
+    }
+    body.and_then(|body| serde_json::to_value(body).ok())
+        .and_then(|value| {
+            value
+                .get("client_order_id")
+                .and_then(|id| id.as_str())
+                .map(|id| !id.is_empty())
+        })
+        .unwrap_or(false)
+}
+
+/// Deserialize `body`, quoting the start of it in the error on failure
+fn decode_with_context<T>(body: &str) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    use serde::de::Error as _;
+
+    let value: serde_json::Value = serde_json::from_str(body)?;
+    serde_json::from_value(value).map_err(|e| {
+        let mut end = body.len().min(JSON_CONTEXT_LIMIT);
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+        let ellipsis = if end < body.len() { "..." } else { "" };
+        Error::Json(serde_json::Error::custom(format!(
+            "{} in response: {}{}",
+            e,
+            &body[..end],
+            ellipsis
+        )))
+    })
+}
+
 // ============================================================================
 // Market Data API
 // ============================================================================
@@ -314,6 +423,30 @@ impl RestClient {
         self.get(&path).await
     }
 
+    /// Get a list of markets filtered by a typed status.
+    ///
+    /// Same as [`get_markets`](Self::get_markets), but the status cannot be
+    /// misspelled. [`MarketStatusFilter::All`] omits the filter.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use kalshi_trading::types::MarketStatusFilter;
+    ///
+    /// # async fn example(client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let markets = client.get_markets_filtered(MarketStatusFilter::Open, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_markets_filtered(
+        &self,
+        status: MarketStatusFilter,
+        event_ticker: Option<&str>,
+        cursor: Option<&str>,
+    ) -> Result<GetMarketsResponse, Error> {
+        self.get_markets(status.as_query(), event_ticker, cursor)
+            .await
+    }
+
     /// Get a specific market by ticker.
     pub async fn get_market(&self, ticker: &str) -> Result<GetMarketResponse, Error> {
         self.get(&format!("/markets/{}", ticker)).await
@@ -327,12 +460,104 @@ impl RestClient {
         self.get(&format!("/markets/{}/orderbook", ticker)).await
     }
 
+    /// Compare a market's book in `manager` against a fresh REST snapshot.
+    ///
+    /// See [`OrderbookManager::reconcile`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] without fetching if `manager` does not track
+    /// the market, or the REST error if the snapshot cannot be fetched.
+    pub async fn reconcile_orderbook(
+        &self,
+        manager: &OrderbookManager,
+        ticker: &str,
+    ) -> Result<ReconcileReport, Error> {
+        if manager.get_state(ticker).is_none() {
+            return Err(Error::Config(format!("market {ticker} is not tracked")));
+        }
+        let snapshot = self.get_orderbook(ticker).await?.orderbook_fp;
+        manager.reconcile(ticker, &snapshot)
+    }
+
+    /// Estimate what a market order for `count` contracts would fill now.
+    ///
+    /// Fetches the orderbook and sweeps it from the best price, returning the
+    /// fillable quantity, total cost (or proceeds), average price and worst
+    /// price for the side traded. Use
+    /// [`MarketOrderEstimate::max_cost_cents`] to set `buy_max_cost` on a
+    /// [`CreateOrderRequest::market`] order. The book can move before the
+    /// order arrives, so leave some headroom.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the orderbook request fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use kalshi_trading::{Config, KalshiClient};
+    /// use kalshi_trading::types::{Action, CreateOrderRequest, Side};
+    ///
+    /// # async fn example(client: &KalshiClient) -> Result<(), kalshi_trading::Error> {
+    /// let estimate = client
+    ///     .rest()
+    ///     .estimate_market_order("KXBTC-25JAN", Side::Yes, Action::Buy, 10)
+    ///     .await?;
+    /// let mut order = CreateOrderRequest::market("KXBTC-25JAN", Side::Yes, Action::Buy, 10);
+    /// order.buy_max_cost = Some(estimate.max_cost_cents());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn estimate_market_order(
+        &self,
+        ticker: &str,
+        side: Side,
+        action: Action,
+        count: i64,
+    ) -> Result<MarketOrderEstimate, Error> {
+        let response = self.get_orderbook(ticker).await?;
+        let mut book = Orderbook::new(ticker);
+        book.seed_from_rest(&response.orderbook_fp);
+        Ok(estimate_from_book(&book, side, action, count * COUNT_SCALE))
+    }
+
     /// Get a list of events.
+    ///
+    /// Sends no `with_nested_markets` flag, so `Event::markets` is whatever
+    /// the API includes by default (currently nothing).
     pub async fn get_events(
         &self,
         series_ticker: Option<&str>,
         cursor: Option<&str>,
         limit: Option<u32>,
+    ) -> Result<GetEventsResponse, Error> {
+        self.events_request(series_ticker, None, cursor, limit)
+            .await
+    }
+
+    /// Get a list of events without their markets.
+    ///
+    /// Sends `with_nested_markets=false`, so the response carries only event
+    /// metadata and `Event::markets` is empty. Use this when browsing events
+    /// to avoid transferring and deserializing every market.
+    pub async fn get_events_lightweight(
+        &self,
+        series_ticker: Option<&str>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<GetEventsResponse, Error> {
+        self.events_request(series_ticker, Some(false), cursor, limit)
+            .await
+    }
+
+    /// `GET /events` with an optional `with_nested_markets` flag
+    async fn events_request(
+        &self,
+        series_ticker: Option<&str>,
+        with_nested_markets: Option<bool>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
     ) -> Result<GetEventsResponse, Error> {
         let mut path = "/events".to_string();
         let mut params = Vec::new();
@@ -340,6 +565,9 @@ impl RestClient {
         if let Some(s) = series_ticker {
             params.push(format!("series_ticker={}", s));
         }
+        if let Some(nested) = with_nested_markets {
+            params.push(format!("with_nested_markets={}", nested));
+        }
         if let Some(c) = cursor {
             params.push(format!("cursor={}", c));
         }
@@ -365,6 +593,64 @@ impl RestClient {
         self.get(&format!("/series/{}", series_ticker)).await
     }
 
+    /// Get every market in a series, across all of its events.
+    ///
+    /// Pages through the series' events, then through each event's markets.
+    /// Markets are returned grouped by event in the order the API lists them.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let markets = client.get_series_markets("KXBTC").await?;
+    /// println!("{} markets", markets.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_series_markets(&self, series_ticker: &str) -> Result<Vec<Market>, Error> {
+        let events = collect(
+            paginate(|cursor| async move {
+                let page = self
+                    .get_events(Some(series_ticker), cursor.as_deref(), None)
+                    .await?;
+                Ok((page.events, page.cursor))
+            }),
+            None,
+        )
+        .await?;
+
+        let mut markets = Vec::new();
+        for event in &events {
+            markets.extend(
+                self.get_all_markets(None, Some(&event.event_ticker), None)
+                    .await?,
+            );
+        }
+
+        Ok(markets)
+    }
+
+    /// Get every market matching the filters, following cursors.
+    ///
+    /// Stops early once `max_results` markets have been collected, even if
+    /// more pages remain.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let markets = client.get_all_markets(Some("open"), None, Some(1_000)).await?;
+    /// assert!(markets.len() <= 1_000);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_all_markets(
+        &self,
+        status: Option<&str>,
+        event_ticker: Option<&str>,
+        max_results: Option<usize>,
+    ) -> Result<Vec<Market>, Error> {
+        collect(self.markets_stream(status, event_ticker), max_results).await
+    }
+
     /// Get public trades for a market.
     pub async fn get_trades(
         &self,
@@ -392,6 +678,29 @@ impl RestClient {
 
         self.get(&path).await
     }
+
+    /// Get OHLC candlesticks for a market.
+    ///
+    /// # Arguments
+    ///
+    /// * `series_ticker` - Series the market belongs to
+    /// * `ticker` - Market ticker
+    /// * `start_ts` / `end_ts` - Time range in Unix seconds
+    /// * `period_interval` - Candle length in minutes (1, 60 or 1440)
+    pub async fn get_candlesticks(
+        &self,
+        series_ticker: &str,
+        ticker: &str,
+        start_ts: i64,
+        end_ts: i64,
+        period_interval: u32,
+    ) -> Result<GetCandlesticksResponse, Error> {
+        self.get(&format!(
+            "/series/{}/markets/{}/candlesticks?start_ts={}&end_ts={}&period_interval={}",
+            series_ticker, ticker, start_ts, end_ts, period_interval
+        ))
+        .await
+    }
 }
 
 // ============================================================================
@@ -401,6 +710,11 @@ impl RestClient {
 impl RestClient {
     /// Create a new order.
     ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] without sending anything if
+    /// [`CreateOrderRequest::validate`] rejects the order.
+    ///
     /// # Example
     /// ```rust,no_run
     /// use kalshi_trading::types::{CreateOrderRequest, Side, Action};
@@ -416,9 +730,131 @@ impl RestClient {
         &self,
         request: &CreateOrderRequest,
     ) -> Result<CreateOrderResponse, Error> {
+        request.validate()?;
+        self.post("/portfolio/orders", request).await
+    }
+
+    /// Create an order after checking it against `market`'s risk limit.
+    ///
+    /// `current_exposure_cents` is your existing exposure in the market, for
+    /// example [`Position::market_exposure_dollars`] divided by 100. The order is validated
+    /// and its worst-case cost compared with the remaining limit via
+    /// [`Market::check_risk_limit`] before anything is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] without submitting if the order is
+    /// invalid, is for a different market, or would exceed the risk limit.
+    pub async fn create_order_checked(
+        &self,
+        request: &CreateOrderRequest,
+        market: &Market,
+        current_exposure_cents: i64,
+    ) -> Result<CreateOrderResponse, Error> {
+        if request.ticker != market.ticker {
+            return Err(Error::InvalidOrder(format!(
+                "order is for {} but the risk limit is for {}",
+                request.ticker, market.ticker
+            )));
+        }
+        request.validate()?;
+        market.check_risk_limit(request, current_exposure_cents)?;
         self.post("/portfolio/orders", request).await
     }
 
+    /// Create an order only if its market stays open for at least `margin`.
+    ///
+    /// The market's `close_time` is fetched on first use and cached per
+    /// ticker for a minute, so later calls for the same market usually cost
+    /// no extra request. A cached close time that would reject the order is
+    /// fetched again first, in case the close was extended. Use
+    /// [`invalidate_close_time`](Self::invalidate_close_time) to drop an
+    /// entry sooner.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] without submitting if the market closes
+    /// within `margin` or has no parseable `close_time`.
+    #[cfg(feature = "chrono")]
+    pub async fn create_order_before_close(
+        &self,
+        request: &CreateOrderRequest,
+        margin: Duration,
+    ) -> Result<CreateOrderResponse, Error> {
+        let closes_within_margin = |close_time: DateTime<Utc>| {
+            let now = DateTime::<Utc>::from(SystemTime::now());
+            chrono::Duration::from_std(margin)
+                .ok()
+                .and_then(|margin| close_time.checked_sub_signed(margin))
+                .map_or(true, |deadline| now >= deadline)
+        };
+
+        let (mut close_time, cached) = self.market_close_time(&request.ticker, false).await?;
+        if cached && closes_within_margin(close_time) {
+            close_time = self.market_close_time(&request.ticker, true).await?.0;
+        }
+        if closes_within_margin(close_time) {
+            return Err(Error::InvalidOrder(format!(
+                "market {} closes at {}, within {:?}",
+                request.ticker,
+                close_time.to_rfc3339(),
+                margin
+            )));
+        }
+
+        self.create_order(request).await
+    }
+
+    /// Forget the cached close time for `ticker`, or for every market if
+    /// `None`
+    ///
+    /// The next [`create_order_before_close`](Self::create_order_before_close)
+    /// for the market fetches it again.
+    #[cfg(feature = "chrono")]
+    pub fn invalidate_close_time(&self, ticker: Option<&str>) {
+        let mut close_times = self.close_times.write();
+        match ticker {
+            Some(ticker) => {
+                close_times.remove(ticker);
+            }
+            None => close_times.clear(),
+        }
+    }
+
+    /// Close time for `ticker` and whether it came from the cache
+    ///
+    /// Entries older than [`CLOSE_TIME_TTL`], or any entry when `refresh`
+    /// is set, are fetched again.
+    #[cfg(feature = "chrono")]
+    async fn market_close_time(
+        &self,
+        ticker: &str,
+        refresh: bool,
+    ) -> Result<(DateTime<Utc>, bool), Error> {
+        if !refresh {
+            if let Some(&(close_time, fetched_at)) = self.close_times.read().get(ticker) {
+                if fetched_at.elapsed() < CLOSE_TIME_TTL {
+                    return Ok((close_time, true));
+                }
+            }
+        }
+
+        let market = self.get_market(ticker).await?.market;
+        let close_time = market.close_time_utc().ok_or_else(|| {
+            Error::InvalidOrder(format!(
+                "market {} has no valid close_time ({:?})",
+                ticker, market.close_time
+            ))
+        })?;
+
+        let now = DateTime::<Utc>::from(SystemTime::now());
+        let mut close_times = self.close_times.write();
+        // Closed markets can't take orders, so their entries are dead weight
+        close_times.retain(|_, (close_time, _)| *close_time > now);
+        close_times.insert(ticker.to_string(), (close_time, Instant::now()));
+        Ok((close_time, false))
+    }
+
     /// Get a list of orders with optional filters.
     pub async fn get_orders(
         &self,
@@ -447,6 +883,31 @@ impl RestClient {
         self.get(&path).await
     }
 
+    /// Get a list of orders filtered by a typed status.
+    ///
+    /// Same as [`get_orders`](Self::get_orders), but the status cannot be
+    /// misspelled. [`OrderStatusFilter::All`] omits the filter.
+    pub async fn get_orders_filtered(
+        &self,
+        ticker: Option<&str>,
+        status: OrderStatusFilter,
+        cursor: Option<&str>,
+    ) -> Result<GetOrdersResponse, Error> {
+        self.get_orders(ticker, status.as_query(), cursor).await
+    }
+
+    /// Get every order matching the filters, following cursors.
+    ///
+    /// Stops early once `max_results` orders have been collected.
+    pub async fn get_all_orders(
+        &self,
+        ticker: Option<&str>,
+        status: Option<&str>,
+        max_results: Option<usize>,
+    ) -> Result<Vec<Order>, Error> {
+        collect(self.orders_stream(ticker, status), max_results).await
+    }
+
     /// Get a specific order by ID.
     pub async fn get_order(&self, order_id: &str) -> Result<GetOrderResponse, Error> {
         self.get(&format!("/portfolio/orders/{}", order_id)).await
@@ -460,6 +921,51 @@ impl RestClient {
             .await
     }
 
+    /// Find an order by the `client_order_id` it was created with.
+    ///
+    /// The API has no direct lookup, so this pages through orders matching
+    /// `status` and stops at the first match. Returns `None` if no order has
+    /// that client id.
+    pub async fn get_order_by_client_id(
+        &self,
+        client_order_id: &str,
+        status: Option<&str>,
+    ) -> Result<Option<Order>, Error> {
+        let orders = self.orders_stream(None, status);
+        futures_util::pin_mut!(orders);
+        while let Some(order) = orders.try_next().await? {
+            if order.client_order_id == client_order_id {
+                return Ok(Some(order));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Cancel a resting order by its `client_order_id`.
+    ///
+    /// Resolves the server order ID with
+    /// [`get_order_by_client_id`](Self::get_order_by_client_id), then cancels
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if no resting order has that client id.
+    pub async fn cancel_order_by_client_id(
+        &self,
+        client_order_id: &str,
+    ) -> Result<CancelOrderResponse, Error> {
+        let order = self
+            .get_order_by_client_id(client_order_id, Some("resting"))
+            .await?
+            .ok_or_else(|| {
+                Error::InvalidOrder(format!(
+                    "no resting order with client_order_id {}",
+                    client_order_id
+                ))
+            })?;
+        self.cancel_order(&order.order_id).await
+    }
+
     /// Amend an order's price and/or quantity.
     ///
     /// The new count must be >= the current filled quantity.
@@ -485,10 +991,26 @@ impl RestClient {
     /// Batch create multiple orders (up to 20).
     ///
     /// Each order counts against your rate limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] without sending anything if
+    /// [`BatchCreateOrdersRequest::validate`] rejects any order. The message
+    /// lists every failure with its index.
     pub async fn batch_create_orders(
         &self,
         request: &BatchCreateOrdersRequest,
     ) -> Result<BatchCreateOrdersResponse, Error> {
+        request.validate().map_err(|errors| {
+            let details: Vec<String> = errors
+                .iter()
+                .map(|(i, e)| match e {
+                    Error::InvalidOrder(msg) => format!("order {i}: {msg}"),
+                    other => format!("order {i}: {other}"),
+                })
+                .collect();
+            Error::InvalidOrder(details.join("; "))
+        })?;
         self.post("/portfolio/orders/batched", request).await
     }
 
@@ -501,6 +1023,67 @@ impl RestClient {
             .await
     }
 
+    /// Cancel every resting order, optionally only in one market.
+    ///
+    /// Collects all resting orders first, then cancels them in batches of
+    /// [`BatchCancelOrdersRequest::MAX_BATCH_SIZE`]. Orders that fill or
+    /// cancel in the meantime come back with a per-order `error` rather than
+    /// failing the call. If a whole batch request fails, each of its orders
+    /// gets a result carrying that error, and the remaining batches are
+    /// still sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if listing the resting orders fails.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn example(client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let results = client.cancel_all_orders(None).await?;
+    /// let failed = results.iter().filter(|r| r.error.is_some()).count();
+    /// println!("cancelled {}, failed {}", results.len() - failed, failed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn cancel_all_orders(
+        &self,
+        ticker: Option<&str>,
+    ) -> Result<Vec<BatchCancelResult>, Error> {
+        let ids: Vec<String> = self
+            .get_all_orders(ticker, Some("resting"), None)
+            .await?
+            .into_iter()
+            .map(|order| order.order_id)
+            .collect();
+
+        let mut results = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(BatchCancelOrdersRequest::MAX_BATCH_SIZE) {
+            let request = BatchCancelOrdersRequest {
+                ids: Some(chunk.to_vec()),
+                orders: None,
+            };
+            match self.batch_cancel_orders(&request).await {
+                Ok(response) => results.extend(response.orders),
+                Err(e) => {
+                    let message = e.to_string();
+                    results.extend(chunk.iter().map(|order_id| BatchCancelResult {
+                        order_id: order_id.clone(),
+                        order: None,
+                        reduced_by_fp: 0,
+                        error: Some(BatchOrderError {
+                            code: None,
+                            message: message.clone(),
+                            details: None,
+                            service: None,
+                        }),
+                    }));
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
     /// Get queue positions for resting orders.
     pub async fn get_queue_positions(
         &self,
@@ -515,36 +1098,68 @@ impl RestClient {
         };
         self.get(&path).await
     }
+
+    /// Get an order group and the IDs of its member orders.
+    pub async fn get_order_group(&self, group_id: &str) -> Result<GetOrderGroupResponse, Error> {
+        self.get(&format!("/portfolio/order_groups/{}", group_id))
+            .await
+    }
+
+    /// Cancel every order in an order group and delete the group.
+    ///
+    /// Orders are tied to a group with
+    /// [`CreateOrderRequest::with_order_group`].
+    pub async fn cancel_order_group(
+        &self,
+        group_id: &str,
+    ) -> Result<CancelOrderGroupResponse, Error> {
+        self.delete(&format!("/portfolio/order_groups/{}", group_id))
+            .await
+    }
 }
 
 // ============================================================================
-// Portfolio API
+// RFQ API
 // ============================================================================
 
 impl RestClient {
-    /// Get account balance and portfolio value.
+    /// Create a request for quote (RFQ) for a block trade.
     ///
-    /// Returns values in centi-cents (divide by 100 for cents, 10000 for dollars).
-    pub async fn get_balance(&self) -> Result<GetBalanceResponse, Error> {
-        self.get("/portfolio/balance").await
+    /// # Example
+    /// ```rust,no_run
+    /// use kalshi_trading::types::CreateRfqRequest;
+    ///
+    /// # async fn example(client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let rfq = CreateRfqRequest::new("KXBTC-25JAN", 500);
+    /// let response = client.create_rfq(&rfq).await?;
+    /// println!("RFQ ID: {}", response.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_rfq(&self, request: &CreateRfqRequest) -> Result<CreateRfqResponse, Error> {
+        self.post("/communications/rfqs", request).await
     }
 
-    /// Get positions in markets.
-    pub async fn get_positions(
+    /// Get a list of RFQs with optional filters.
+    pub async fn get_rfqs(
         &self,
-        ticker: Option<&str>,
-        event_ticker: Option<&str>,
+        market_ticker: Option<&str>,
+        status: Option<RfqStatus>,
         cursor: Option<&str>,
         limit: Option<u32>,
-    ) -> Result<GetPositionsResponse, Error> {
-        let mut path = "/portfolio/positions".to_string();
+    ) -> Result<GetRfqsResponse, Error> {
+        let mut path = "/communications/rfqs".to_string();
         let mut params = Vec::new();
 
-        if let Some(t) = ticker {
-            params.push(format!("ticker={}", t));
+        if let Some(t) = market_ticker {
+            params.push(format!("market_ticker={}", t));
         }
-        if let Some(e) = event_ticker {
-            params.push(format!("event_ticker={}", e));
+        if let Some(s) = status {
+            let status = match s {
+                RfqStatus::Open => "open",
+                RfqStatus::Closed => "closed",
+            };
+            params.push(format!("status={}", status));
         }
         if let Some(c) = cursor {
             params.push(format!("cursor={}", c));
@@ -561,12 +1176,170 @@ impl RestClient {
         self.get(&path).await
     }
 
-    /// Get fills (matched trades) for your orders.
-    pub async fn get_fills(
+    /// Quote on an open RFQ.
+    pub async fn create_quote(
         &self,
-        ticker: Option<&str>,
-        order_id: Option<&str>,
-        cursor: Option<&str>,
+        request: &CreateQuoteRequest,
+    ) -> Result<CreateQuoteResponse, Error> {
+        self.post("/communications/quotes", request).await
+    }
+}
+
+// ============================================================================
+// Portfolio API
+// ============================================================================
+
+impl RestClient {
+    /// Get account balance and portfolio value.
+    ///
+    /// Returns values in centi-cents (divide by 100 for cents, 10000 for dollars).
+    /// Scoped to the subaccount from [`Config::with_subaccount`], if set.
+    pub async fn get_balance(&self) -> Result<GetBalanceResponse, Error> {
+        self.balance_request(self.subaccount).await
+    }
+
+    /// Get balance and portfolio value for a specific subaccount.
+    ///
+    /// Overrides the subaccount from [`Config::with_subaccount`].
+    pub async fn get_balance_for_subaccount(
+        &self,
+        subaccount: u32,
+    ) -> Result<GetBalanceResponse, Error> {
+        self.balance_request(Some(subaccount)).await
+    }
+
+    async fn balance_request(&self, subaccount: Option<u32>) -> Result<GetBalanceResponse, Error> {
+        let path = match subaccount {
+            Some(n) => format!("/portfolio/balance?subaccount={}", n),
+            None => "/portfolio/balance".to_string(),
+        };
+        self.get(&path).await
+    }
+
+    /// Get positions in markets.
+    ///
+    /// Scoped to the subaccount from [`Config::with_subaccount`], if set.
+    pub async fn get_positions(
+        &self,
+        ticker: Option<&str>,
+        event_ticker: Option<&str>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<GetPositionsResponse, Error> {
+        self.positions_request(
+            self.subaccount,
+            ticker,
+            event_ticker,
+            SettlementStatus::All,
+            cursor,
+            limit,
+        )
+        .await
+    }
+
+    /// Get positions filtered by settlement status.
+    ///
+    /// Same as [`get_positions`](Self::get_positions), but lets the server
+    /// drop settled (or unsettled) positions instead of paging through them.
+    /// [`SettlementStatus::All`] omits the filter.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use kalshi_trading::types::SettlementStatus;
+    ///
+    /// # async fn example(client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let open = client
+    ///     .get_positions_filtered(None, None, SettlementStatus::Unsettled, None, None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_positions_filtered(
+        &self,
+        ticker: Option<&str>,
+        event_ticker: Option<&str>,
+        settlement_status: SettlementStatus,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<GetPositionsResponse, Error> {
+        self.positions_request(
+            self.subaccount,
+            ticker,
+            event_ticker,
+            settlement_status,
+            cursor,
+            limit,
+        )
+        .await
+    }
+
+    /// Get positions for a specific subaccount.
+    ///
+    /// Overrides the subaccount from [`Config::with_subaccount`].
+    pub async fn get_positions_for_subaccount(
+        &self,
+        subaccount: u32,
+        ticker: Option<&str>,
+        event_ticker: Option<&str>,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<GetPositionsResponse, Error> {
+        self.positions_request(
+            Some(subaccount),
+            ticker,
+            event_ticker,
+            SettlementStatus::All,
+            cursor,
+            limit,
+        )
+        .await
+    }
+
+    async fn positions_request(
+        &self,
+        subaccount: Option<u32>,
+        ticker: Option<&str>,
+        event_ticker: Option<&str>,
+        settlement_status: SettlementStatus,
+        cursor: Option<&str>,
+        limit: Option<u32>,
+    ) -> Result<GetPositionsResponse, Error> {
+        let mut path = "/portfolio/positions".to_string();
+        let mut params = Vec::new();
+
+        if let Some(t) = ticker {
+            params.push(format!("ticker={}", t));
+        }
+        if let Some(e) = event_ticker {
+            params.push(format!("event_ticker={}", e));
+        }
+        if let Some(s) = settlement_status.as_query() {
+            params.push(format!("settlement_status={}", s));
+        }
+        if let Some(c) = cursor {
+            params.push(format!("cursor={}", c));
+        }
+        if let Some(l) = limit {
+            params.push(format!("limit={}", l));
+        }
+        if let Some(n) = subaccount {
+            params.push(format!("subaccount={}", n));
+        }
+
+        if !params.is_empty() {
+            path.push('?');
+            path.push_str(&params.join("&"));
+        }
+
+        self.get(&path).await
+    }
+
+    /// Get fills (matched trades) for your orders.
+    pub async fn get_fills(
+        &self,
+        ticker: Option<&str>,
+        order_id: Option<&str>,
+        cursor: Option<&str>,
         limit: Option<u32>,
     ) -> Result<GetFillsResponse, Error> {
         let mut path = "/portfolio/fills".to_string();
@@ -593,6 +1366,18 @@ impl RestClient {
         self.get(&path).await
     }
 
+    /// Get every fill matching the filters, following cursors.
+    ///
+    /// Stops early once `max_results` fills have been collected.
+    pub async fn get_all_fills(
+        &self,
+        ticker: Option<&str>,
+        order_id: Option<&str>,
+        max_results: Option<usize>,
+    ) -> Result<Vec<Fill>, Error> {
+        collect(self.fills_stream(ticker, order_id), max_results).await
+    }
+
     /// Get settlement history.
     pub async fn get_settlements(
         &self,
@@ -622,23 +1407,1120 @@ impl RestClient {
     }
 }
 
+// ============================================================================
+// Paginated streams
+// ============================================================================
+
+impl RestClient {
+    /// Stream every market matching the filters, following cursors.
+    ///
+    /// Pages are fetched lazily as the stream is polled. The stream yields
+    /// the first error and then ends.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use futures_util::{pin_mut, StreamExt};
+    ///
+    /// # async fn example(client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let markets = client.markets_stream(Some("open"), None);
+    /// pin_mut!(markets);
+    /// while let Some(market) = markets.next().await {
+    ///     println!("{}", market?.ticker);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn markets_stream<'a>(
+        &'a self,
+        status: Option<&'a str>,
+        event_ticker: Option<&'a str>,
+    ) -> impl Stream<Item = Result<Market, Error>> + 'a {
+        paginate(move |cursor| async move {
+            let page = self
+                .get_markets(status, event_ticker, cursor.as_deref())
+                .await?;
+            Ok((page.markets, page.cursor))
+        })
+    }
+
+    /// Stream every order matching the filters, following cursors.
+    pub fn orders_stream<'a>(
+        &'a self,
+        ticker: Option<&'a str>,
+        status: Option<&'a str>,
+    ) -> impl Stream<Item = Result<Order, Error>> + 'a {
+        paginate(move |cursor| async move {
+            let page = self.get_orders(ticker, status, cursor.as_deref()).await?;
+            Ok((page.orders, page.cursor))
+        })
+    }
+
+    /// Stream every fill matching the filters, following cursors.
+    pub fn fills_stream<'a>(
+        &'a self,
+        ticker: Option<&'a str>,
+        order_id: Option<&'a str>,
+    ) -> impl Stream<Item = Result<Fill, Error>> + 'a {
+        paginate(move |cursor| async move {
+            let page = self
+                .get_fills(ticker, order_id, cursor.as_deref(), None)
+                .await?;
+            Ok((page.fills, page.cursor))
+        })
+    }
+
+    /// Stream every market position matching the filters, following cursors.
+    ///
+    /// Event-level positions are not included; use
+    /// [`get_positions`](Self::get_positions) for those.
+    pub fn positions_stream<'a>(
+        &'a self,
+        ticker: Option<&'a str>,
+        event_ticker: Option<&'a str>,
+    ) -> impl Stream<Item = Result<Position, Error>> + 'a {
+        paginate(move |cursor| async move {
+            let page = self
+                .get_positions(ticker, event_ticker, cursor.as_deref(), None)
+                .await?;
+            Ok((page.market_positions, page.cursor))
+        })
+    }
+
+    /// Stream every public trade, optionally for one market, following cursors.
+    pub fn trades_stream<'a>(
+        &'a self,
+        ticker: Option<&'a str>,
+    ) -> impl Stream<Item = Result<Trade, Error>> + 'a {
+        paginate(move |cursor| async move {
+            let page = self.get_trades(ticker, cursor.as_deref(), None).await?;
+            Ok((page.trades, page.cursor))
+        })
+    }
+
+    /// Stream every settlement, optionally for one market, following cursors.
+    pub fn settlements_stream<'a>(
+        &'a self,
+        ticker: Option<&'a str>,
+    ) -> impl Stream<Item = Result<Settlement, Error>> + 'a {
+        paginate(move |cursor| async move {
+            let page = self
+                .get_settlements(ticker, cursor.as_deref(), None)
+                .await?;
+            Ok((page.settlements, page.cursor))
+        })
+    }
+}
+
 // ============================================================================
 // Exchange API
 // ============================================================================
 
 impl RestClient {
     /// Get exchange status (trading active, exchange active).
+    ///
+    /// During maintenance the status may also carry an estimated resume
+    /// time and announcements.
     pub async fn get_exchange_status(&self) -> Result<ExchangeStatus, Error> {
         self.get("/exchange/status").await
     }
 
+    /// Check whether the exchange is accepting orders.
+    ///
+    /// True when both `trading_active` and `exchange_active` are set. Bots
+    /// can call this before a trading cycle instead of attempting orders
+    /// that would be rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the status request fails.
+    pub async fn is_trading_open(&self) -> Result<bool, Error> {
+        Ok(self.get_exchange_status().await?.is_trading_open())
+    }
+
+    /// Get exchange-wide announcements.
+    pub async fn get_exchange_announcements(
+        &self,
+    ) -> Result<GetExchangeAnnouncementsResponse, Error> {
+        self.get("/exchange/announcements").await
+    }
+
     /// Get exchange schedule.
     pub async fn get_exchange_schedule(&self) -> Result<GetExchangeScheduleResponse, Error> {
         self.get("/exchange/schedule").await
     }
 }
 
+/// Parse a `Retry-After` header into milliseconds
+///
+/// Accepts both forms allowed by RFC 9110: a number of seconds, or an HTTP
+/// date, which is measured from now (zero if already past).
+fn parse_retry_after(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(seconds.saturating_mul(1000));
+    }
+    let at = httpdate::parse_http_date(value).ok()?;
+    let wait = at
+        .duration_since(SystemTime::now())
+        .unwrap_or(Duration::ZERO);
+    Some(u64::try_from(wait.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// Stream items across pages, following cursors until one is missing or empty
+///
+/// `fetch` receives the cursor for the next page (`None` for the first) and
+/// returns that page's items and cursor. The stream ends after yielding the
+/// first error.
+fn paginate<'a, T, F, Fut>(fetch: F) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: 'a,
+    F: FnMut(Option<String>) -> Fut + 'a,
+    Fut: Future<Output = Result<(Vec<T>, Option<String>), Error>> + 'a,
+{
+    struct State<T, F> {
+        fetch: F,
+        page: std::vec::IntoIter<T>,
+        cursor: Option<String>,
+        done: bool,
+    }
+
+    let state = State {
+        fetch,
+        page: Vec::new().into_iter(),
+        cursor: None,
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(item) = state.page.next() {
+                return Some((Ok(item), state));
+            }
+            if state.done {
+                return None;
+            }
+            match (state.fetch)(state.cursor.take()).await {
+                Ok((items, next)) => {
+                    state.page = items.into_iter();
+                    match next {
+                        Some(c) if !c.is_empty() => state.cursor = Some(c),
+                        _ => state.done = true,
+                    }
+                }
+                Err(e) => {
+                    state.done = true;
+                    return Some((Err(e), state));
+                }
+            }
+        }
+    })
+}
+
+/// Sweep `book` for a market order of `count_fp` contracts
+///
+/// Buying Yes or selling No takes the Yes asks; the other two hit the Yes
+/// bids. No totals and prices are converted from the Yes ladder.
+fn estimate_from_book(
+    book: &Orderbook,
+    side: Side,
+    action: Action,
+    count_fp: i64,
+) -> MarketOrderEstimate {
+    let takes_asks = matches!(
+        (side, action),
+        (Side::Yes, Action::Buy) | (Side::No, Action::Sell)
+    );
+    let swept = if takes_asks {
+        book.buy_cost(count_fp)
+    } else {
+        book.sell_proceeds(count_fp)
+    };
+    let Some((filled, yes_total)) = swept else {
+        return MarketOrderEstimate {
+            fillable_fp: 0,
+            total_dollars: 0,
+            average_price: None,
+            worst_price_dollars: None,
+        };
+    };
+
+    let reaches = |&(_, total): &(i64, i64)| total >= filled;
+    let yes_worst = if takes_asks {
+        book.cumulative_asks().find(reaches)
+    } else {
+        book.cumulative_bids().find(reaches)
+    }
+    .map(|(price, _)| price);
+
+    let (total, worst) = match side {
+        Side::Yes => (yes_total, yes_worst),
+        Side::No => (
+            filled * DOLLAR_SCALE / COUNT_SCALE - yes_total,
+            yes_worst.map(|price| DOLLAR_SCALE - price),
+        ),
+    };
+    MarketOrderEstimate {
+        fillable_fp: filled,
+        total_dollars: total,
+        average_price: Some(total as f64 * COUNT_SCALE as f64 / filled as f64),
+        worst_price_dollars: worst,
+    }
+}
+
+/// Collect a paginated stream, stopping after `max_results` items
+async fn collect<T>(
+    stream: impl Stream<Item = Result<T, Error>>,
+    max_results: Option<usize>,
+) -> Result<Vec<T>, Error> {
+    stream
+        .take(max_results.unwrap_or(usize::MAX))
+        .try_collect()
+        .await
+}
+
 #[cfg(test)]
 mod tests {
-    // Integration tests would go here with mock server or test credentials
+    use super::*;
+    use crate::client::testing::{
+        market_json, mock_client, order_json, resting_order_json, test_private_key_pem,
+        MockResponse, MockServer, TEST_CERT_PEM, TEST_KEY_PEM,
+    };
+    use crate::config::RateLimitTier;
+    use crate::error::ApiErrorKind;
+
+    #[tokio::test]
+    async fn test_exchange_status_and_announcements() {
+        let server = MockServer::start(|req| match req.path.as_str() {
+            "/exchange/status" => MockResponse::json(
+                r#"{"trading_active":false,"exchange_active":true,
+                    "exchange_estimated_resume_time":"2026-01-05T14:00:00Z"}"#,
+            ),
+            "/exchange/announcements" => MockResponse::json(
+                r#"{"announcements":[{"type":"info","message":"Maintenance tonight",
+                    "delivery_time":"2026-01-05T12:00:00Z","status":"active"}]}"#,
+            ),
+            other => panic!("unexpected request {}", other),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        assert!(!client.is_trading_open().await.unwrap());
+        let status = client.get_exchange_status().await.unwrap();
+        assert!(status.exchange_estimated_resume_time.is_some());
+        #[cfg(feature = "chrono")]
+        assert!(status.estimated_resume_time_utc().is_some());
+
+        let response = client.get_exchange_announcements().await.unwrap();
+        assert_eq!(response.announcements.len(), 1);
+        assert_eq!(response.announcements[0].message, "Maintenance tonight");
+    }
+
+    #[tokio::test]
+    async fn test_custom_tls_certificates() {
+        let certificate = reqwest::Certificate::from_pem(TEST_CERT_PEM.as_bytes()).unwrap();
+        let identity =
+            reqwest::Identity::from_pem(format!("{}{}", TEST_KEY_PEM, TEST_CERT_PEM).as_bytes())
+                .unwrap();
+        let config = Config::new("key", test_private_key_pem())
+            .with_root_certificate(certificate)
+            .with_tls_client_cert(identity);
+        assert_eq!(config.root_certificates().len(), 1);
+        assert!(config.tls_client_cert().is_some());
+
+        // Plain HTTP is unaffected by the TLS settings
+        let server = MockServer::start(|_| {
+            MockResponse::json(r#"{"trading_active":true,"exchange_active":true}"#)
+        })
+        .await;
+        let client = mock_client(&server, config);
+        assert!(client.get_exchange_status().await.unwrap().trading_active);
+    }
+
+    #[tokio::test]
+    async fn test_balance_uses_configured_subaccount() {
+        let server =
+            MockServer::start(|_| MockResponse::json(r#"{"balance":100,"portfolio_value":0}"#))
+                .await;
+        let config = Config::new("key", test_private_key_pem()).with_subaccount(Some(3));
+        let client = mock_client(&server, config);
+
+        let balance = client.get_balance().await.unwrap();
+        assert_eq!(balance.balance, 100);
+        client.get_balance_for_subaccount(7).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/portfolio/balance?subaccount=3");
+        assert_eq!(requests[0].header(AuthHeaders::KEY_HEADER), Some("key"));
+        assert_eq!(requests[1].path, "/portfolio/balance?subaccount=7");
+    }
+
+    #[test]
+    fn test_rate_limit_tier_sets_limiter_rates() {
+        let client = RestClient::new(&Config::new("key", test_private_key_pem())).unwrap();
+        assert!(client.read_limiter.is_none());
+        assert!(client.write_limiter.is_none());
+
+        for (tier, reads, writes) in [
+            (RateLimitTier::Basic, 20, 10),
+            (RateLimitTier::Advanced, 30, 30),
+            (RateLimitTier::MarketMaker, 100, 100),
+        ] {
+            let config = Config::new("key", test_private_key_pem()).with_rate_limit_tier(tier);
+            let client = RestClient::new(&config).unwrap();
+            assert_eq!(client.read_limiter.as_ref().unwrap().per_second(), reads);
+            assert_eq!(client.write_limiter.as_ref().unwrap().per_second(), writes);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_with_connect_timeout() {
+        let server =
+            MockServer::start(|_| MockResponse::json(r#"{"balance":100,"portfolio_value":0}"#))
+                .await;
+        let config = Config::new("key", test_private_key_pem())
+            .with_timeout(Duration::from_secs(30))
+            .with_connect_timeout(Duration::from_millis(500));
+        assert_eq!(config.timeout(), Duration::from_secs(30));
+        assert_eq!(config.connect_timeout(), Some(Duration::from_millis(500)));
+
+        let client = mock_client(&server, config);
+        assert_eq!(client.get_balance().await.unwrap().balance, 100);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_orderbook() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(
+                r#"{"orderbook_fp":{"yes_dollars":[["0.4000","10.00"]],"no_dollars":[]}}"#,
+            )
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+        let manager = OrderbookManager::new();
+
+        let err = client
+            .reconcile_orderbook(&manager, "TEST")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        assert!(server.requests().is_empty());
+
+        // The empty local book is missing the snapshot's one level
+        manager.add_market("TEST");
+        let report = client.reconcile_orderbook(&manager, "TEST").await.unwrap();
+        assert_eq!(report.discrepancies.len(), 1);
+        assert_eq!(server.requests()[0].path, "/markets/TEST/orderbook");
+    }
+
+    #[tokio::test]
+    async fn test_estimate_market_order() {
+        let server = MockServer::start(|req| match req.path.as_str() {
+            // Yes bids at $0.45 and $0.40; No bids make Yes asks at $0.50
+            // and $0.55
+            "/markets/TEST/orderbook" => MockResponse::json(
+                r#"{"orderbook_fp":{
+                    "yes_dollars":[["0.4000","10.00"],["0.4500","5.00"]],
+                    "no_dollars":[["0.5000","4.00"],["0.4500","10.00"]]
+                }}"#,
+            ),
+            "/markets/EMPTY/orderbook" => {
+                MockResponse::json(r#"{"orderbook_fp":{"yes_dollars":[],"no_dollars":[]}}"#)
+            }
+            other => panic!("unexpected request {}", other),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        // 4 @ $0.50 + 4 @ $0.55
+        let buy = client
+            .estimate_market_order("TEST", Side::Yes, Action::Buy, 8)
+            .await
+            .unwrap();
+        assert_eq!(buy.fillable_fp, 800);
+        assert_eq!(buy.total_dollars, 42_000);
+        assert_eq!(buy.average_price, Some(5_250.0));
+        assert_eq!(buy.worst_price_dollars, Some(5_500));
+        assert_eq!(buy.max_cost_cents(), 420);
+
+        // No asks are $0.55 (5) and $0.60 (10); only 15 of 20 can fill
+        let buy_no = client
+            .estimate_market_order("TEST", Side::No, Action::Buy, 20)
+            .await
+            .unwrap();
+        assert_eq!(buy_no.fillable_fp, 1_500);
+        assert_eq!(buy_no.total_dollars, 87_500);
+        assert_eq!(buy_no.worst_price_dollars, Some(6_000));
+
+        // Selling Yes hits the bids: 5 @ $0.45 + 1 @ $0.40
+        let sell = client
+            .estimate_market_order("TEST", Side::Yes, Action::Sell, 6)
+            .await
+            .unwrap();
+        assert_eq!(sell.total_dollars, 26_500);
+        assert_eq!(sell.worst_price_dollars, Some(4_000));
+
+        let empty = client
+            .estimate_market_order("EMPTY", Side::Yes, Action::Buy, 5)
+            .await
+            .unwrap();
+        assert_eq!(empty.fillable_fp, 0);
+        assert_eq!(empty.average_price, None);
+        assert_eq!(empty.worst_price_dollars, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_events_lightweight() {
+        let server = MockServer::start(|req| match req.path.as_str() {
+            "/events?series_ticker=SER&with_nested_markets=false&limit=5" => MockResponse::json(
+                r#"{"events":[{"event_ticker":"EV1","series_ticker":"SER","title":""}],"cursor":""}"#,
+            ),
+            other => panic!("unexpected request {}", other),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let response = client
+            .get_events_lightweight(Some("SER"), None, Some(5))
+            .await
+            .unwrap();
+        assert_eq!(response.events.len(), 1);
+        assert!(response.events[0].markets.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_series_markets() {
+        let server = MockServer::start(|req| {
+            let body = match req.path.as_str() {
+                "/events?series_ticker=SER" => {
+                    r#"{"events":[{"event_ticker":"EV1","series_ticker":"SER","title":""}],"cursor":"p2"}"#
+                        .to_string()
+                }
+                "/events?series_ticker=SER&cursor=p2" => {
+                    r#"{"events":[{"event_ticker":"EV2","series_ticker":"SER","title":""}],"cursor":""}"#
+                        .to_string()
+                }
+                "/markets?event_ticker=EV1" => format!(
+                    r#"{{"markets":[{}],"cursor":"m2"}}"#,
+                    market_json("EV1-A", "EV1")
+                ),
+                "/markets?event_ticker=EV1&cursor=m2" => format!(
+                    r#"{{"markets":[{}],"cursor":null}}"#,
+                    market_json("EV1-B", "EV1")
+                ),
+                "/markets?event_ticker=EV2" => format!(
+                    r#"{{"markets":[{}]}}"#,
+                    market_json("EV2-A", "EV2")
+                ),
+                other => panic!("unexpected request {}", other),
+            };
+            MockResponse::json(body)
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let markets = client.get_series_markets("SER").await.unwrap();
+        let tickers: Vec<_> = markets.iter().map(|m| m.ticker.as_str()).collect();
+        assert_eq!(tickers, ["EV1-A", "EV1-B", "EV2-A"]);
+        assert_eq!(server.requests().len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_get_candlesticks() {
+        let server = MockServer::start(|req| {
+            assert_eq!(
+                req.path,
+                "/series/KXBTC/markets/KXBTC-25JAN/candlesticks?start_ts=100&end_ts=200&period_interval=60"
+            );
+            MockResponse::json(
+                r#"{"ticker":"KXBTC-25JAN","candlesticks":[
+                    {"end_period_ts":160,"price":{"open_dollars":"0.4500","high_dollars":"0.5200",
+                     "low_dollars":"0.4400","close_dollars":"0.5100"},
+                     "volume_fp":"12.00","open_interest_fp":"30.00"},
+                    {"end_period_ts":200,"price":{"open":null,"close":null},
+                     "volume":0,"open_interest":30}
+                ]}"#,
+            )
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let response = client
+            .get_candlesticks("KXBTC", "KXBTC-25JAN", 100, 200, 60)
+            .await
+            .unwrap();
+        let [traded, quiet] = response.candlesticks.as_slice() else {
+            panic!("expected two candlesticks");
+        };
+        assert_eq!(traded.end_period_ts, 160);
+        assert_eq!(
+            (traded.open, traded.high, traded.low, traded.close),
+            (Some(4_500), Some(5_200), Some(4_400), Some(5_100))
+        );
+        assert_eq!(traded.volume_fp, 1_200);
+        assert_eq!(traded.open_interest_fp, 3_000);
+
+        assert_eq!(quiet.close, None);
+        assert_eq!(quiet.volume_fp, 0);
+        assert_eq!(quiet.open_interest_fp, 3_000);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders() {
+        let page = |range: std::ops::Range<usize>, cursor: &str| {
+            let orders: Vec<_> = range
+                .map(|i| resting_order_json(&format!("o{}", i)))
+                .collect();
+            format!(
+                r#"{{"orders":[{}],"cursor":"{}"}}"#,
+                orders.join(","),
+                cursor
+            )
+        };
+        let (first, second) = (page(0..15, "p2"), page(15..25, ""));
+        let server = MockServer::start(move |req| {
+            match (req.method.as_str(), req.path.as_str()) {
+                ("GET", "/portfolio/orders?ticker=T&status=resting") => {
+                    MockResponse::json(first.clone())
+                }
+                ("GET", "/portfolio/orders?ticker=T&status=resting&cursor=p2") => {
+                    MockResponse::json(second.clone())
+                }
+                ("DELETE", "/portfolio/orders/batched") => {
+                    let body: serde_json::Value = serde_json::from_str(&req.body).unwrap();
+                    let ids = body["ids"].as_array().unwrap();
+                    if ids.len() < BatchCancelOrdersRequest::MAX_BATCH_SIZE {
+                        return MockResponse::status(500, r#"{"error":"internal"}"#);
+                    }
+                    // o0 already executed; the rest cancel
+                    let results: Vec<_> = ids
+                        .iter()
+                        .map(|id| match id.as_str().unwrap() {
+                            "o0" => r#"{"order_id":"o0","reduced_by_fp":"0.00","error":{"message":"not found"}}"#
+                                .to_string(),
+                            id => format!(r#"{{"order_id":"{}","reduced_by_fp":"1.00"}}"#, id),
+                        })
+                        .collect();
+                    MockResponse::json(format!(r#"{{"orders":[{}]}}"#, results.join(",")))
+                }
+                other => panic!("unexpected request {:?}", other),
+            }
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let results = client.cancel_all_orders(Some("T")).await.unwrap();
+        assert_eq!(results.len(), 25);
+        let failed: Vec<_> = results
+            .iter()
+            .filter(|r| r.error.is_some())
+            .map(|r| r.order_id.as_str())
+            .collect();
+        // o0 failed individually; o20..o24 were in the failed second batch
+        assert_eq!(failed, ["o0", "o20", "o21", "o22", "o23", "o24"]);
+        assert_eq!(results[1].reduced_by_fp, 100);
+
+        let deletes = server
+            .requests()
+            .into_iter()
+            .filter(|r| r.method == "DELETE")
+            .count();
+        assert_eq!(deletes, 2);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_order_by_client_id() {
+        let with_client_id = |order_id: &str, client_order_id: &str| {
+            let mut order = order_json(order_id, "0.00", "1.00", Some("1.00"));
+            order["client_order_id"] = client_order_id.into();
+            order
+        };
+        let orders = serde_json::json!({
+            "orders": [with_client_id("o1", "a"), with_client_id("o2", "b")],
+            "cursor": ""
+        })
+        .to_string();
+        let server = MockServer::start(move |req| match (req.method.as_str(), req.path.as_str()) {
+            ("GET", "/portfolio/orders?status=resting") => MockResponse::json(orders.clone()),
+            ("DELETE", "/portfolio/orders/o2") => MockResponse::json(format!(
+                r#"{{"order":{},"reduced_by_fp":"1.00"}}"#,
+                resting_order_json("o2")
+            )),
+            other => panic!("unexpected request {:?}", other),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let response = client.cancel_order_by_client_id("b").await.unwrap();
+        assert_eq!(response.order.order_id, "o2");
+        assert_eq!(response.reduced_by_fp, 100);
+
+        let err = client
+            .cancel_order_by_client_id("missing")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidOrder(_)));
+        let deletes = server
+            .requests()
+            .into_iter()
+            .filter(|r| r.method == "DELETE")
+            .count();
+        assert_eq!(deletes, 1);
+    }
+
+    #[tokio::test]
+    async fn test_order_group_endpoints() {
+        let server = MockServer::start(|req| match (req.method.as_str(), req.path.as_str()) {
+            ("GET", "/portfolio/order_groups/g1") => {
+                MockResponse::json(r#"{"is_auto_cancel_enabled":true,"orders":["o1","o2"]}"#)
+            }
+            ("DELETE", "/portfolio/order_groups/g1") => MockResponse::json("{}"),
+            other => panic!("unexpected request {:?}", other),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let group = client.get_order_group("g1").await.unwrap();
+        assert!(group.is_auto_cancel_enabled);
+        assert_eq!(group.orders, ["o1", "o2"]);
+
+        client.cancel_order_group("g1").await.unwrap();
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "DELETE");
+    }
+
+    #[cfg(feature = "chrono")]
+    fn market_closing_at(ticker: &str, close_time: &str) -> String {
+        market_json(ticker, "EV").replace(
+            r#""close_time": """#,
+            &format!(r#""close_time": "{}""#, close_time),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_all_markets_stops_at_max_results() {
+        // Every page has two markets and a cursor to another page
+        let server = MockServer::start(|_| {
+            MockResponse::json(format!(
+                r#"{{"markets":[{},{}],"cursor":"next"}}"#,
+                market_json("A", "EV"),
+                market_json("B", "EV")
+            ))
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let markets = client.get_all_markets(None, None, Some(3)).await.unwrap();
+        assert_eq!(markets.len(), 3);
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(paths, ["/markets", "/markets?cursor=next"]);
+    }
+
+    #[tokio::test]
+    async fn test_markets_stream_follows_cursors() {
+        let server = MockServer::start(|req| {
+            let body = match req.path.as_str() {
+                "/markets?status=open" => format!(
+                    r#"{{"markets":[{},{}],"cursor":"p2"}}"#,
+                    market_json("A", "EV"),
+                    market_json("B", "EV")
+                ),
+                // An empty-string cursor ends pagination like a missing one
+                "/markets?status=open&cursor=p2" => {
+                    format!(r#"{{"markets":[{}],"cursor":""}}"#, market_json("C", "EV"))
+                }
+                other => panic!("unexpected request {}", other),
+            };
+            MockResponse::json(body)
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let markets: Vec<Market> = client
+            .markets_stream(Some("open"), None)
+            .try_collect()
+            .await
+            .unwrap();
+        let tickers: Vec<_> = markets.iter().map(|m| m.ticker.as_str()).collect();
+        assert_eq!(tickers, ["A", "B", "C"]);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stream_stops_on_error() {
+        let server = MockServer::start(|req| match req.path.as_str() {
+            "/markets" => MockResponse::json(format!(
+                r#"{{"markets":[{}],"cursor":"p2"}}"#,
+                market_json("A", "EV")
+            )),
+            _ => MockResponse::status(500, "boom"),
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let results: Vec<_> = client.markets_stream(None, None).collect().await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().ticker, "A");
+        assert!(results[1].is_err());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_nested_api_error_code() {
+        let server = MockServer::start(|_| {
+            MockResponse::status(
+                400,
+                r#"{"error":{"code":"insufficient_balance","message":"Insufficient balance"}}"#,
+            )
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let Err(Error::Api(err)) = client.get_balance().await else {
+            panic!("expected an API error");
+        };
+        assert_eq!(err.status, 400);
+        assert_eq!(err.code.as_deref(), Some("insufficient_balance"));
+        assert_eq!(err.message, "Insufficient balance");
+        assert_eq!(err.kind(), ApiErrorKind::InsufficientBalance);
+    }
+
+    /// Server that answers 429 `failures` times, then returns `body`
+    async fn rate_limited_server(failures: usize, body: impl Into<String>) -> MockServer {
+        let body = body.into();
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        MockServer::start(move |_| {
+            if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) < failures {
+                MockResponse::status(429, "").with_header("Retry-After", "1")
+            } else {
+                MockResponse::json(body.clone())
+            }
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_get_retries_rate_limits() {
+        let body = r#"{"balance":100,"portfolio_value":0}"#;
+        let server = rate_limited_server(2, body).await;
+        let config = Config::new("key", test_private_key_pem()).with_max_retries(2);
+        let client = mock_client(&server, config);
+
+        // Each retry waits out the one-second Retry-After. A paused clock
+        // would auto-advance into reqwest's request timeout, so this uses
+        // real time.
+        let start = std::time::Instant::now();
+        assert_eq!(client.get_balance().await.unwrap().balance, 100);
+        assert!(start.elapsed() >= Duration::from_secs(2));
+        assert_eq!(server.requests().len(), 3);
+
+        // Retries exhausted
+        let server = rate_limited_server(3, body).await;
+        let config = Config::new("key", test_private_key_pem()).with_max_retries(2);
+        let client = mock_client(&server, config);
+
+        let err = client.get_balance().await.unwrap_err();
+        assert!(matches!(err, Error::RateLimited { .. }));
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("5"), Some(5_000));
+        assert_eq!(parse_retry_after(" 0 "), Some(0));
+        assert_eq!(parse_retry_after("soon"), None);
+
+        let past = httpdate::fmt_http_date(SystemTime::UNIX_EPOCH);
+        assert_eq!(parse_retry_after(&past), Some(0));
+        let future = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(30));
+        let ms = parse_retry_after(&future).unwrap();
+        assert!((28_000..=30_000).contains(&ms), "{}", ms);
+    }
+
+    #[tokio::test]
+    async fn test_post_retries_only_with_client_order_id() {
+        let mut resting = order_json("o1", "0.00", "1.00", Some("1.00"));
+        resting["client_order_id"] = "c1".into();
+        let body = serde_json::json!({ "order": resting }).to_string();
+        let order = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000);
+
+        let server = rate_limited_server(1, &body).await;
+        let config = Config::new("key", test_private_key_pem()).with_max_retries(3);
+        let client = mock_client(&server, config);
+        let err = client.create_order(&order).await.unwrap_err();
+        assert!(matches!(err, Error::RateLimited { .. }));
+        assert_eq!(server.requests().len(), 1);
+
+        let server = rate_limited_server(1, &body).await;
+        let config = Config::new("key", test_private_key_pem()).with_max_retries(3);
+        let client = mock_client(&server, config);
+        let order = order.with_client_order_id("c1");
+        client.create_order(&order).await.unwrap();
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_order_checked_enforces_risk_limit() {
+        let server = MockServer::start(|_| {
+            MockResponse::json(format!(r#"{{"order":{}}}"#, resting_order_json("o1")))
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+        let mut market: Market = serde_json::from_str(&market_json("T", "EV")).unwrap();
+        market.risk_limit_cents = Some(1_000);
+
+        // 10 contracts at $0.50 is 500 cents against 400 remaining
+        let order = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 10, 5_000);
+        let err = client
+            .create_order_checked(&order, &market, 600)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidOrder(_)));
+        let other = CreateOrderRequest::limit("U", Side::Yes, Action::Buy, 1, 5_000);
+        assert!(client
+            .create_order_checked(&other, &market, 0)
+            .await
+            .is_err());
+        assert!(server.requests().is_empty());
+
+        client
+            .create_order_checked(&order, &market, 500)
+            .await
+            .unwrap();
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_orders_are_not_sent() {
+        let server = MockServer::start(|_| MockResponse::json("{}")).await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let typo = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 0);
+        let err = client.create_order(&typo).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidOrder(_)));
+
+        let valid = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000);
+        let batch = BatchCreateOrdersRequest {
+            orders: vec![
+                valid.clone(),
+                CreateOrderRequest::limit("T", Side::Yes, Action::Buy, -1, 5_000),
+                valid,
+                typo,
+            ],
+        };
+        match client.batch_create_orders(&batch).await {
+            Err(Error::InvalidOrder(msg)) => {
+                assert!(msg.contains("order 1: "));
+                assert!(msg.contains("order 3: "));
+            }
+            other => panic!("expected InvalidOrder, got {other:?}"),
+        }
+
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "chrono")]
+    async fn test_create_order_before_close() {
+        let now = DateTime::<Utc>::from(SystemTime::now());
+        let soon = market_closing_at("SOON", &(now + chrono::Duration::seconds(30)).to_rfc3339());
+        let later = market_closing_at("LATER", &(now + chrono::Duration::days(1)).to_rfc3339());
+        let server = MockServer::start(move |req| {
+            let body = match (req.method.as_str(), req.path.as_str()) {
+                ("GET", "/markets/SOON") => format!(r#"{{"market":{}}}"#, soon),
+                ("GET", "/markets/LATER") => format!(r#"{{"market":{}}}"#, later),
+                ("POST", "/portfolio/orders") => {
+                    let mut order = order_json("o1", "0.00", "1.00", Some("1.00"));
+                    order["ticker"] = "LATER".into();
+                    serde_json::json!({ "order": order }).to_string()
+                }
+                other => panic!("unexpected request {:?}", other),
+            };
+            MockResponse::json(body)
+        })
+        .await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+        let margin = Duration::from_secs(300);
+
+        let closing = CreateOrderRequest::limit("SOON", Side::Yes, Action::Buy, 1, 5_000);
+        let err = client
+            .create_order_before_close(&closing, margin)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidOrder(_)));
+
+        let open = CreateOrderRequest::limit("LATER", Side::Yes, Action::Buy, 1, 5_000);
+        let response = client
+            .create_order_before_close(&open, margin)
+            .await
+            .unwrap();
+        assert_eq!(response.order.order_id, "o1");
+        client
+            .create_order_before_close(&open, margin)
+            .await
+            .unwrap();
+
+        // A cached close time that would reject is checked again
+        assert!(client
+            .create_order_before_close(&closing, margin)
+            .await
+            .is_err());
+        client.invalidate_close_time(Some("LATER"));
+        client
+            .create_order_before_close(&open, margin)
+            .await
+            .unwrap();
+
+        // Close times are cached until invalidated, and rejected orders are
+        // never sent
+        let requests: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|r| format!("{} {}", r.method, r.path))
+            .collect();
+        assert_eq!(
+            requests,
+            [
+                "GET /markets/SOON",
+                "GET /markets/LATER",
+                "POST /portfolio/orders",
+                "POST /portfolio/orders",
+                "GET /markets/SOON",
+                "GET /markets/LATER",
+                "POST /portfolio/orders",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_lenient_json_includes_context() {
+        let server = MockServer::start(|_| MockResponse::json(r#"{"portfolio_value":0}"#)).await;
+
+        let strict = mock_client(&server, Config::new("key", test_private_key_pem()));
+        let err = strict.get_balance().await.unwrap_err().to_string();
+        assert!(err.contains("missing field `balance`"));
+        assert!(!err.contains("portfolio_value"));
+
+        let lenient = mock_client(
+            &server,
+            Config::new("key", test_private_key_pem()).with_lenient_json(true),
+        );
+        let err = lenient.get_balance().await.unwrap_err();
+        assert!(matches!(err, Error::Json(_)));
+        let err = err.to_string();
+        assert!(err.contains("missing field `balance`"));
+        assert!(err.contains(r#"in response: {"portfolio_value":0}"#));
+    }
+
+    #[tokio::test]
+    async fn test_orders_status_filter_query() {
+        let server = MockServer::start(|_| MockResponse::json(r#"{"orders":[]}"#)).await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let filters = [
+            OrderStatusFilter::Resting,
+            OrderStatusFilter::Executed,
+            OrderStatusFilter::Canceled,
+            OrderStatusFilter::Pending,
+            OrderStatusFilter::All,
+        ];
+        for filter in filters {
+            client
+                .get_orders_filtered(Some("TEST"), filter, None)
+                .await
+                .unwrap();
+        }
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/portfolio/orders?ticker=TEST&status=resting",
+                "/portfolio/orders?ticker=TEST&status=executed",
+                "/portfolio/orders?ticker=TEST&status=canceled",
+                "/portfolio/orders?ticker=TEST&status=pending",
+                "/portfolio/orders?ticker=TEST",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_markets_status_filter_query() {
+        let server = MockServer::start(|_| MockResponse::json(r#"{"markets":[]}"#)).await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let filters = [
+            MarketStatusFilter::Unopened,
+            MarketStatusFilter::Open,
+            MarketStatusFilter::Closed,
+            MarketStatusFilter::Settled,
+            MarketStatusFilter::All,
+        ];
+        for filter in filters {
+            client
+                .get_markets_filtered(filter, None, None)
+                .await
+                .unwrap();
+        }
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/markets?status=unopened",
+                "/markets?status=open",
+                "/markets?status=closed",
+                "/markets?status=settled",
+                "/markets",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_positions_subaccount_param() {
+        let server = MockServer::start(|_| MockResponse::json(r#"{"market_positions":[]}"#)).await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        client.get_positions(None, None, None, None).await.unwrap();
+        client
+            .get_positions_for_subaccount(2, Some("TEST"), None, None, Some(10))
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/portfolio/positions");
+        assert_eq!(
+            requests[1].path,
+            "/portfolio/positions?ticker=TEST&limit=10&subaccount=2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_positions_settlement_status_filter() {
+        let server = MockServer::start(|_| MockResponse::json(r#"{"market_positions":[]}"#)).await;
+        let client = mock_client(&server, Config::new("key", test_private_key_pem()));
+
+        let filters = [
+            SettlementStatus::Settled,
+            SettlementStatus::Unsettled,
+            SettlementStatus::All,
+        ];
+        for filter in filters {
+            client
+                .get_positions_filtered(Some("TEST"), None, filter, None, Some(50))
+                .await
+                .unwrap();
+        }
+
+        let paths: Vec<_> = server.requests().into_iter().map(|r| r.path).collect();
+        assert_eq!(
+            paths,
+            [
+                "/portfolio/positions?ticker=TEST&settlement_status=settled&limit=50",
+                "/portfolio/positions?ticker=TEST&settlement_status=unsettled&limit=50",
+                "/portfolio/positions?ticker=TEST&limit=50",
+            ]
+        );
+    }
 }
@@ -16,28 +16,39 @@
 //! let config = Config::new("api-key", "private-key-pem");
 //! let client = KalshiClient::new(config)?;
 //!
-//! // WebSocket connection (not yet implemented)
-//! // let mut ws = client.websocket().await?;
-//! // ws.subscribe_orderbook(&["KXBTC-25JAN"]).await?;
+//! let mut ws = client.websocket().await?;
+//! ws.subscribe_orderbook(&["KXBTC-25JAN"]).await?;
+//!
+//! while let Some(msg) = ws.next().await {
+//!     println!("{:?}", msg?);
+//! }
 //! # Ok(())
 //! # }
 //! ```
 
-use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use futures_util::stream::{SplitSink, SplitStream};
 use futures_util::{SinkExt, StreamExt};
 use tokio::net::TcpStream;
+use tokio::sync::{mpsc, oneshot};
 use tokio_tungstenite::tungstenite::http::Request;
 use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tokio_tungstenite::{Connector, MaybeTlsStream, WebSocketStream};
 
 use crate::client::auth::Signer;
-use crate::config::Config;
+use crate::client::recorder::MessageRecorder;
+use crate::config::{Config, DEFAULT_SUBSCRIBE_CHUNK_SIZE};
 use crate::error::Error;
+use crate::orderbook::{OrderbookManager, OrderbookState};
 use crate::types::messages::{
-    OkMsgData, SubscribeParams, UpdateSubscriptionAction, UpdateSubscriptionParams, WsCommand,
-    WsMessage,
+    SubscribeParams, UpdateSubscriptionAction, UpdateSubscriptionParams, WsCommand, WsMessage,
 };
 
 type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
@@ -53,6 +64,19 @@ pub struct SubscriptionInfo {
     pub market_tickers: Option<Vec<String>>,
 }
 
+/// A subscription replayed after reconnecting that the server did not confirm
+///
+/// See [`ReconnectingWebSocket::with_resubscribe_verification`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnconfirmedSubscription {
+    /// Channel name
+    pub channel: String,
+    /// Market tickers (if applicable)
+    pub market_tickers: Option<Vec<String>>,
+    /// The server's error message, or `None` if no response arrived in time
+    pub error: Option<String>,
+}
+
 /// WebSocket client for real-time market data
 ///
 /// Provides methods to subscribe to various channels and receive real-time updates.
@@ -61,16 +85,24 @@ pub struct SubscriptionInfo {
 /// # Thread Safety
 ///
 /// This client is NOT thread-safe. For concurrent access from multiple tasks,
-/// use channels or wrap in a mutex.
+/// use [`into_stream`](Self::into_stream) to drive it from a background task
+/// and control it through a cloneable [`WsHandle`], or [`split`](Self::split)
+/// it into a [`WsWriter`] and [`WsReader`] without a background task.
 #[derive(Debug)]
 pub struct WebSocketClient {
     write: SplitSink<WsStream, Message>,
     read: SplitStream<WsStream>,
     message_id: u64,
-    /// Active subscriptions by sid
-    subscriptions: FxHashMap<u64, SubscriptionInfo>,
-    /// Pending subscription requests by message id
-    pending_subscriptions: FxHashMap<u64, PendingSubscription>,
+    /// Subscription state (active and pending)
+    tracker: SubscriptionTracker,
+    /// Optional capture of raw text frames
+    recorder: Option<MessageRecorder>,
+    /// Whether `next()` answers pings itself instead of surfacing them
+    auto_pong: bool,
+    /// Maximum market tickers per subscribe command
+    chunk_size: usize,
+    /// Idle time after which `next()` pings the server, then gives up
+    heartbeat_timeout: Option<Duration>,
 }
 
 /// Information about a pending subscription request
@@ -80,6 +112,86 @@ struct PendingSubscription {
     market_tickers: Option<Vec<String>>,
 }
 
+/// Subscription bookkeeping driven by server acknowledgements
+#[derive(Debug, Default)]
+struct SubscriptionTracker {
+    /// Active subscriptions by sid
+    subscriptions: FxHashMap<u64, SubscriptionInfo>,
+    /// Pending subscription requests by message id
+    pending: FxHashMap<u64, PendingSubscription>,
+}
+
+impl SubscriptionTracker {
+    /// Record a subscribe command awaiting confirmation
+    fn add_pending(&mut self, msg_id: u64, channel: &str, market_tickers: Option<Vec<String>>) {
+        self.pending.insert(
+            msg_id,
+            PendingSubscription {
+                channel: channel.to_string(),
+                market_tickers,
+            },
+        );
+    }
+
+    /// Update state from an incoming message
+    fn handle_message(&mut self, msg: &WsMessage) {
+        match msg {
+            WsMessage::Subscribed(subscribed) => {
+                // Move pending subscription to active
+                if let Some(id) = subscribed.id {
+                    if let Some(pending) = self.pending.remove(&id) {
+                        self.subscriptions.insert(
+                            subscribed.msg.sid,
+                            SubscriptionInfo {
+                                sid: subscribed.msg.sid,
+                                channel: pending.channel,
+                                market_tickers: pending.market_tickers,
+                            },
+                        );
+                    }
+                }
+            }
+            WsMessage::Unsubscribed(unsubscribed) => {
+                self.subscriptions.remove(&unsubscribed.sid);
+            }
+            WsMessage::SubscriptionUpdated(update) => {
+                if let Some(subscription) =
+                    update.sid.and_then(|sid| self.subscriptions.get_mut(&sid))
+                {
+                    subscription.market_tickers = Some(update.market_tickers.clone());
+                }
+            }
+            WsMessage::SubscriptionsList(list) => {
+                // The server's list is authoritative; forget anything it no longer has
+                let live: FxHashSet<u64> = list.subscriptions.iter().map(|sub| sub.sid).collect();
+                self.subscriptions.retain(|sid, _| live.contains(sid));
+            }
+            _ => {}
+        }
+    }
+
+    /// Market tickers covered by confirmed orderbook subscriptions
+    fn subscribed_tickers(&self) -> FxHashSet<String> {
+        self.subscriptions
+            .values()
+            .filter(|info| info.channel == "orderbook_delta")
+            .filter_map(|info| info.market_tickers.as_ref())
+            .flatten()
+            .cloned()
+            .collect()
+    }
+
+    /// Requested tickers without a confirmed orderbook subscription, in request order
+    fn missing_subscriptions(&self, requested: &[&str]) -> Vec<String> {
+        let subscribed = self.subscribed_tickers();
+        requested
+            .iter()
+            .filter(|ticker| !subscribed.contains(**ticker))
+            .map(|ticker| ticker.to_string())
+            .collect()
+    }
+}
+
 impl WebSocketClient {
     /// Connect to the Kalshi WebSocket API
     ///
@@ -92,17 +204,18 @@ impl WebSocketClient {
     /// Returns an error if the connection fails or authentication headers
     /// cannot be generated.
     pub async fn connect(config: &Config) -> Result<Self, Error> {
-        let signer = Signer::new(config.private_key_pem())?;
+        let signer = Signer::with_scheme(config.private_key_pem(), config.signing_scheme())?;
         let timestamp = Signer::current_timestamp_ms();
         let signature = signer.sign(timestamp, "GET", "/trade-api/ws/v2")?;
 
         // Build WebSocket request with auth headers
+        let url = config.effective_websocket_url();
         let request = Request::builder()
-            .uri(config.websocket_url())
+            .uri(url)
             .header("KALSHI-ACCESS-KEY", config.api_key_id())
             .header("KALSHI-ACCESS-TIMESTAMP", timestamp.to_string())
             .header("KALSHI-ACCESS-SIGNATURE", signature)
-            .header("Host", "api.elections.kalshi.com")
+            .header("Host", host_header(url)?)
             .header("Connection", "Upgrade")
             .header("Upgrade", "websocket")
             .header("Sec-WebSocket-Version", "13")
@@ -112,16 +225,79 @@ impl WebSocketClient {
             )
             .body(())?;
 
-        let (ws_stream, _response) = tokio_tungstenite::connect_async(request).await?;
+        let connector = config
+            .websocket_tls()
+            .map(|tls| Connector::Rustls(Arc::clone(tls)));
+        let (ws_stream, _response) =
+            tokio_tungstenite::connect_async_tls_with_config(request, None, false, connector)
+                .await?;
+        let mut client = Self::from_stream(ws_stream);
+        client.chunk_size = config.subscribe_chunk_size();
+        Ok(client)
+    }
+
+    /// Wrap an already established WebSocket stream
+    pub(crate) fn from_stream(ws_stream: WsStream) -> Self {
         let (write, read) = ws_stream.split();
 
-        Ok(Self {
+        Self {
             write,
             read,
             message_id: 1,
-            subscriptions: FxHashMap::default(),
-            pending_subscriptions: FxHashMap::default(),
-        })
+            tracker: SubscriptionTracker::default(),
+            recorder: None,
+            auto_pong: true,
+            chunk_size: DEFAULT_SUBSCRIBE_CHUNK_SIZE,
+            heartbeat_timeout: None,
+        }
+    }
+
+    /// Set whether ping frames are answered automatically (default `true`)
+    ///
+    /// When disabled, [`next`](Self::next) returns each ping as
+    /// [`WsMessage::Ping`] so you can observe the server's ping cadence, and
+    /// this client no longer sends its own pong. Reply with
+    /// [`send_pong`](Self::send_pong) if you need control over the payload.
+    ///
+    /// The exchange drops connections that stop answering pings. The
+    /// underlying `tungstenite` transport still queues a protocol-level pong
+    /// for every ping it reads, but only while `next()` keeps being polled,
+    /// so a consumer that stops reading will be disconnected.
+    #[must_use]
+    pub fn with_auto_pong(mut self, enabled: bool) -> Self {
+        self.auto_pong = enabled;
+        self
+    }
+
+    /// Detect dead connections that stop sending anything (default off)
+    ///
+    /// If no frame of any kind arrives for `timeout`, [`next`](Self::next)
+    /// sends a ping. If nothing arrives within another `timeout` after that,
+    /// it returns [`Error::ConnectionClosed`], which makes
+    /// [`ReconnectingWebSocket`] reconnect. A silently dropped connection is
+    /// therefore detected after at most twice `timeout`.
+    ///
+    /// Applies to `next()` and to the [`WsReader`] from [`split`](Self::split),
+    /// not to the task started by [`into_stream`](Self::into_stream).
+    #[must_use]
+    pub fn with_heartbeat_timeout(mut self, timeout: Duration) -> Self {
+        self.heartbeat_timeout = Some(timeout);
+        self
+    }
+
+    /// Send a pong frame with the given payload
+    pub async fn send_pong(&mut self, data: Vec<u8>) -> Result<(), Error> {
+        self.write.send(Message::Pong(data)).await?;
+        Ok(())
+    }
+
+    /// Record every received text frame with the given recorder
+    ///
+    /// Frames are recorded before parsing, so messages this crate cannot
+    /// parse are captured too. Pass `None` to stop recording; the previous
+    /// recorder is returned so it can be flushed or reused.
+    pub fn set_recorder(&mut self, recorder: Option<MessageRecorder>) -> Option<MessageRecorder> {
+        std::mem::replace(&mut self.recorder, recorder)
     }
 
     /// Send a command to the WebSocket server
@@ -142,46 +318,62 @@ impl WebSocketClient {
     /// Get all active subscriptions
     #[must_use]
     pub fn subscriptions(&self) -> &FxHashMap<u64, SubscriptionInfo> {
-        &self.subscriptions
+        &self.tracker.subscriptions
     }
 
     /// Get subscription info by sid
     #[must_use]
     pub fn get_subscription(&self, sid: u64) -> Option<&SubscriptionInfo> {
-        self.subscriptions.get(&sid)
+        self.tracker.subscriptions.get(&sid)
+    }
+
+    /// Get the market tickers with a confirmed orderbook subscription
+    #[must_use]
+    pub fn subscribed_tickers(&self) -> FxHashSet<String> {
+        self.tracker.subscribed_tickers()
+    }
+
+    /// Get the requested tickers that have no confirmed orderbook subscription
+    ///
+    /// Tickers are returned in the order they were requested. A ticker is
+    /// missing until its `Subscribed` acknowledgement has been received via
+    /// [`next`](Self::next), so call this after draining the confirmations.
+    #[must_use]
+    pub fn missing_subscriptions(&self, requested: &[&str]) -> Vec<String> {
+        self.tracker.missing_subscriptions(requested)
     }
 
     /// Subscribe to orderbook updates for the given markets
     ///
+    /// Large ticker lists are split into several subscribe commands of at
+    /// most [`Config::subscribe_chunk_size`] tickers each, and every chunk is
+    /// tracked as its own subscription.
+    ///
     /// # Arguments
     ///
     /// * `market_tickers` - Market tickers to subscribe to
     ///
     /// # Returns
     ///
-    /// The message ID of the subscription request (use to correlate with response)
-    pub async fn subscribe_orderbook(&mut self, market_tickers: &[&str]) -> Result<u64, Error> {
-        let tickers: Vec<String> = market_tickers.iter().map(|s| s.to_string()).collect();
-        let msg_id = self.message_id;
-
-        self.pending_subscriptions.insert(
-            msg_id,
-            PendingSubscription {
-                channel: "orderbook_delta".to_string(),
-                market_tickers: Some(tickers.clone()),
-            },
-        );
+    /// The message IDs of the subscription requests, one per chunk (use to
+    /// correlate with responses)
+    pub async fn subscribe_orderbook(
+        &mut self,
+        market_tickers: &[&str],
+    ) -> Result<Vec<u64>, Error> {
+        let mut msg_ids = Vec::with_capacity(market_tickers.len().div_ceil(self.chunk_size));
+
+        for chunk in market_tickers.chunks(self.chunk_size) {
+            let tickers: Vec<String> = chunk.iter().map(|s| s.to_string()).collect();
+            let msg_id = self.message_id;
+
+            self.tracker
+                .add_pending(msg_id, "orderbook_delta", Some(tickers.clone()));
+            let cmd = subscribe_command(msg_id, "orderbook_delta", Some(tickers));
+            msg_ids.push(self.send_command(cmd).await?);
+        }
 
-        let cmd = WsCommand::Subscribe {
-            id: msg_id,
-            params: SubscribeParams {
-                channels: vec!["orderbook_delta".to_string()],
-                market_ticker: None,
-                market_tickers: Some(tickers),
-                send_initial_snapshot: None,
-            },
-        };
-        self.send_command(cmd).await
+        Ok(msg_ids)
     }
 
     /// Subscribe to ticker updates
@@ -196,24 +388,9 @@ impl WebSocketClient {
         let tickers = market_tickers.map(|t| t.iter().map(|s| s.to_string()).collect());
         let msg_id = self.message_id;
 
-        self.pending_subscriptions.insert(
-            msg_id,
-            PendingSubscription {
-                channel: "ticker".to_string(),
-                market_tickers: tickers.clone(),
-            },
-        );
-
-        let cmd = WsCommand::Subscribe {
-            id: msg_id,
-            params: SubscribeParams {
-                channels: vec!["ticker".to_string()],
-                market_ticker: None,
-                market_tickers: tickers,
-                send_initial_snapshot: None,
-            },
-        };
-        self.send_command(cmd).await
+        self.tracker.add_pending(msg_id, "ticker", tickers.clone());
+        self.send_command(subscribe_command(msg_id, "ticker", tickers))
+            .await
     }
 
     /// Subscribe to trade updates
@@ -224,24 +401,9 @@ impl WebSocketClient {
         let tickers = market_tickers.map(|t| t.iter().map(|s| s.to_string()).collect());
         let msg_id = self.message_id;
 
-        self.pending_subscriptions.insert(
-            msg_id,
-            PendingSubscription {
-                channel: "trade".to_string(),
-                market_tickers: tickers.clone(),
-            },
-        );
-
-        let cmd = WsCommand::Subscribe {
-            id: msg_id,
-            params: SubscribeParams {
-                channels: vec!["trade".to_string()],
-                market_ticker: None,
-                market_tickers: tickers,
-                send_initial_snapshot: None,
-            },
-        };
-        self.send_command(cmd).await
+        self.tracker.add_pending(msg_id, "trade", tickers.clone());
+        self.send_command(subscribe_command(msg_id, "trade", tickers))
+            .await
     }
 
     /// Subscribe to fill notifications (your trades)
@@ -249,24 +411,9 @@ impl WebSocketClient {
         let tickers = market_tickers.map(|t| t.iter().map(|s| s.to_string()).collect());
         let msg_id = self.message_id;
 
-        self.pending_subscriptions.insert(
-            msg_id,
-            PendingSubscription {
-                channel: "fill".to_string(),
-                market_tickers: tickers.clone(),
-            },
-        );
-
-        let cmd = WsCommand::Subscribe {
-            id: msg_id,
-            params: SubscribeParams {
-                channels: vec!["fill".to_string()],
-                market_ticker: None,
-                market_tickers: tickers,
-                send_initial_snapshot: None,
-            },
-        };
-        self.send_command(cmd).await
+        self.tracker.add_pending(msg_id, "fill", tickers.clone());
+        self.send_command(subscribe_command(msg_id, "fill", tickers))
+            .await
     }
 
     /// Subscribe to user order updates
@@ -275,29 +422,16 @@ impl WebSocketClient {
     pub async fn subscribe_user_orders(&mut self) -> Result<u64, Error> {
         let msg_id = self.message_id;
 
-        self.pending_subscriptions.insert(
-            msg_id,
-            PendingSubscription {
-                channel: "user_orders".to_string(),
-                market_tickers: None,
-            },
-        );
-
-        let cmd = WsCommand::Subscribe {
-            id: msg_id,
-            params: SubscribeParams {
-                channels: vec!["user_orders".to_string()],
-                market_ticker: None,
-                market_tickers: None,
-                send_initial_snapshot: None,
-            },
-        };
-        self.send_command(cmd).await
+        self.tracker.add_pending(msg_id, "user_orders", None);
+        self.send_command(subscribe_command(msg_id, "user_orders", None))
+            .await
     }
 
     /// Subscribe to market lifecycle events
     ///
-    /// Receives updates when markets open, close, settle, etc.
+    /// Receives updates when markets open, close, settle, etc. Use
+    /// [`MarketLifecycleMsg::as_settlement`](crate::types::messages::MarketLifecycleMsg::as_settlement)
+    /// to read a settlement.
     ///
     /// # Arguments
     ///
@@ -308,163 +442,884 @@ impl WebSocketClient {
     ) -> Result<u64, Error> {
         let msg_id = self.message_id;
 
-        self.pending_subscriptions.insert(
-            msg_id,
-            PendingSubscription {
-                channel: "market_lifecycle_v2".to_string(),
-                market_tickers: None,
-            },
-        );
+        self.tracker
+            .add_pending(msg_id, "market_lifecycle_v2", None);
+        self.send_command(subscribe_command(msg_id, "market_lifecycle_v2", None))
+            .await
+    }
 
-        let cmd = WsCommand::Subscribe {
-            id: msg_id,
-            params: SubscribeParams {
-                channels: vec!["market_lifecycle_v2".to_string()],
-                market_ticker: None,
-                market_tickers: None,
-                send_initial_snapshot: None,
+    /// Unsubscribe from channels by subscription ID
+    ///
+    /// # Arguments
+    ///
+    /// * `sids` - Subscription IDs to unsubscribe from
+    pub async fn unsubscribe(&mut self, sids: &[u64]) -> Result<u64, Error> {
+        let cmd = WsCommand::Unsubscribe {
+            id: self.message_id,
+            params: crate::types::messages::UnsubscribeParams {
+                sids: sids.to_vec(),
             },
         };
         self.send_command(cmd).await
     }
 
-    /// Unsubscribe from channels by subscription ID
-    ///
-    /// # Arguments
+    /// Update an existing subscription to add or remove markets
+    ///
+    /// # Arguments
+    ///
+    /// * `sid` - The subscription ID to update
+    /// * `add_tickers` - Market tickers to add
+    /// * `remove_tickers` - Market tickers to remove
+    pub async fn update_subscription(
+        &mut self,
+        sid: u64,
+        add_tickers: Option<&[&str]>,
+        remove_tickers: Option<&[&str]>,
+    ) -> Result<u64, Error> {
+        let cmd = update_subscription_command(self.message_id, sid, add_tickers, remove_tickers);
+        self.send_command(cmd).await
+    }
+
+    /// List current subscriptions
+    pub async fn list_subscriptions(&mut self) -> Result<u64, Error> {
+        let cmd = WsCommand::ListSubscriptions {
+            id: self.message_id,
+        };
+        self.send_command(cmd).await
+    }
+
+    /// Receive the next message from the WebSocket
+    ///
+    /// This method also handles subscription tracking automatically:
+    /// - When a `Subscribed` message is received, it adds to the subscriptions map
+    /// - When an `Unsubscribed` message is received, it removes from the subscriptions map
+    /// - `SubscriptionUpdated` and `SubscriptionsList` responses refresh tracked tickers
+    ///   and drop subscriptions the server no longer reports
+    ///
+    /// # Returns
+    ///
+    /// The next message, or `None` if the connection is closed.
+    pub async fn next(&mut self) -> Option<Result<WsMessage, Error>> {
+        loop {
+            let frame = match self.heartbeat_timeout {
+                Some(timeout) => {
+                    let write = &mut self.write;
+                    let ping = || async move { write.send(Message::Ping(Vec::new())).await };
+                    match read_with_heartbeat(&mut self.read, timeout, ping).await {
+                        Ok(frame) => frame?,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                None => self.read.next().await?,
+            };
+            if let Some(result) = self.handle_frame(frame).await {
+                return Some(result);
+            }
+        }
+    }
+
+    /// Process one frame from the socket
+    ///
+    /// Returns `None` for frames that produce no message, such as pings that
+    /// were answered automatically.
+    async fn handle_frame(
+        &mut self,
+        frame: Result<Message, tokio_tungstenite::tungstenite::Error>,
+    ) -> Option<Result<WsMessage, Error>> {
+        let result = match decode_frame(frame, &mut self.recorder) {
+            Decoded::Message(result) => result,
+            Decoded::Ping(data) if !self.auto_pong => return Some(Ok(WsMessage::Ping(data))),
+            // Respond to pings automatically
+            Decoded::Ping(data) => {
+                return match self.write.send(Message::Pong(data)).await {
+                    Ok(()) => None,
+                    Err(e) => Some(Err(e.into())),
+                };
+            }
+            Decoded::Skip => return None,
+        };
+
+        // Track subscription state
+        if let Ok(msg) = &result {
+            self.tracker.handle_message(msg);
+        }
+        Some(result)
+    }
+
+    /// Close the WebSocket connection
+    pub async fn close(&mut self) -> Result<(), Error> {
+        self.write.close().await?;
+        Ok(())
+    }
+
+    /// Read until every pending subscribe command is answered or `timeout` passes
+    ///
+    /// Returns the messages read in the meantime, in order, and the commands
+    /// that were rejected or never confirmed. Unanswered commands stay
+    /// pending, so a late confirmation is still tracked.
+    async fn confirm_pending(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<(Vec<WsMessage>, Vec<UnconfirmedSubscription>), Error> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        let mut received = Vec::new();
+        let mut rejected = Vec::new();
+
+        while !self.tracker.pending.is_empty() {
+            let msg = match tokio::time::timeout_at(deadline, self.next()).await {
+                Err(_) => break,
+                Ok(None) | Ok(Some(Err(Error::ConnectionClosed))) => {
+                    return Err(Error::ConnectionClosed)
+                }
+                Ok(Some(Err(e))) => {
+                    tracing::warn!(error = %e, "unreadable message while confirming subscriptions");
+                    continue;
+                }
+                Ok(Some(Ok(msg))) => msg,
+            };
+            if let WsMessage::Error(err) = &msg {
+                if let Some((id, pending)) = err
+                    .id
+                    .and_then(|id| self.tracker.pending.remove(&id).map(|p| (id, p)))
+                {
+                    rejected.push((id, pending, Some(err.msg.msg.clone())));
+                }
+            }
+            received.push(msg);
+        }
+
+        let mut unconfirmed: Vec<_> = self
+            .tracker
+            .pending
+            .iter()
+            .map(|(id, pending)| (*id, pending.clone(), None))
+            .chain(rejected)
+            .collect();
+        unconfirmed.sort_by_key(|(id, _, _)| *id);
+        let unconfirmed = unconfirmed
+            .into_iter()
+            .map(|(_, pending, error)| UnconfirmedSubscription {
+                channel: pending.channel,
+                market_tickers: pending.market_tickers,
+                error,
+            })
+            .collect();
+        Ok((received, unconfirmed))
+    }
+
+    /// Move this client into a background task and receive messages over a channel
+    ///
+    /// The task reads the socket and forwards every message to the returned
+    /// receiver, answering pings on its own as [`next`](Self::next) does. The
+    /// [`WsHandle`] sends subscription commands to the same task, so one task
+    /// can subscribe while another consumes messages. Must be called from
+    /// within a Tokio runtime.
+    ///
+    /// # Shutdown
+    ///
+    /// - Dropping every clone of the [`WsHandle`] closes the connection; the
+    ///   receiver then yields any buffered messages followed by `None`.
+    /// - Dropping the receiver closes the connection when the next message
+    ///   arrives; later handle calls fail with [`Error::ConnectionClosed`].
+    /// - If the server closes the connection, the receiver yields
+    ///   [`Error::ConnectionClosed`] and then `None`.
+    ///
+    /// The channel holds [`STREAM_CHANNEL_CAPACITY`] messages. While it is
+    /// full the task stops reading the socket, so pings go unanswered and
+    /// handle calls wait; keep the receiver drained.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kalshi_trading::{Config, KalshiClient};
+    ///
+    /// # async fn example() -> kalshi_trading::Result<()> {
+    /// let client = KalshiClient::new(Config::new("api-key", "private-key-pem"))?;
+    /// let (handle, mut messages) = client.websocket().await?.into_stream();
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Some(msg) = messages.recv().await {
+    ///         println!("{:?}", msg);
+    ///     }
+    /// });
+    ///
+    /// handle.subscribe_orderbook(&["KXBTC-25JAN"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_stream(self) -> (WsHandle, mpsc::Receiver<Result<WsMessage, Error>>) {
+        let (command_tx, command_rx) = mpsc::channel(HANDLE_COMMAND_CAPACITY);
+        let (message_tx, message_rx) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+        tokio::spawn(self.run_stream(command_rx, message_tx));
+        (
+            WsHandle {
+                commands: command_tx,
+            },
+            message_rx,
+        )
+    }
+
+    /// Split into a cloneable command writer and a message reader
+    ///
+    /// Unlike [`into_stream`](Self::into_stream), no background task is
+    /// spawned: the [`WsReader`] reads the socket directly while any number
+    /// of [`WsWriter`] clones send commands from other tasks. Subscription
+    /// tracking is shared, so acknowledgements read by the reader are
+    /// visible through every writer.
+    ///
+    /// The reader answers pings through the shared socket writer, so keep
+    /// polling [`WsReader::next`] to stay connected.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kalshi_trading::{Config, KalshiClient};
+    ///
+    /// # async fn example() -> kalshi_trading::Result<()> {
+    /// let client = KalshiClient::new(Config::new("api-key", "private-key-pem"))?;
+    /// let (writer, mut reader) = client.websocket().await?.split();
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Some(msg) = reader.next().await {
+    ///         println!("{:?}", msg);
+    ///     }
+    /// });
+    ///
+    /// writer.subscribe_orderbook(&["KXBTC-25JAN"]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn split(self) -> (WsWriter, WsReader) {
+        let shared = Arc::new(SplitShared {
+            write: tokio::sync::Mutex::new(self.write),
+            tracker: parking_lot::Mutex::new(self.tracker),
+            message_id: AtomicU64::new(self.message_id),
+        });
+        (
+            WsWriter {
+                shared: Arc::clone(&shared),
+                chunk_size: self.chunk_size,
+            },
+            WsReader {
+                read: self.read,
+                shared,
+                recorder: self.recorder,
+                auto_pong: self.auto_pong,
+                heartbeat_timeout: self.heartbeat_timeout,
+            },
+        )
+    }
+
+    /// Body of the task spawned by [`into_stream`](Self::into_stream)
+    async fn run_stream(
+        mut self,
+        mut commands: mpsc::Receiver<HandleCommand>,
+        messages: mpsc::Sender<Result<WsMessage, Error>>,
+    ) {
+        loop {
+            // Both branches are cancel-safe, so no frame or command is lost
+            tokio::select! {
+                frame = self.read.next() => {
+                    let Some(frame) = frame else { break };
+                    if let Some(result) = self.handle_frame(frame).await {
+                        if messages.send(result).await.is_err() {
+                            // Receiver dropped
+                            let _ = self.close().await;
+                            break;
+                        }
+                    }
+                }
+                command = commands.recv() => {
+                    let Some(command) = command else {
+                        // Every handle dropped
+                        let _ = self.close().await;
+                        break;
+                    };
+                    self.execute(command).await;
+                }
+            }
+        }
+    }
+
+    /// Run a command sent by a [`WsHandle`] and reply with its result
+    async fn execute(&mut self, command: HandleCommand) {
+        // A send error means the caller stopped waiting, which is fine
+        match command {
+            HandleCommand::SubscribeOrderbook { tickers, reply } => {
+                let _ = reply.send(self.subscribe_orderbook(&as_refs(&tickers)).await);
+            }
+            HandleCommand::SubscribeTicker { tickers, reply } => {
+                let tickers = tickers.as_deref().map(as_refs);
+                let _ = reply.send(self.subscribe_ticker(tickers.as_deref()).await);
+            }
+            HandleCommand::SubscribeTrades { tickers, reply } => {
+                let tickers = tickers.as_deref().map(as_refs);
+                let _ = reply.send(self.subscribe_trades(tickers.as_deref()).await);
+            }
+            HandleCommand::SubscribeFills { tickers, reply } => {
+                let tickers = tickers.as_deref().map(as_refs);
+                let _ = reply.send(self.subscribe_fills(tickers.as_deref()).await);
+            }
+            HandleCommand::SubscribeUserOrders { reply } => {
+                let _ = reply.send(self.subscribe_user_orders().await);
+            }
+            HandleCommand::SubscribeMarketLifecycle { tickers, reply } => {
+                let tickers = tickers.as_deref().map(as_refs);
+                let _ = reply.send(self.subscribe_market_lifecycle(tickers.as_deref()).await);
+            }
+            HandleCommand::Unsubscribe { sids, reply } => {
+                let _ = reply.send(self.unsubscribe(&sids).await);
+            }
+            HandleCommand::UpdateSubscription {
+                sid,
+                add_tickers,
+                remove_tickers,
+                reply,
+            } => {
+                let add = add_tickers.as_deref().map(as_refs);
+                let remove = remove_tickers.as_deref().map(as_refs);
+                let result = self
+                    .update_subscription(sid, add.as_deref(), remove.as_deref())
+                    .await;
+                let _ = reply.send(result);
+            }
+            HandleCommand::ListSubscriptions { reply } => {
+                let _ = reply.send(self.list_subscriptions().await);
+            }
+            HandleCommand::Subscriptions { reply } => {
+                let _ = reply.send(self.tracker.subscriptions.clone());
+            }
+        }
+    }
+}
+
+/// Number of messages buffered between the [`into_stream`](WebSocketClient::into_stream)
+/// task and its receiver
+pub const STREAM_CHANNEL_CAPACITY: usize = 1024;
+
+/// Number of [`WsHandle`] commands buffered before callers wait
+const HANDLE_COMMAND_CAPACITY: usize = 32;
+
+type Reply<T> = oneshot::Sender<T>;
+
+/// A request from a [`WsHandle`] to its background task
+#[derive(Debug)]
+enum HandleCommand {
+    SubscribeOrderbook {
+        tickers: Vec<String>,
+        reply: Reply<Result<Vec<u64>, Error>>,
+    },
+    SubscribeTicker {
+        tickers: Option<Vec<String>>,
+        reply: Reply<Result<u64, Error>>,
+    },
+    SubscribeTrades {
+        tickers: Option<Vec<String>>,
+        reply: Reply<Result<u64, Error>>,
+    },
+    SubscribeFills {
+        tickers: Option<Vec<String>>,
+        reply: Reply<Result<u64, Error>>,
+    },
+    SubscribeUserOrders {
+        reply: Reply<Result<u64, Error>>,
+    },
+    SubscribeMarketLifecycle {
+        tickers: Option<Vec<String>>,
+        reply: Reply<Result<u64, Error>>,
+    },
+    Unsubscribe {
+        sids: Vec<u64>,
+        reply: Reply<Result<u64, Error>>,
+    },
+    UpdateSubscription {
+        sid: u64,
+        add_tickers: Option<Vec<String>>,
+        remove_tickers: Option<Vec<String>>,
+        reply: Reply<Result<u64, Error>>,
+    },
+    ListSubscriptions {
+        reply: Reply<Result<u64, Error>>,
+    },
+    Subscriptions {
+        reply: Reply<FxHashMap<u64, SubscriptionInfo>>,
+    },
+}
+
+/// A socket frame after decoding, before any reply is sent
+// Short-lived and returned by value like the message itself, so not boxed
+#[allow(clippy::large_enum_variant)]
+enum Decoded {
+    Message(Result<WsMessage, Error>),
+    Ping(Vec<u8>),
+    /// Frames that produce no message (binary, pong, raw frames)
+    Skip,
+}
+
+/// `Host` header value for a WebSocket URL
+fn host_header(url: &str) -> Result<String, Error> {
+    let parsed = url::Url::parse(url)
+        .map_err(|e| Error::Config(format!("invalid WebSocket URL {}: {}", url, e)))?;
+    let host = parsed
+        .host_str()
+        .ok_or_else(|| Error::Config(format!("WebSocket URL {} has no host", url)))?;
+    Ok(match parsed.port() {
+        Some(port) => format!("{}:{}", host, port),
+        None => host.to_string(),
+    })
+}
+
+/// Wait for the next frame, pinging once if the socket stays idle
+///
+/// Fails with [`Error::ConnectionClosed`] if nothing arrives within
+/// `timeout` of the ping, or if the ping cannot be sent.
+async fn read_with_heartbeat<F, Fut>(
+    read: &mut SplitStream<WsStream>,
+    timeout: Duration,
+    ping: F,
+) -> Result<Option<Result<Message, tokio_tungstenite::tungstenite::Error>>, Error>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<(), tokio_tungstenite::tungstenite::Error>>,
+{
+    if let Ok(frame) = tokio::time::timeout(timeout, read.next()).await {
+        return Ok(frame);
+    }
+    ping().await.map_err(|_| Error::ConnectionClosed)?;
+    tokio::time::timeout(timeout, read.next())
+        .await
+        .map_err(|_| Error::ConnectionClosed)
+}
+
+/// Decode one frame, recording text frames before they are parsed
+fn decode_frame(
+    frame: Result<Message, tokio_tungstenite::tungstenite::Error>,
+    recorder: &mut Option<MessageRecorder>,
+) -> Decoded {
+    match frame {
+        Ok(Message::Text(text)) => {
+            if let Some(recorder) = recorder {
+                if let Err(e) = recorder.record(&text) {
+                    return Decoded::Message(Err(e));
+                }
+            }
+            Decoded::Message(WsMessage::from_json(&text).map_err(Error::from))
+        }
+        Ok(Message::Ping(data)) => Decoded::Ping(data),
+        Ok(Message::Close(_)) => Decoded::Message(Err(Error::ConnectionClosed)),
+        Ok(_) => Decoded::Skip,
+        Err(e) => Decoded::Message(Err(e.into())),
+    }
+}
+
+/// Subscribe command for one channel
+fn subscribe_command(id: u64, channel: &str, market_tickers: Option<Vec<String>>) -> WsCommand {
+    WsCommand::Subscribe {
+        id,
+        params: SubscribeParams {
+            channels: vec![channel.to_string()],
+            market_ticker: None,
+            market_tickers,
+            send_initial_snapshot: None,
+        },
+    }
+}
+
+/// Command adding `add_tickers` to, or else removing `remove_tickers` from, a subscription
+fn update_subscription_command(
+    id: u64,
+    sid: u64,
+    add_tickers: Option<&[&str]>,
+    remove_tickers: Option<&[&str]>,
+) -> WsCommand {
+    WsCommand::UpdateSubscription {
+        id,
+        params: UpdateSubscriptionParams {
+            sid: Some(sid),
+            sids: None,
+            market_ticker: None,
+            market_tickers: to_owned(add_tickers.or(remove_tickers)),
+            send_initial_snapshot: None,
+            action: if add_tickers.is_some() {
+                UpdateSubscriptionAction::AddMarkets
+            } else {
+                UpdateSubscriptionAction::DeleteMarkets
+            },
+        },
+    }
+}
+
+fn as_refs(tickers: &[String]) -> Vec<&str> {
+    tickers.iter().map(String::as_str).collect()
+}
+
+fn to_owned(tickers: Option<&[&str]>) -> Option<Vec<String>> {
+    tickers.map(|t| t.iter().map(|s| s.to_string()).collect())
+}
+
+/// Handle for controlling a [`WebSocketClient`] moved into a background task
+///
+/// Created by [`WebSocketClient::into_stream`]. Cheap to clone and safe to
+/// share across tasks; every method forwards to the client running in the
+/// task and returns its result. The connection closes once every clone is
+/// dropped.
+///
+/// Methods fail with [`Error::ConnectionClosed`] once the task has stopped.
+#[derive(Debug, Clone)]
+pub struct WsHandle {
+    commands: mpsc::Sender<HandleCommand>,
+}
+
+impl WsHandle {
+    /// Send a command to the task and wait for its reply
+    async fn request<T>(
+        &self,
+        command: impl FnOnce(Reply<T>) -> HandleCommand,
+    ) -> Result<T, Error> {
+        let (reply, response) = oneshot::channel();
+        self.commands
+            .send(command(reply))
+            .await
+            .map_err(|_| Error::ConnectionClosed)?;
+        response.await.map_err(|_| Error::ConnectionClosed)
+    }
+
+    /// Subscribe to orderbook updates for the given markets
+    ///
+    /// See [`WebSocketClient::subscribe_orderbook`].
+    pub async fn subscribe_orderbook(&self, market_tickers: &[&str]) -> Result<Vec<u64>, Error> {
+        let tickers = market_tickers.iter().map(|s| s.to_string()).collect();
+        self.request(|reply| HandleCommand::SubscribeOrderbook { tickers, reply })
+            .await?
+    }
+
+    /// Subscribe to ticker updates
+    ///
+    /// See [`WebSocketClient::subscribe_ticker`].
+    pub async fn subscribe_ticker(&self, market_tickers: Option<&[&str]>) -> Result<u64, Error> {
+        let tickers = to_owned(market_tickers);
+        self.request(|reply| HandleCommand::SubscribeTicker { tickers, reply })
+            .await?
+    }
+
+    /// Subscribe to trade updates
+    ///
+    /// See [`WebSocketClient::subscribe_trades`].
+    pub async fn subscribe_trades(&self, market_tickers: Option<&[&str]>) -> Result<u64, Error> {
+        let tickers = to_owned(market_tickers);
+        self.request(|reply| HandleCommand::SubscribeTrades { tickers, reply })
+            .await?
+    }
+
+    /// Subscribe to fill notifications (your trades)
+    ///
+    /// See [`WebSocketClient::subscribe_fills`].
+    pub async fn subscribe_fills(&self, market_tickers: Option<&[&str]>) -> Result<u64, Error> {
+        let tickers = to_owned(market_tickers);
+        self.request(|reply| HandleCommand::SubscribeFills { tickers, reply })
+            .await?
+    }
+
+    /// Subscribe to user order updates
+    ///
+    /// See [`WebSocketClient::subscribe_user_orders`].
+    pub async fn subscribe_user_orders(&self) -> Result<u64, Error> {
+        self.request(|reply| HandleCommand::SubscribeUserOrders { reply })
+            .await?
+    }
+
+    /// Subscribe to market lifecycle events
+    ///
+    /// See [`WebSocketClient::subscribe_market_lifecycle`].
+    pub async fn subscribe_market_lifecycle(
+        &self,
+        market_tickers: Option<&[&str]>,
+    ) -> Result<u64, Error> {
+        let tickers = to_owned(market_tickers);
+        self.request(|reply| HandleCommand::SubscribeMarketLifecycle { tickers, reply })
+            .await?
+    }
+
+    /// Unsubscribe from channels by subscription ID
+    pub async fn unsubscribe(&self, sids: &[u64]) -> Result<u64, Error> {
+        let sids = sids.to_vec();
+        self.request(|reply| HandleCommand::Unsubscribe { sids, reply })
+            .await?
+    }
+
+    /// Update an existing subscription to add or remove markets
+    ///
+    /// See [`WebSocketClient::update_subscription`].
+    pub async fn update_subscription(
+        &self,
+        sid: u64,
+        add_tickers: Option<&[&str]>,
+        remove_tickers: Option<&[&str]>,
+    ) -> Result<u64, Error> {
+        let (add_tickers, remove_tickers) = (to_owned(add_tickers), to_owned(remove_tickers));
+        self.request(|reply| HandleCommand::UpdateSubscription {
+            sid,
+            add_tickers,
+            remove_tickers,
+            reply,
+        })
+        .await?
+    }
+
+    /// List current subscriptions
+    ///
+    /// The server's reply arrives on the message receiver.
+    pub async fn list_subscriptions(&self) -> Result<u64, Error> {
+        self.request(|reply| HandleCommand::ListSubscriptions { reply })
+            .await?
+    }
+
+    /// Get a snapshot of the active subscriptions
+    pub async fn subscriptions(&self) -> Result<FxHashMap<u64, SubscriptionInfo>, Error> {
+        self.request(|reply| HandleCommand::Subscriptions { reply })
+            .await
+    }
+
+    /// Check whether the background task has stopped
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.commands.is_closed()
+    }
+}
+
+/// State shared by a [`WsWriter`] and [`WsReader`] pair
+#[derive(Debug)]
+struct SplitShared {
+    write: tokio::sync::Mutex<SplitSink<WsStream, Message>>,
+    tracker: parking_lot::Mutex<SubscriptionTracker>,
+    message_id: AtomicU64,
+}
+
+/// Command half of a [`WebSocketClient`] created by [`WebSocketClient::split`]
+///
+/// Cheap to clone and safe to share across tasks. Commands are written to
+/// the socket directly; concurrent writers wait on a lock only while a frame
+/// is being sent. Responses arrive on the paired [`WsReader`].
+#[derive(Debug, Clone)]
+pub struct WsWriter {
+    shared: Arc<SplitShared>,
+    chunk_size: usize,
+}
+
+impl WsWriter {
+    /// Send the command built for the next message ID
+    async fn send_command(&self, command: impl FnOnce(u64) -> WsCommand) -> Result<u64, Error> {
+        let msg_id = self.shared.message_id.fetch_add(1, Ordering::Relaxed);
+        let json = serde_json::to_string(&command(msg_id))?;
+        self.shared
+            .write
+            .lock()
+            .await
+            .send(Message::Text(json))
+            .await?;
+        Ok(msg_id)
+    }
+
+    /// Track and send a subscribe command
+    async fn subscribe(
+        &self,
+        channel: &str,
+        market_tickers: Option<Vec<String>>,
+    ) -> Result<u64, Error> {
+        self.send_command(|msg_id| {
+            // Pending before sending, so the reader can match the acknowledgement
+            self.shared
+                .tracker
+                .lock()
+                .add_pending(msg_id, channel, market_tickers.clone());
+            subscribe_command(msg_id, channel, market_tickers)
+        })
+        .await
+    }
+
+    /// Subscribe to orderbook updates for the given markets
+    ///
+    /// See [`WebSocketClient::subscribe_orderbook`].
+    pub async fn subscribe_orderbook(&self, market_tickers: &[&str]) -> Result<Vec<u64>, Error> {
+        let mut msg_ids = Vec::with_capacity(market_tickers.len().div_ceil(self.chunk_size));
+        for chunk in market_tickers.chunks(self.chunk_size) {
+            msg_ids.push(
+                self.subscribe("orderbook_delta", to_owned(Some(chunk)))
+                    .await?,
+            );
+        }
+        Ok(msg_ids)
+    }
+
+    /// Subscribe to ticker updates
+    ///
+    /// See [`WebSocketClient::subscribe_ticker`].
+    pub async fn subscribe_ticker(&self, market_tickers: Option<&[&str]>) -> Result<u64, Error> {
+        self.subscribe("ticker", to_owned(market_tickers)).await
+    }
+
+    /// Subscribe to trade updates
+    ///
+    /// See [`WebSocketClient::subscribe_trades`].
+    pub async fn subscribe_trades(&self, market_tickers: Option<&[&str]>) -> Result<u64, Error> {
+        self.subscribe("trade", to_owned(market_tickers)).await
+    }
+
+    /// Subscribe to fill notifications (your trades)
+    ///
+    /// See [`WebSocketClient::subscribe_fills`].
+    pub async fn subscribe_fills(&self, market_tickers: Option<&[&str]>) -> Result<u64, Error> {
+        self.subscribe("fill", to_owned(market_tickers)).await
+    }
+
+    /// Subscribe to user order updates
+    ///
+    /// See [`WebSocketClient::subscribe_user_orders`].
+    pub async fn subscribe_user_orders(&self) -> Result<u64, Error> {
+        self.subscribe("user_orders", None).await
+    }
+
+    /// Subscribe to market lifecycle events
     ///
-    /// * `sids` - Subscription IDs to unsubscribe from
-    pub async fn unsubscribe(&mut self, sids: &[u64]) -> Result<u64, Error> {
-        let cmd = WsCommand::Unsubscribe {
-            id: self.message_id,
+    /// See [`WebSocketClient::subscribe_market_lifecycle`].
+    pub async fn subscribe_market_lifecycle(
+        &self,
+        _market_tickers: Option<&[&str]>,
+    ) -> Result<u64, Error> {
+        self.subscribe("market_lifecycle_v2", None).await
+    }
+
+    /// Unsubscribe from channels by subscription ID
+    pub async fn unsubscribe(&self, sids: &[u64]) -> Result<u64, Error> {
+        self.send_command(|id| WsCommand::Unsubscribe {
+            id,
             params: crate::types::messages::UnsubscribeParams {
                 sids: sids.to_vec(),
             },
-        };
-        self.send_command(cmd).await
+        })
+        .await
     }
 
     /// Update an existing subscription to add or remove markets
     ///
-    /// # Arguments
-    ///
-    /// * `sid` - The subscription ID to update
-    /// * `add_tickers` - Market tickers to add
-    /// * `remove_tickers` - Market tickers to remove
+    /// See [`WebSocketClient::update_subscription`].
     pub async fn update_subscription(
-        &mut self,
+        &self,
         sid: u64,
         add_tickers: Option<&[&str]>,
         remove_tickers: Option<&[&str]>,
     ) -> Result<u64, Error> {
-        let cmd = WsCommand::UpdateSubscription {
-            id: self.message_id,
-            params: UpdateSubscriptionParams {
-                sid: Some(sid),
-                sids: None,
-                market_ticker: None,
-                market_tickers: add_tickers
-                    .or(remove_tickers)
-                    .map(|t| t.iter().map(|s| s.to_string()).collect()),
-                send_initial_snapshot: None,
-                action: if add_tickers.is_some() {
-                    UpdateSubscriptionAction::AddMarkets
-                } else {
-                    UpdateSubscriptionAction::DeleteMarkets
-                },
-            },
-        };
-        self.send_command(cmd).await
+        self.send_command(|id| update_subscription_command(id, sid, add_tickers, remove_tickers))
+            .await
     }
 
     /// List current subscriptions
-    pub async fn list_subscriptions(&mut self) -> Result<u64, Error> {
-        let cmd = WsCommand::ListSubscriptions {
-            id: self.message_id,
-        };
-        self.send_command(cmd).await
+    ///
+    /// The server's reply arrives on the [`WsReader`].
+    pub async fn list_subscriptions(&self) -> Result<u64, Error> {
+        self.send_command(|id| WsCommand::ListSubscriptions { id })
+            .await
     }
 
-    /// Receive the next message from the WebSocket
-    ///
-    /// This method also handles subscription tracking automatically:
-    /// - When a `Subscribed` message is received, it adds to the subscriptions map
-    /// - When an `Unsubscribed` message is received, it removes from the subscriptions map
+    /// Send a pong frame with the given payload
+    pub async fn send_pong(&self, data: Vec<u8>) -> Result<(), Error> {
+        self.shared
+            .write
+            .lock()
+            .await
+            .send(Message::Pong(data))
+            .await?;
+        Ok(())
+    }
+
+    /// Get a snapshot of the active subscriptions
+    #[must_use]
+    pub fn subscriptions(&self) -> FxHashMap<u64, SubscriptionInfo> {
+        self.shared.tracker.lock().subscriptions.clone()
+    }
+
+    /// Get the market tickers with a confirmed orderbook subscription
+    #[must_use]
+    pub fn subscribed_tickers(&self) -> FxHashSet<String> {
+        self.shared.tracker.lock().subscribed_tickers()
+    }
+
+    /// Get the requested tickers that have no confirmed orderbook subscription
     ///
-    /// # Returns
+    /// See [`WebSocketClient::missing_subscriptions`].
+    #[must_use]
+    pub fn missing_subscriptions(&self, requested: &[&str]) -> Vec<String> {
+        self.shared.tracker.lock().missing_subscriptions(requested)
+    }
+
+    /// Close the WebSocket connection
+    pub async fn close(&self) -> Result<(), Error> {
+        self.shared.write.lock().await.close().await?;
+        Ok(())
+    }
+}
+
+/// Message half of a [`WebSocketClient`] created by [`WebSocketClient::split`]
+///
+/// Reads the socket and keeps the subscription state shared with the
+/// paired [`WsWriter`] up to date.
+#[derive(Debug)]
+pub struct WsReader {
+    read: SplitStream<WsStream>,
+    shared: Arc<SplitShared>,
+    recorder: Option<MessageRecorder>,
+    auto_pong: bool,
+    heartbeat_timeout: Option<Duration>,
+}
+
+impl WsReader {
+    /// Receive the next message from the WebSocket
     ///
-    /// The next message, or `None` if the connection is closed.
+    /// Behaves like [`WebSocketClient::next`], including ping handling,
+    /// the heartbeat timeout and subscription tracking.
     pub async fn next(&mut self) -> Option<Result<WsMessage, Error>> {
         loop {
-            match self.read.next().await? {
-                Ok(Message::Text(text)) => {
-                    let result: Result<WsMessage, _> = serde_json::from_str(&text);
-                    match result {
-                        Ok(msg) => {
-                            // Track subscription state
-                            self.handle_subscription_tracking(&msg);
-                            return Some(Ok(msg));
-                        }
-                        Err(e) => return Some(Err(Error::from(e))),
+            let frame = match self.heartbeat_timeout {
+                Some(timeout) => {
+                    let shared = &self.shared;
+                    let ping = || async move {
+                        let mut write = shared.write.lock().await;
+                        write.send(Message::Ping(Vec::new())).await
+                    };
+                    match read_with_heartbeat(&mut self.read, timeout, ping).await {
+                        Ok(frame) => frame?,
+                        Err(e) => return Some(Err(e)),
                     }
                 }
-                Ok(Message::Ping(data)) => {
-                    // Respond to pings automatically
-                    if let Err(e) = self.write.send(Message::Pong(data)).await {
-                        return Some(Err(e.into()));
+                None => self.read.next().await?,
+            };
+            let result = match decode_frame(frame, &mut self.recorder) {
+                Decoded::Message(result) => result,
+                Decoded::Ping(data) if !self.auto_pong => Ok(WsMessage::Ping(data)),
+                Decoded::Ping(data) => {
+                    let mut write = self.shared.write.lock().await;
+                    match write.send(Message::Pong(data)).await {
+                        Ok(()) => continue,
+                        Err(e) => Err(e.into()),
                     }
                 }
-                Ok(Message::Close(_)) => {
-                    return Some(Err(Error::ConnectionClosed));
-                }
-                Ok(_) => {
-                    // Ignore other message types (Binary, Pong, Frame)
-                    continue;
-                }
-                Err(e) => {
-                    return Some(Err(e.into()));
-                }
-            }
-        }
-    }
+                Decoded::Skip => continue,
+            };
 
-    /// Handle subscription tracking for incoming messages
-    fn handle_subscription_tracking(&mut self, msg: &WsMessage) {
-        match msg {
-            WsMessage::Subscribed(subscribed) => {
-                // Move pending subscription to active
-                if let Some(id) = subscribed.id {
-                    if let Some(pending) = self.pending_subscriptions.remove(&id) {
-                        self.subscriptions.insert(
-                            subscribed.msg.sid,
-                            SubscriptionInfo {
-                                sid: subscribed.msg.sid,
-                                channel: pending.channel,
-                                market_tickers: pending.market_tickers,
-                            },
-                        );
-                    }
-                }
-            }
-            WsMessage::Unsubscribed(unsubscribed) => {
-                self.subscriptions.remove(&unsubscribed.sid);
-            }
-            WsMessage::Ok(ok) => {
-                if let Some(sid) = ok.sid {
-                    if let Some(OkMsgData::SubscriptionUpdate(update)) = &ok.msg {
-                        if let Some(subscription) = self.subscriptions.get_mut(&sid) {
-                            subscription.market_tickers = Some(update.market_tickers.clone());
-                        }
-                    }
-                }
+            if let Ok(msg) = &result {
+                self.shared.tracker.lock().handle_message(msg);
             }
-            _ => {}
+            return Some(result);
         }
     }
 
-    /// Close the WebSocket connection
-    pub async fn close(&mut self) -> Result<(), Error> {
-        self.write.close().await?;
-        Ok(())
+    /// Record every received text frame with the given recorder
+    ///
+    /// See [`WebSocketClient::set_recorder`].
+    pub fn set_recorder(&mut self, recorder: Option<MessageRecorder>) -> Option<MessageRecorder> {
+        std::mem::replace(&mut self.recorder, recorder)
     }
 }
 
@@ -485,6 +1340,8 @@ pub struct ReconnectConfig {
     pub max_delay_ms: u64,
     /// Multiplier for exponential backoff
     pub backoff_multiplier: f64,
+    /// Give up once disconnected for this long, regardless of attempts
+    pub max_downtime: Option<Duration>,
 }
 
 impl Default for ReconnectConfig {
@@ -494,6 +1351,7 @@ impl Default for ReconnectConfig {
             initial_delay_ms: 100,
             max_delay_ms: 30_000,
             backoff_multiplier: 2.0,
+            max_downtime: None,
         }
     }
 }
@@ -533,6 +1391,16 @@ impl ReconnectConfig {
         self
     }
 
+    /// Set the maximum total downtime before giving up
+    ///
+    /// Measured from the moment the connection was lost, across all
+    /// attempts. Once exceeded, reconnection fails with [`Error::Timeout`].
+    #[must_use]
+    pub const fn max_downtime(mut self, downtime: Duration) -> Self {
+        self.max_downtime = Some(downtime);
+        self
+    }
+
     /// Calculate delay for a given retry attempt
     #[must_use]
     pub fn delay_for_attempt(&self, attempt: u32) -> std::time::Duration {
@@ -612,8 +1480,29 @@ pub struct ReconnectingWebSocket {
     reconnect_attempt: u32,
     /// Whether we're currently trying to reconnect
     is_reconnecting: bool,
+    /// When the connection was lost, cleared once reconnected
+    disconnected_at: Option<Instant>,
+    /// Set by a fatal server error; stops automatic reconnection
+    fatal_error: Option<String>,
+    /// Books fed from incoming messages, resynced on sequence gaps
+    orderbook_manager: Option<Arc<OrderbookManager>>,
+    /// Minimum time between resync attempts for one market
+    resync_interval: Duration,
+    /// Markets awaiting a fresh snapshot, with their last resync attempt
+    pending_resyncs: FxHashMap<String, Option<Instant>>,
+    /// Applied to every connection, see [`WebSocketClient::with_heartbeat_timeout`]
+    heartbeat_timeout: Option<Duration>,
+    /// How long to wait for replayed subscriptions to be confirmed
+    resubscribe_timeout: Option<Duration>,
+    /// Messages read while confirming replayed subscriptions, not yet returned
+    replayed: VecDeque<WsMessage>,
+    /// Replayed subscriptions the server did not confirm on the last reconnect
+    unconfirmed: Vec<UnconfirmedSubscription>,
 }
 
+/// Default for [`ReconnectingWebSocket::with_resync_interval`]
+const DEFAULT_RESYNC_INTERVAL: Duration = Duration::from_secs(5);
+
 impl std::fmt::Debug for ReconnectingWebSocket {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ReconnectingWebSocket")
@@ -621,6 +1510,7 @@ impl std::fmt::Debug for ReconnectingWebSocket {
             .field("reconnect_attempt", &self.reconnect_attempt)
             .field("is_reconnecting", &self.is_reconnecting)
             .field("subscription_count", &self.subscription_requests.len())
+            .field("pending_resyncs", &self.pending_resyncs.len())
             .finish()
     }
 }
@@ -629,15 +1519,116 @@ impl ReconnectingWebSocket {
     /// Connect to the Kalshi WebSocket API with reconnection support
     pub async fn connect(config: Config, reconnect_config: ReconnectConfig) -> Result<Self, Error> {
         let client = WebSocketClient::connect(&config).await?;
+        Ok(Self::from_client(client, config, reconnect_config))
+    }
 
-        Ok(Self {
+    /// Wrap an already connected client
+    fn from_client(
+        client: WebSocketClient,
+        config: Config,
+        reconnect_config: ReconnectConfig,
+    ) -> Self {
+        Self {
             client: Some(client),
             config,
             reconnect_config,
             subscription_requests: Vec::new(),
             reconnect_attempt: 0,
             is_reconnecting: false,
-        })
+            disconnected_at: None,
+            fatal_error: None,
+            orderbook_manager: None,
+            resync_interval: DEFAULT_RESYNC_INTERVAL,
+            pending_resyncs: FxHashMap::default(),
+            heartbeat_timeout: None,
+            resubscribe_timeout: None,
+            replayed: VecDeque::new(),
+            unconfirmed: Vec::new(),
+        }
+    }
+
+    /// Feed every received message into `manager` and resync books on gaps
+    ///
+    /// Each message returned by [`next`](Self::next) is first passed to
+    /// [`OrderbookManager::process_message`]. When that reports a sequence
+    /// gap, the market is removed from and re-added to its orderbook
+    /// subscription, which makes the server send a fresh snapshot. Gaps are
+    /// logged at `warn` level and handled here rather than returned as errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::sync::Arc;
+    /// use kalshi_trading::Config;
+    /// use kalshi_trading::client::websocket::{ReconnectingWebSocket, ReconnectConfig};
+    /// use kalshi_trading::orderbook::OrderbookManager;
+    ///
+    /// # async fn example() -> kalshi_trading::Result<()> {
+    /// let manager = Arc::new(OrderbookManager::new());
+    /// let mut ws = ReconnectingWebSocket::connect(Config::new("key", "pem"), ReconnectConfig::default())
+    ///     .await?
+    ///     .with_orderbook_manager(Arc::clone(&manager));
+    ///
+    /// ws.subscribe_orderbook(&["KXBTC-25JAN"]).await?;
+    /// while let Some(msg) = ws.next().await {
+    ///     msg?;
+    ///     println!("{:?}", manager.best_bid("KXBTC-25JAN"));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_orderbook_manager(mut self, manager: Arc<OrderbookManager>) -> Self {
+        self.orderbook_manager = Some(manager);
+        self
+    }
+
+    /// Set the minimum time between resyncs of one market (default 5s)
+    ///
+    /// A market that still needs a snapshot after this long is resubscribed
+    /// again, so a persistently lossy stream cannot trigger a resubscribe
+    /// loop.
+    #[must_use]
+    pub fn with_resync_interval(mut self, interval: Duration) -> Self {
+        self.resync_interval = interval;
+        self
+    }
+
+    /// Reconnect when the connection goes silent for too long
+    ///
+    /// Sets [`WebSocketClient::with_heartbeat_timeout`] on the current
+    /// connection and every reconnected one.
+    #[must_use]
+    pub fn with_heartbeat_timeout(mut self, timeout: Duration) -> Self {
+        self.heartbeat_timeout = Some(timeout);
+        if let Some(client) = &mut self.client {
+            client.heartbeat_timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Verify replayed subscriptions after every reconnect (default off)
+    ///
+    /// After replaying subscriptions, the new connection is read for up to
+    /// `timeout` until each subscribe command is confirmed or rejected.
+    /// Messages read meanwhile are returned by [`next`](Self::next) as
+    /// usual. Commands that were rejected or not confirmed in time are
+    /// logged at `warn` level and reported by
+    /// [`unconfirmed_subscriptions`](Self::unconfirmed_subscriptions).
+    #[must_use]
+    pub fn with_resubscribe_verification(mut self, timeout: Duration) -> Self {
+        self.resubscribe_timeout = Some(timeout);
+        self
+    }
+
+    /// Get the replayed subscriptions the server did not confirm
+    ///
+    /// Refers to the most recent reconnect and is always empty unless
+    /// [`with_resubscribe_verification`](Self::with_resubscribe_verification)
+    /// is set.
+    #[must_use]
+    pub fn unconfirmed_subscriptions(&self) -> &[UnconfirmedSubscription] {
+        &self.unconfirmed
     }
 
     /// Check if currently connected
@@ -664,10 +1655,37 @@ impl ReconnectingWebSocket {
         self.client.as_ref().map(|c| c.subscriptions())
     }
 
+    /// Get the market tickers with a confirmed orderbook subscription
+    ///
+    /// Empty while disconnected.
+    #[must_use]
+    pub fn subscribed_tickers(&self) -> FxHashSet<String> {
+        self.client
+            .as_ref()
+            .map(|c| c.subscribed_tickers())
+            .unwrap_or_default()
+    }
+
+    /// Get the requested tickers that have no confirmed orderbook subscription
+    ///
+    /// Use this after a large batch subscribe to find markets that never
+    /// confirmed, then retry them with [`subscribe_orderbook`](Self::subscribe_orderbook).
+    #[must_use]
+    pub fn missing_subscriptions(&self, requested: &[&str]) -> Vec<String> {
+        match &self.client {
+            Some(client) => client.missing_subscriptions(requested),
+            None => requested.iter().map(|t| t.to_string()).collect(),
+        }
+    }
+
     /// Subscribe to orderbook updates
     ///
-    /// The subscription will be automatically replayed if the connection is lost.
-    pub async fn subscribe_orderbook(&mut self, market_tickers: &[&str]) -> Result<u64, Error> {
+    /// The subscription will be automatically replayed if the connection is
+    /// lost, chunked the same way as [`WebSocketClient::subscribe_orderbook`].
+    pub async fn subscribe_orderbook(
+        &mut self,
+        market_tickers: &[&str],
+    ) -> Result<Vec<u64>, Error> {
         let tickers: Vec<String> = market_tickers.iter().map(|s| s.to_string()).collect();
         self.subscription_requests
             .push(SubscriptionRequest::Orderbook(tickers));
@@ -763,12 +1781,27 @@ impl ReconnectingWebSocket {
     ///
     /// This method will automatically attempt to reconnect if the connection
     /// is lost, replaying all subscriptions after successful reconnection.
+    ///
+    /// After a fatal error message (see
+    /// [`ErrorMsg::is_fatal`](crate::types::messages::ErrorMsg::is_fatal)), automatic
+    /// reconnection stops and returns [`Error::Authentication`]. Call
+    /// [`reconnect`](Self::reconnect) to try again explicitly.
     pub async fn next(&mut self) -> Option<Result<WsMessage, Error>> {
         loop {
             if let Some(ref mut client) = self.client {
-                match client.next().await {
+                let received = match self.replayed.pop_front() {
+                    Some(msg) => Some(Ok(msg)),
+                    None => client.next().await,
+                };
+                match received {
                     Some(Ok(msg)) => {
                         self.reconnect_attempt = 0; // Reset on successful message
+                        if let WsMessage::Error(err) = &msg {
+                            if err.is_fatal() {
+                                self.fatal_error = Some(err.msg.msg.clone());
+                            }
+                        }
+                        self.track_orderbooks(&msg).await;
                         return Some(Ok(msg));
                     }
                     Some(Err(Error::ConnectionClosed)) | None => {
@@ -793,51 +1826,146 @@ impl ReconnectingWebSocket {
         }
     }
 
-    /// Attempt to reconnect with exponential backoff
-    async fn attempt_reconnect(&mut self) -> Result<(), Error> {
-        self.is_reconnecting = true;
+    /// Apply `msg` to the orderbook manager and resync any books that need it
+    async fn track_orderbooks(&mut self, msg: &WsMessage) {
+        let Some(manager) = &self.orderbook_manager else {
+            return;
+        };
 
-        loop {
-            // Check max retries
-            if self.reconnect_config.max_retries > 0
-                && self.reconnect_attempt >= self.reconnect_config.max_retries
-            {
-                self.is_reconnecting = false;
-                return Err(Error::ConnectionClosed);
+        if let Err(Error::SequenceGap { expected, got }) = manager.process_message(msg) {
+            if let WsMessage::OrderbookDelta(delta) = msg {
+                let ticker = &delta.msg.market_ticker;
+                tracing::warn!(ticker = %ticker, expected, got, "orderbook sequence gap");
+                self.pending_resyncs.entry(ticker.clone()).or_insert(None);
             }
+        }
 
-            // Calculate and wait for backoff delay
-            let delay = self
-                .reconnect_config
-                .delay_for_attempt(self.reconnect_attempt);
-            tokio::time::sleep(delay).await;
+        if !self.pending_resyncs.is_empty() {
+            self.resync_orderbooks().await;
+        }
+    }
 
-            self.reconnect_attempt += 1;
+    /// Resubscribe markets still waiting for a snapshot, at most once per
+    /// `resync_interval` each
+    async fn resync_orderbooks(&mut self) {
+        let (Some(manager), Some(client)) = (&self.orderbook_manager, &mut self.client) else {
+            return;
+        };
 
-            // Attempt to connect
-            match WebSocketClient::connect(&self.config).await {
-                Ok(mut client) => {
-                    // Replay subscriptions
-                    if self.replay_subscriptions(&mut client).await.is_err() {
-                        // Failed to replay, try again
-                        continue;
-                    }
+        // Done once the snapshot has arrived, or if the market was removed
+        self.pending_resyncs.retain(|ticker, _| {
+            matches!(
+                manager.get_state(ticker),
+                Some(OrderbookState::NeedsResync | OrderbookState::WaitingForSnapshot)
+            )
+        });
 
-                    self.client = Some(client);
-                    self.is_reconnecting = false;
-                    return Ok(());
-                }
-                Err(_) => {
-                    // Connection failed, continue loop to retry
-                    continue;
-                }
+        let now = Instant::now();
+        for (ticker, last_attempt) in &mut self.pending_resyncs {
+            if last_attempt.is_some_and(|at| now.duration_since(at) < self.resync_interval) {
+                continue;
+            }
+            *last_attempt = Some(now);
+
+            let sid = client.subscriptions().values().find_map(|info| {
+                let covers = info.channel == "orderbook_delta"
+                    && info
+                        .market_tickers
+                        .as_ref()
+                        .is_some_and(|tickers| tickers.contains(ticker));
+                covers.then_some(info.sid)
+            });
+            let Some(sid) = sid else {
+                tracing::warn!(ticker = %ticker, "no orderbook subscription to resync");
+                continue;
+            };
+
+            tracing::info!(ticker = %ticker, sid, "resubscribing to resync orderbook");
+            let tickers = [ticker.as_str()];
+            let result = match client.update_subscription(sid, None, Some(&tickers)).await {
+                Ok(_) => client
+                    .update_subscription(sid, Some(&tickers), None)
+                    .await
+                    .map(|_| ()),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                // A broken connection surfaces on the next read
+                tracing::warn!(ticker = %ticker, error = %e, "orderbook resync failed");
             }
         }
     }
 
+    /// Attempt to reconnect with exponential backoff
+    async fn attempt_reconnect(&mut self) -> Result<(), Error> {
+        // Reconnecting with the same credentials cannot fix an auth error
+        if let Some(reason) = &self.fatal_error {
+            return Err(Error::Authentication(reason.clone()));
+        }
+
+        self.is_reconnecting = true;
+        let disconnected_at = *self.disconnected_at.get_or_insert_with(Instant::now);
+
+        let config = &self.config;
+        let requests = &self.subscription_requests;
+        let heartbeat_timeout = self.heartbeat_timeout;
+        let resubscribe_timeout = self.resubscribe_timeout;
+        let result = reconnect_with_backoff(
+            &self.reconnect_config,
+            &mut self.reconnect_attempt,
+            disconnected_at,
+            || async move {
+                let mut client = WebSocketClient::connect(config).await?;
+                client.heartbeat_timeout = heartbeat_timeout;
+                let restored =
+                    Self::restore_subscriptions(requests, resubscribe_timeout, &mut client).await?;
+                Ok((client, restored))
+            },
+        )
+        .await;
+
+        self.is_reconnecting = false;
+        let (client, (received, unconfirmed)) = result?;
+        self.client = Some(client);
+        self.disconnected_at = None;
+        self.replayed.extend(received);
+        self.unconfirmed = unconfirmed;
+        Ok(())
+    }
+
+    /// Replay saved subscriptions and, if `verify_timeout` is set, wait for
+    /// them to be confirmed
+    ///
+    /// Returns the messages read while waiting and the unconfirmed
+    /// subscriptions, each of which is logged.
+    async fn restore_subscriptions(
+        requests: &[SubscriptionRequest],
+        verify_timeout: Option<Duration>,
+        client: &mut WebSocketClient,
+    ) -> Result<(Vec<WsMessage>, Vec<UnconfirmedSubscription>), Error> {
+        Self::replay_subscriptions(requests, client).await?;
+        let Some(timeout) = verify_timeout else {
+            return Ok((Vec::new(), Vec::new()));
+        };
+
+        let (received, unconfirmed) = client.confirm_pending(timeout).await?;
+        for subscription in &unconfirmed {
+            tracing::warn!(
+                channel = %subscription.channel,
+                market_tickers = ?subscription.market_tickers,
+                error = ?subscription.error,
+                "replayed subscription not confirmed"
+            );
+        }
+        Ok((received, unconfirmed))
+    }
+
     /// Replay all saved subscriptions on a new connection
-    async fn replay_subscriptions(&self, client: &mut WebSocketClient) -> Result<(), Error> {
-        for request in &self.subscription_requests {
+    async fn replay_subscriptions(
+        requests: &[SubscriptionRequest],
+        client: &mut WebSocketClient,
+    ) -> Result<(), Error> {
+        for request in requests {
             match request {
                 SubscriptionRequest::Orderbook(tickers) => {
                     let refs: Vec<&str> = tickers.iter().map(|s| s.as_str()).collect();
@@ -884,6 +2012,8 @@ impl ReconnectingWebSocket {
         }
         self.client = None;
         self.reconnect_attempt = 0;
+        self.disconnected_at = None;
+        self.fatal_error = None;
         self.attempt_reconnect().await
     }
 
@@ -897,9 +2027,98 @@ impl ReconnectingWebSocket {
     }
 }
 
+/// Retry `connect` with exponential backoff until it succeeds or the
+/// attempt or downtime budget in `config` runs out
+///
+/// `attempt` carries the attempt count across calls so backoff keeps growing
+/// if the caller retries after an error.
+async fn reconnect_with_backoff<T, F, Fut>(
+    config: &ReconnectConfig,
+    attempt: &mut u32,
+    disconnected_at: Instant,
+    mut connect: F,
+) -> Result<T, Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, Error>>,
+{
+    loop {
+        if config.max_retries > 0 && *attempt >= config.max_retries {
+            return Err(Error::ConnectionClosed);
+        }
+
+        let mut delay = config.delay_for_attempt(*attempt);
+        if let Some(max_downtime) = config.max_downtime {
+            let remaining = max_downtime.saturating_sub(disconnected_at.elapsed());
+            if remaining.is_zero() {
+                return Err(Error::Timeout);
+            }
+            // Don't sleep past the budget
+            delay = delay.min(remaining);
+        }
+        tokio::time::sleep(delay).await;
+
+        *attempt += 1;
+
+        if let Ok(value) = connect().await {
+            return Ok(value);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::testing::ws_pair;
+
+    fn subscribed(id: u64, sid: u64) -> WsMessage {
+        serde_json::from_str(&format!(
+            r#"{{"type":"subscribed","id":{},"msg":{{"channel":"orderbook_delta","sid":{}}}}}"#,
+            id, sid
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_missing_subscriptions() {
+        let mut tracker = SubscriptionTracker::default();
+        tracker.add_pending(1, "orderbook_delta", Some(vec!["A".into(), "B".into()]));
+        tracker.add_pending(2, "orderbook_delta", Some(vec!["C".into()]));
+        tracker.add_pending(3, "orderbook_delta", Some(vec!["D".into()]));
+
+        // Only the first and third commands are confirmed
+        tracker.handle_message(&subscribed(1, 10));
+        tracker.handle_message(&subscribed(3, 11));
+
+        let subscribed = tracker.subscribed_tickers();
+        assert_eq!(subscribed.len(), 3);
+        assert!(subscribed.contains("A") && subscribed.contains("D"));
+
+        let missing = tracker.missing_subscriptions(&["A", "B", "C", "D", "E"]);
+        assert_eq!(missing, vec!["C".to_string(), "E".to_string()]);
+    }
+
+    #[test]
+    fn test_tracker_applies_ok_responses() {
+        let mut tracker = SubscriptionTracker::default();
+        tracker.add_pending(1, "orderbook_delta", Some(vec!["A".into()]));
+        tracker.add_pending(2, "orderbook_delta", Some(vec!["B".into()]));
+        tracker.handle_message(&subscribed(1, 10));
+        tracker.handle_message(&subscribed(2, 11));
+
+        let update = r#"{"type":"ok","id":3,"sid":10,"seq":5,"msg":{"market_tickers":["A","C"]}}"#;
+        tracker.handle_message(&serde_json::from_str(update).unwrap());
+        assert_eq!(
+            tracker.subscriptions[&10].market_tickers,
+            Some(vec!["A".to_string(), "C".to_string()])
+        );
+
+        // Subscriptions missing from the server's list are dropped
+        let list = r#"{"type":"ok","id":4,"msg":[{"channel":"orderbook_delta","sid":10}]}"#;
+        tracker.handle_message(&serde_json::from_str(list).unwrap());
+        assert_eq!(tracker.subscriptions.len(), 1);
+        assert!(tracker.subscriptions.contains_key(&10));
+    }
 
     #[test]
     fn test_reconnect_config_default() {
@@ -957,4 +2176,377 @@ mod tests {
             std::time::Duration::from_millis(1000)
         );
     }
+
+    #[tokio::test]
+    async fn test_reconnect_gives_up_after_max_downtime() {
+        // Unlimited retries, so only the downtime budget can stop the loop
+        let config = ReconnectConfig::new()
+            .max_retries(0)
+            .initial_delay_ms(5)
+            .max_delay_ms(5)
+            .max_downtime(Duration::from_millis(50));
+        let mut attempt = 0;
+        let started = Instant::now();
+
+        let result: Result<(), Error> =
+            reconnect_with_backoff(&config, &mut attempt, started, || async {
+                Err(Error::ConnectionClosed)
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::Timeout)));
+        assert!(attempt > 1);
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_orderbook_chunks_tickers() {
+        let (mut client, mut server) = ws_pair().await;
+        let tickers: Vec<String> = (0..250).map(|i| format!("M{}", i)).collect();
+        let refs: Vec<&str> = tickers.iter().map(|s| s.as_str()).collect();
+
+        let msg_ids = client.subscribe_orderbook(&refs).await.unwrap();
+        assert_eq!(msg_ids, [1, 2, 3]);
+        assert_eq!(client.tracker.pending.len(), 3);
+
+        let mut sizes = Vec::new();
+        for expected_id in msg_ids {
+            let Some(Ok(Message::Text(text))) = server.next().await else {
+                panic!("expected a subscribe command");
+            };
+            let cmd: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(cmd["cmd"], "subscribe");
+            assert_eq!(cmd["id"], expected_id);
+            sizes.push(cmd["params"]["market_tickers"].as_array().unwrap().len());
+        }
+        assert_eq!(sizes, [100, 100, 50]);
+    }
+
+    #[tokio::test]
+    async fn test_heartbeat_timeout() {
+        let timeout = Duration::from_millis(50);
+
+        // A server that answers the idle ping keeps the connection alive
+        let (client, mut server) = ws_pair().await;
+        let mut client = client.with_heartbeat_timeout(timeout);
+        let responder = tokio::spawn(async move {
+            let frame = server.next().await.unwrap().unwrap();
+            assert!(matches!(frame, Message::Ping(_)));
+            server
+                .send(Message::Text(r#"{"type":"ok","id":1}"#.to_string()))
+                .await
+                .unwrap();
+            server
+        });
+        assert!(matches!(client.next().await, Some(Ok(WsMessage::Ok(_)))));
+        let _server = responder.await.unwrap();
+
+        // A silent server is declared dead after the ping goes unanswered
+        let (client, _server) = ws_pair().await;
+        let (_writer, mut reader) = client.with_heartbeat_timeout(timeout).split();
+        let started = Instant::now();
+        assert!(matches!(
+            reader.next().await,
+            Some(Err(Error::ConnectionClosed))
+        ));
+        assert!(started.elapsed() >= timeout * 2);
+    }
+
+    #[tokio::test]
+    async fn test_connect_uses_websocket_url_override() {
+        use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut headers = None;
+            // The error type is fixed by tungstenite
+            #[allow(clippy::result_large_err)]
+            let callback = |req: &Request, resp: Response| {
+                headers = Some(req.headers().clone());
+                Ok(resp)
+            };
+            let _ws = tokio_tungstenite::accept_hdr_async(stream, callback)
+                .await
+                .unwrap();
+            headers.unwrap()
+        });
+
+        let config = Config::new("key", crate::client::testing::test_private_key_pem())
+            .with_websocket_url(format!("ws://{}", addr));
+        let _client = WebSocketClient::connect(&config).await.unwrap();
+
+        let headers = server.await.unwrap();
+        assert_eq!(headers["host"], addr.to_string().as_str());
+        assert_eq!(headers["kalshi-access-key"], "key");
+        assert!(headers.contains_key("kalshi-access-signature"));
+
+        assert_eq!(
+            host_header("wss://demo-api.kalshi.co/trade-api/ws/v2").unwrap(),
+            "demo-api.kalshi.co"
+        );
+        assert!(matches!(host_header("not a url"), Err(Error::Config(_))));
+    }
+
+    #[tokio::test]
+    async fn test_connect_with_custom_tls() {
+        use rustls::pki_types::pem::PemObject;
+        use rustls::pki_types::CertificateDer;
+
+        let mut roots = rustls::RootCertStore::empty();
+        roots
+            .add(
+                CertificateDer::from_pem_slice(crate::client::testing::TEST_CERT_PEM.as_bytes())
+                    .unwrap(),
+            )
+            .unwrap();
+        let tls = rustls::ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(stream).await.unwrap()
+        });
+
+        // The TLS settings only take effect for wss:// URLs
+        let config = Config::new("key", crate::client::testing::test_private_key_pem())
+            .with_websocket_url(format!("ws://{}", addr))
+            .with_websocket_tls(Arc::new(tls));
+        assert!(config.websocket_tls().is_some());
+        let _client = WebSocketClient::connect(&config).await.unwrap();
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pings_surfaced_without_auto_pong() {
+        let (client, mut server) = ws_pair().await;
+        let mut client = client.with_auto_pong(false);
+
+        server.send(Message::Ping(b"hb".to_vec())).await.unwrap();
+        server
+            .send(Message::Text(r#"{"type":"ok","id":1}"#.to_string()))
+            .await
+            .unwrap();
+
+        assert!(matches!(client.next().await, Some(Ok(WsMessage::Ping(data))) if data == b"hb"));
+        assert!(matches!(client.next().await, Some(Ok(WsMessage::Ok(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_pings_hidden_with_auto_pong() {
+        let (mut client, mut server) = ws_pair().await;
+
+        server.send(Message::Ping(b"hb".to_vec())).await.unwrap();
+        server
+            .send(Message::Text(r#"{"type":"ok","id":1}"#.to_string()))
+            .await
+            .unwrap();
+
+        assert!(matches!(client.next().await, Some(Ok(WsMessage::Ok(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_into_stream_forwards_messages_and_commands() {
+        let (client, mut server) = ws_pair().await;
+        let (handle, mut messages) = client.into_stream();
+
+        // Subscribe from another task while this one reads
+        let subscriber = handle.clone();
+        let subscribe = tokio::spawn(async move { subscriber.subscribe_trades(None).await });
+        let Some(Ok(Message::Text(text))) = server.next().await else {
+            panic!("expected a subscribe command");
+        };
+        let cmd: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(cmd["params"]["channels"][0], "trade");
+        assert_eq!(subscribe.await.unwrap().unwrap(), 1);
+
+        // Pings are answered inside the task and never reach the receiver
+        server.send(Message::Ping(b"hb".to_vec())).await.unwrap();
+        assert!(matches!(server.next().await, Some(Ok(Message::Pong(data))) if data == b"hb"));
+
+        server
+            .send(Message::Text(
+                r#"{"type":"subscribed","id":1,"msg":{"channel":"trade","sid":7}}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        assert!(matches!(
+            messages.recv().await,
+            Some(Ok(WsMessage::Subscribed(_)))
+        ));
+        assert!(handle.subscriptions().await.unwrap().contains_key(&7));
+
+        // Dropping every handle closes the connection and ends the stream
+        drop(handle);
+        assert!(matches!(server.next().await, Some(Ok(Message::Close(_)))));
+        assert!(messages.recv().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_split_writer_and_reader_share_state() {
+        let (client, mut server) = ws_pair().await;
+        let (writer, mut reader) = client.split();
+
+        // Subscribe from another task while this one reads
+        let subscriber = writer.clone();
+        let subscribe = tokio::spawn(async move { subscriber.subscribe_fills(None).await });
+        let Some(Ok(Message::Text(text))) = server.next().await else {
+            panic!("expected a subscribe command");
+        };
+        let cmd: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(cmd["params"]["channels"][0], "fill");
+        assert_eq!(subscribe.await.unwrap().unwrap(), 1);
+
+        // The reader answers pings and records acknowledgements for every writer
+        server.send(Message::Ping(b"hb".to_vec())).await.unwrap();
+        server
+            .send(Message::Text(
+                r#"{"type":"subscribed","id":1,"msg":{"channel":"fill","sid":7}}"#.to_string(),
+            ))
+            .await
+            .unwrap();
+        assert!(matches!(
+            reader.next().await,
+            Some(Ok(WsMessage::Subscribed(_)))
+        ));
+        assert!(matches!(server.next().await, Some(Ok(Message::Pong(data))) if data == b"hb"));
+        assert_eq!(writer.subscriptions()[&7].channel, "fill");
+
+        // Message IDs keep counting across clones
+        assert_eq!(writer.list_subscriptions().await.unwrap(), 2);
+        let Some(Ok(Message::Text(text))) = server.next().await else {
+            panic!("expected a list command");
+        };
+        assert!(text.contains("list_subscriptions"));
+
+        writer.close().await.unwrap();
+        assert!(matches!(server.next().await, Some(Ok(Message::Close(_)))));
+    }
+
+    #[tokio::test]
+    async fn test_restore_reports_unconfirmed_subscriptions() {
+        let (mut client, mut server) = ws_pair().await;
+        let requests = [
+            SubscriptionRequest::Orderbook(vec!["M".to_string()]),
+            SubscriptionRequest::Ticker(None),
+            SubscriptionRequest::Trades(Some(vec!["T".to_string()])),
+        ];
+
+        // Confirm the orderbook, reject the ticker, never answer the trades
+        let responder = tokio::spawn(async move {
+            for _ in 0..3 {
+                let Some(Ok(Message::Text(_))) = server.next().await else {
+                    panic!("expected a subscribe command");
+                };
+            }
+            for frame in [
+                r#"{"type":"subscribed","id":1,"msg":{"channel":"orderbook_delta","sid":5}}"#,
+                r#"{"type":"orderbook_snapshot","sid":5,"seq":1,"msg":{"market_ticker":"M","market_id":"id"}}"#,
+                r#"{"type":"error","id":2,"msg":{"code":8,"msg":"Unknown channel name"}}"#,
+            ] {
+                server.send(Message::Text(frame.to_string())).await.unwrap();
+            }
+            server
+        });
+
+        let (received, unconfirmed) = ReconnectingWebSocket::restore_subscriptions(
+            &requests,
+            Some(Duration::from_millis(200)),
+            &mut client,
+        )
+        .await
+        .unwrap();
+        let _server = responder.await.unwrap();
+
+        // Data that arrived while waiting is kept for the caller
+        assert_eq!(received.len(), 3);
+        assert!(matches!(received[1], WsMessage::OrderbookSnapshot(_)));
+        assert_eq!(
+            unconfirmed,
+            vec![
+                UnconfirmedSubscription {
+                    channel: "ticker".to_string(),
+                    market_tickers: None,
+                    error: Some("Unknown channel name".to_string()),
+                },
+                UnconfirmedSubscription {
+                    channel: "trade".to_string(),
+                    market_tickers: Some(vec!["T".to_string()]),
+                    error: None,
+                },
+            ]
+        );
+        assert_eq!(client.subscriptions().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sequence_gap_triggers_rate_limited_resync() {
+        let (client, mut server) = ws_pair().await;
+        let manager = Arc::new(OrderbookManager::new());
+        let mut ws = ReconnectingWebSocket::from_client(
+            client,
+            Config::new("key", "pem"),
+            ReconnectConfig::default(),
+        )
+        .with_orderbook_manager(Arc::clone(&manager))
+        .with_resync_interval(Duration::from_secs(60));
+
+        ws.subscribe_orderbook(&["M"]).await.unwrap();
+        let Some(Ok(Message::Text(_))) = server.next().await else {
+            panic!("expected a subscribe command");
+        };
+
+        let snapshot = r#"{"type":"orderbook_snapshot","sid":5,"seq":1,"msg":{"market_ticker":"M","market_id":"id","yes_dollars_fp":[["0.4000","10.00"]]}}"#;
+        let delta = |seq: u64| {
+            format!(
+                r#"{{"type":"orderbook_delta","sid":5,"seq":{},"msg":{{"market_ticker":"M","market_id":"id","price_dollars":"0.4000","delta_fp":"1.00","side":"yes"}}}}"#,
+                seq
+            )
+        };
+        for frame in [
+            r#"{"type":"subscribed","id":1,"msg":{"channel":"orderbook_delta","sid":5}}"#
+                .to_string(),
+            snapshot.to_string(),
+            delta(2),
+            delta(4),
+            delta(5),
+        ] {
+            server.send(Message::Text(frame)).await.unwrap();
+            assert!(matches!(ws.next().await, Some(Ok(_))));
+        }
+        assert_eq!(manager.get_state("M"), Some(OrderbookState::NeedsResync));
+
+        // The gap removes and re-adds the market, exactly once
+        let mut actions = Vec::new();
+        for _ in 0..2 {
+            let Some(Ok(Message::Text(text))) = server.next().await else {
+                panic!("expected an update_subscription command");
+            };
+            let cmd: serde_json::Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(cmd["cmd"], "update_subscription");
+            assert_eq!(cmd["params"]["sid"], 5);
+            assert_eq!(cmd["params"]["market_tickers"][0], "M");
+            actions.push(cmd["params"]["action"].as_str().unwrap().to_string());
+        }
+        assert_eq!(actions, ["delete_markets", "add_markets"]);
+
+        // The fresh snapshot clears the pending resync
+        server
+            .send(Message::Text(snapshot.to_string()))
+            .await
+            .unwrap();
+        ws.next().await.unwrap().unwrap();
+        assert_eq!(manager.get_state("M"), Some(OrderbookState::Synchronized));
+        assert!(ws.pending_resyncs.is_empty());
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), server.next())
+                .await
+                .is_err()
+        );
+    }
 }
@@ -70,6 +70,10 @@ pub enum Error {
     #[error("Invalid ticker: {0}")]
     InvalidTicker(String),
 
+    /// Order rejected by client-side validation before being sent
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+
     /// Operation timed out
     #[error("Operation timed out")]
     Timeout,
@@ -144,6 +148,33 @@ impl ApiError {
         }
     }
 
+    /// Classify the error for retry or abort decisions
+    ///
+    /// Uses [`code`](Self::code) when present, falling back to the HTTP
+    /// status. The raw code is kept in [`ApiErrorKind::Unknown`] for codes
+    /// this crate does not recognize yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi_trading::error::{ApiError, ApiErrorKind};
+    ///
+    /// let err = ApiError::with_code(400, "insufficient_balance", "Insufficient balance");
+    /// assert_eq!(err.kind(), ApiErrorKind::InsufficientBalance);
+    /// ```
+    #[must_use]
+    pub fn kind(&self) -> ApiErrorKind {
+        match &self.code {
+            Some(code) => ApiErrorKind::from_code(code),
+            None => match self.status {
+                401 | 403 => ApiErrorKind::Unauthorized,
+                404 => ApiErrorKind::NotFound,
+                429 => ApiErrorKind::RateLimited,
+                _ => ApiErrorKind::Unknown(String::new()),
+            },
+        }
+    }
+
     /// Check if this is a client error (4xx)
     #[must_use]
     pub const fn is_client_error(&self) -> bool {
@@ -157,6 +188,45 @@ impl ApiError {
     }
 }
 
+/// Classification of a REST API error code
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiErrorKind {
+    /// Not enough balance to cover the order
+    InsufficientBalance,
+    /// The market is closed, paused, or not yet open
+    MarketNotActive,
+    /// Price outside the allowed range or off the tick grid
+    InvalidPrice,
+    /// Malformed request or invalid parameters
+    InvalidParameters,
+    /// The order or market does not exist
+    NotFound,
+    /// Credentials missing, invalid, or lacking permission
+    Unauthorized,
+    /// Too many requests
+    RateLimited,
+    /// Any other code, kept verbatim (empty if the API sent none)
+    Unknown(String),
+}
+
+impl ApiErrorKind {
+    /// Map a Kalshi error code, ignoring ASCII case
+    #[must_use]
+    pub fn from_code(code: &str) -> Self {
+        match code.to_ascii_lowercase().as_str() {
+            "insufficient_balance" => Self::InsufficientBalance,
+            "market_not_active" | "market_closed" => Self::MarketNotActive,
+            "invalid_price" => Self::InvalidPrice,
+            "invalid_parameters" | "bad_request" => Self::InvalidParameters,
+            "not_found" | "market_not_found" | "order_not_found" => Self::NotFound,
+            "unauthorized" | "forbidden" => Self::Unauthorized,
+            "too_many_requests" | "rate_limited" => Self::RateLimited,
+            _ => Self::Unknown(code.to_string()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,6 +264,33 @@ mod tests {
         assert!(err.to_string().contains("401"));
     }
 
+    #[test]
+    fn test_api_error_kind() {
+        let err = ApiError::with_code(400, "insufficient_balance", "Insufficient balance");
+        assert_eq!(err.kind(), ApiErrorKind::InsufficientBalance);
+        assert_eq!(
+            ApiError::with_code(409, "MARKET_CLOSED", "closed").kind(),
+            ApiErrorKind::MarketNotActive
+        );
+        assert_eq!(
+            ApiError::with_code(400, "invalid_price", "bad price").kind(),
+            ApiErrorKind::InvalidPrice
+        );
+
+        // Unrecognized codes keep the raw string
+        assert_eq!(
+            ApiError::with_code(400, "brand_new_code", "?").kind(),
+            ApiErrorKind::Unknown("brand_new_code".to_string())
+        );
+
+        // Without a code, fall back to the status
+        assert_eq!(ApiError::new(404, "gone").kind(), ApiErrorKind::NotFound);
+        assert_eq!(
+            ApiError::new(500, "oops").kind(),
+            ApiErrorKind::Unknown(String::new())
+        );
+    }
+
     #[test]
     fn test_error_is_client_server() {
         let client_err = ApiError::new(404, "Not found");
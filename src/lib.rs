@@ -6,7 +6,7 @@
 //!
 //! - **REST API Client** - Full coverage of trading and market data endpoints
 //! - **WebSocket Client** - Real-time orderbook, trades, fills, and lifecycle events
-//! - **HFT-Grade Orderbook** - Cache-optimized with O(log n) updates
+//! - **HFT-Grade Orderbook** - O(log n) `BTreeMap` ladders, or O(1) price-indexed arrays via `ArrayOrderbook`
 //! - **Async/Await** - Built on Tokio for maximum concurrency
 //!
 //! ## Quick Start
@@ -54,6 +54,7 @@
 //! - [`client`] - REST and WebSocket clients for API communication
 //! - [`types`] - Request/response types matching the Kalshi API
 //! - [`orderbook`] - High-performance orderbook data structure
+//! - [`analytics`] - Position and execution statistics derived from fills
 //! - [`config`] - Configuration and credentials management
 //! - [`error`] - Error types for the crate
 //!
@@ -71,6 +72,7 @@
 #![warn(rustdoc::missing_crate_level_docs)]
 #![deny(unsafe_code)]
 
+pub mod analytics;
 pub mod client;
 pub mod config;
 pub mod error;
@@ -92,7 +94,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 ///
 /// ```rust,no_run
 /// use kalshi_trading::{Config, KalshiClient};
-/// use kalshi_trading::types::{CreateOrderRequest, Side, Action};
+/// use kalshi_trading::types::{CreateOrderRequest, PriceExt, Side, Action};
 ///
 /// # async fn example() -> kalshi_trading::Result<()> {
 /// let config = Config::new("api-key", "private-key-pem");
@@ -103,7 +105,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 ///
 /// // Get your balance
 /// let balance = client.rest().get_balance().await?;
-/// println!("Balance: ${:.2}", balance.balance as f64 / 10000.0);
+/// println!("Balance: ${:.2}", balance.balance.to_dollars());
 ///
 /// // Place an order
 /// let order = CreateOrderRequest::limit("TICKER", Side::Yes, Action::Buy, 10, 5000);
@@ -197,15 +199,201 @@ impl KalshiClient {
         client::websocket::ReconnectingWebSocket::connect(self.config.clone(), reconnect_config)
             .await
     }
+
+    /// Stream orderbooks for several markets, one `watch` channel per market
+    ///
+    /// Opens a single reconnecting WebSocket, subscribes to orderbook updates
+    /// for all tickers, and spawns a background task that feeds an
+    /// [`orderbook::OrderbookPublisher`]. Each receiver always holds the
+    /// latest book for its market.
+    ///
+    /// A market that hits a sequence gap is resubscribed automatically, and
+    /// its receiver holds an empty book until the new snapshot arrives, so a
+    /// gapped book is never published. Stream errors are logged and
+    /// skipped.
+    ///
+    /// The background task exits once every receiver has been dropped (checked
+    /// as messages arrive) or when reconnection gives up.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kalshi_trading::{Config, KalshiClient};
+    ///
+    /// # async fn example() -> kalshi_trading::Result<()> {
+    /// let client = KalshiClient::new(Config::new("api-key", "private-key-pem"))?;
+    ///
+    /// let mut books = client.stream_per_market(&["KXBTC-25JAN", "KXBTC-26JAN"]).await?;
+    /// let mut rx = books.remove("KXBTC-25JAN").unwrap();
+    ///
+    /// while rx.changed().await.is_ok() {
+    ///     println!("Best bid: {:?}", rx.borrow().best_bid());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_per_market(
+        &self,
+        market_tickers: &[&str],
+    ) -> Result<std::collections::HashMap<String, tokio::sync::watch::Receiver<orderbook::Orderbook>>>
+    {
+        let (publisher, receivers) = orderbook::OrderbookPublisher::new(market_tickers);
+
+        // The socket applies updates to the publisher's manager and
+        // resubscribes markets after a sequence gap
+        let mut ws = self
+            .websocket_reconnecting(client::websocket::ReconnectConfig::default())
+            .await?
+            .with_orderbook_manager(std::sync::Arc::clone(publisher.manager()));
+        ws.subscribe_orderbook(market_tickers).await?;
+
+        tokio::spawn(async move {
+            while let Some(result) = ws.next().await {
+                match result {
+                    Ok(types::messages::WsMessage::OrderbookSnapshot(snapshot)) => {
+                        publisher.publish(&snapshot.msg.market_ticker);
+                    }
+                    Ok(types::messages::WsMessage::OrderbookDelta(delta)) => {
+                        publisher.publish(&delta.msg.market_ticker);
+                    }
+                    Ok(_) => {}
+                    Err(Error::ConnectionClosed) => break,
+                    Err(e) => tracing::warn!(error = %e, "orderbook stream error"),
+                }
+                if publisher.is_closed() {
+                    break;
+                }
+            }
+            let _ = ws.close().await;
+        });
+
+        Ok(receivers)
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
     use super::*;
+    use crate::client::testing::{market_json, test_private_key_pem, MockResponse, MockServer};
+    use crate::error::ApiErrorKind;
+
+    fn client_for(server: &MockServer, config: Config) -> KalshiClient {
+        KalshiClient::new(config.with_base_url(server.url())).unwrap()
+    }
+
+    /// A server answering 429 to the first `failures` requests, then an
+    /// exchange status
+    async fn throttled_server(failures: usize, retry_after: Option<&'static str>) -> MockServer {
+        let calls = AtomicUsize::new(0);
+        MockServer::start(move |_| {
+            if calls.fetch_add(1, Ordering::SeqCst) < failures {
+                let response = MockResponse::status(429, "");
+                match retry_after {
+                    Some(delay) => response.with_header("Retry-After", delay),
+                    None => response,
+                }
+            } else {
+                MockResponse::json(r#"{"trading_active":true,"exchange_active":true}"#)
+            }
+        })
+        .await
+    }
 
     #[test]
     fn test_config_creation() {
         let config = Config::new("test-key", "test-private-key");
         assert_eq!(config.api_key_id(), "test-key");
     }
+
+    #[tokio::test]
+    async fn test_requests_are_signed() {
+        let server = throttled_server(0, None).await;
+        let client = client_for(&server, Config::new("offline-key", test_private_key_pem()));
+        let status = client.rest().get_exchange_status().await.unwrap();
+        assert!(status.trading_active && status.exchange_active);
+
+        let request = &server.requests()[0];
+        assert_eq!(request.path, "/exchange/status");
+        assert_eq!(request.header("KALSHI-ACCESS-KEY"), Some("offline-key"));
+        assert!(request.header("KALSHI-ACCESS-SIGNATURE").is_some());
+        let timestamp = request.header("KALSHI-ACCESS-TIMESTAMP").unwrap();
+        assert!(timestamp.parse::<u64>().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_pagination_follows_cursor() {
+        let server = MockServer::start(|req| {
+            let (tickers, cursor) = if req.path.contains("cursor=page2") {
+                (vec!["C"], "")
+            } else {
+                (vec!["A", "B"], "page2")
+            };
+            let markets: Vec<_> = tickers.iter().map(|t| market_json(t, "EV")).collect();
+            MockResponse::json(format!(
+                r#"{{"markets":[{}],"cursor":"{}"}}"#,
+                markets.join(","),
+                cursor
+            ))
+        })
+        .await;
+
+        let client = client_for(&server, Config::new("key", test_private_key_pem()));
+        let markets = client
+            .rest()
+            .get_all_markets(None, None, None)
+            .await
+            .unwrap();
+        let tickers: Vec<_> = markets.iter().map(|m| m.ticker.as_str()).collect();
+        assert_eq!(tickers, ["A", "B", "C"]);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(!requests[0].path.contains("cursor="));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_handling() {
+        // Without retries the 429 is surfaced with its delay
+        let server = throttled_server(1, Some("5")).await;
+        let client = client_for(&server, Config::new("key", test_private_key_pem()));
+        let err = client.rest().get_exchange_status().await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                retry_after_ms: Some(5_000)
+            }
+        ));
+        assert!(client.rest().get_exchange_status().await.is_ok());
+
+        // With retries it is absorbed
+        let server = throttled_server(2, None).await;
+        let config = Config::new("key", test_private_key_pem())
+            .with_max_retries(3)
+            .with_retry_base_delay(Duration::from_millis(1));
+        let client = client_for(&server, config);
+        assert!(client.rest().get_exchange_status().await.is_ok());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_are_parsed() {
+        let server = MockServer::start(|_| {
+            MockResponse::status(
+                404,
+                r#"{"error":{"code":"not_found","message":"market not found"}}"#,
+            )
+        })
+        .await;
+
+        let client = client_for(&server, Config::new("key", test_private_key_pem()));
+        let Err(Error::Api(err)) = client.rest().get_market("MISSING").await else {
+            panic!("expected an API error");
+        };
+        assert_eq!(err.status, 404);
+        assert_eq!(err.message, "market not found");
+        assert_eq!(err.kind(), ApiErrorKind::NotFound);
+    }
 }
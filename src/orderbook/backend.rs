@@ -0,0 +1,304 @@
+//! Storage for one side of an orderbook.
+//!
+//! [`Orderbook`](super::Orderbook) keeps each price ladder in an
+//! [`OrderbookBackend`]. Two are provided:
+//!
+//! - `BTreeMap<Price, Quantity>` (the default) - O(log n) updates, memory
+//!   proportional to the number of levels
+//! - [`PriceArray`] - one slot per centi-cent price, O(1) updates and
+//!   contiguous scans, at a fixed ~80 KB per side
+
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::iter::{Enumerate, Map};
+use std::slice;
+
+use crate::types::{Price, Quantity, DOLLAR_SCALE};
+
+/// A price ladder: quantities keyed by price, iterable in ascending order
+///
+/// A quantity of 0 means the level is absent; setting a level to 0 removes
+/// it.
+pub trait OrderbookBackend: Clone + fmt::Debug + Default {
+    /// Iterator over occupied levels in ascending price order
+    type Iter<'a>: DoubleEndedIterator<Item = (Price, Quantity)>
+    where
+        Self: 'a;
+
+    /// Quantity at `price`, or 0 if the level is empty
+    fn get(&self, price: Price) -> Quantity;
+
+    /// Set the quantity at `price`, removing the level if `quantity` is 0
+    fn set(&mut self, price: Price, quantity: Quantity);
+
+    /// Lowest occupied level
+    fn lowest(&self) -> Option<(Price, Quantity)>;
+
+    /// Highest occupied level
+    fn highest(&self) -> Option<(Price, Quantity)>;
+
+    /// Occupied levels in ascending price order
+    fn iter(&self) -> Self::Iter<'_>;
+
+    /// Number of occupied levels
+    fn len(&self) -> usize;
+
+    /// Check if no levels are occupied
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove every level
+    fn clear(&mut self);
+}
+
+type LevelRef<'a> = (&'a Price, &'a Quantity);
+
+fn copy_level((&price, &quantity): LevelRef<'_>) -> (Price, Quantity) {
+    (price, quantity)
+}
+
+impl OrderbookBackend for BTreeMap<Price, Quantity> {
+    type Iter<'a> =
+        Map<btree_map::Iter<'a, Price, Quantity>, fn(LevelRef<'a>) -> (Price, Quantity)>;
+
+    fn get(&self, price: Price) -> Quantity {
+        BTreeMap::get(self, &price).copied().unwrap_or(0)
+    }
+
+    fn set(&mut self, price: Price, quantity: Quantity) {
+        if quantity == 0 {
+            self.remove(&price);
+        } else {
+            self.insert(price, quantity);
+        }
+    }
+
+    fn lowest(&self) -> Option<(Price, Quantity)> {
+        self.first_key_value().map(copy_level)
+    }
+
+    fn highest(&self) -> Option<(Price, Quantity)> {
+        self.last_key_value().map(copy_level)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        BTreeMap::iter(self).map(copy_level as fn(LevelRef<'_>) -> (Price, Quantity))
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn clear(&mut self) {
+        BTreeMap::clear(self);
+    }
+}
+
+/// Number of slots in a [`PriceArray`], one per price from $0 to $1
+const PRICE_SLOTS: usize = DOLLAR_SCALE as usize + 1;
+
+/// Price ladder backed by a fixed array with one slot per centi-cent
+///
+/// Updates index straight into the array, and the lowest and highest
+/// occupied slots are cached, so best-price lookups are O(1). Removing the
+/// best level scans to the next occupied slot, which is short in a live
+/// book. Prices outside `0..=10_000` cannot be stored and are ignored.
+#[derive(Clone)]
+pub struct PriceArray {
+    levels: Box<[Quantity]>,
+    len: usize,
+    /// Lowest occupied slot, meaningful only when `len > 0`
+    low: usize,
+    /// Highest occupied slot, meaningful only when `len > 0`
+    high: usize,
+}
+
+impl PriceArray {
+    /// Create an empty ladder
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            levels: vec![0; PRICE_SLOTS].into_boxed_slice(),
+            len: 0,
+            low: 0,
+            high: 0,
+        }
+    }
+
+    fn slot(price: Price) -> Option<usize> {
+        usize::try_from(price).ok().filter(|&i| i < PRICE_SLOTS)
+    }
+
+    fn level(&self, slot: usize) -> (Price, Quantity) {
+        (slot as Price, self.levels[slot])
+    }
+}
+
+impl Default for PriceArray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for PriceArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl OrderbookBackend for PriceArray {
+    type Iter<'a> = PriceArrayIter<'a>;
+
+    fn get(&self, price: Price) -> Quantity {
+        Self::slot(price).map_or(0, |i| self.levels[i])
+    }
+
+    fn set(&mut self, price: Price, quantity: Quantity) {
+        let Some(i) = Self::slot(price) else {
+            return;
+        };
+        let was_occupied = self.levels[i] != 0;
+        self.levels[i] = quantity;
+
+        match (was_occupied, quantity != 0) {
+            (false, true) => {
+                if self.len == 0 {
+                    self.low = i;
+                    self.high = i;
+                } else {
+                    self.low = self.low.min(i);
+                    self.high = self.high.max(i);
+                }
+                self.len += 1;
+            }
+            (true, false) => {
+                self.len -= 1;
+                if self.len == 0 {
+                    self.low = 0;
+                    self.high = 0;
+                } else if i == self.low {
+                    self.low += self.levels[i..=self.high]
+                        .iter()
+                        .position(|&q| q != 0)
+                        .unwrap_or(0);
+                } else if i == self.high {
+                    self.high = self.low
+                        + self.levels[self.low..=i]
+                            .iter()
+                            .rposition(|&q| q != 0)
+                            .unwrap_or(0);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn lowest(&self) -> Option<(Price, Quantity)> {
+        (self.len > 0).then(|| self.level(self.low))
+    }
+
+    fn highest(&self) -> Option<(Price, Quantity)> {
+        (self.len > 0).then(|| self.level(self.high))
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        let occupied = if self.len == 0 {
+            &self.levels[..0]
+        } else {
+            &self.levels[self.low..=self.high]
+        };
+        PriceArrayIter {
+            slots: occupied.iter().enumerate(),
+            offset: self.low,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn clear(&mut self) {
+        if self.len > 0 {
+            self.levels[self.low..=self.high].fill(0);
+        }
+        self.len = 0;
+        self.low = 0;
+        self.high = 0;
+    }
+}
+
+/// Iterator over the occupied levels of a [`PriceArray`]
+#[derive(Debug, Clone)]
+pub struct PriceArrayIter<'a> {
+    slots: Enumerate<slice::Iter<'a, Quantity>>,
+    offset: usize,
+}
+
+impl Iterator for PriceArrayIter<'_> {
+    type Item = (Price, Quantity);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        self.slots
+            .find(|(_, &q)| q != 0)
+            .map(|(i, &q)| ((offset + i) as Price, q))
+    }
+}
+
+impl DoubleEndedIterator for PriceArrayIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        self.slots
+            .rfind(|(_, &q)| q != 0)
+            .map(|(i, &q)| ((offset + i) as Price, q))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_array_matches_btree() {
+        let mut array = PriceArray::new();
+        let mut tree = BTreeMap::new();
+        assert_eq!(array.lowest(), None);
+        assert_eq!(array.iter().next(), None);
+
+        let updates = [
+            (5_000, 100),
+            (4_900, 50),
+            (5_100, 25),
+            (5_000, 0),
+            (5_100, 0),
+            (4_000, 10),
+            (4_900, 0),
+            (9_999, 5),
+            (1, 7),
+            (4_000, 0),
+        ];
+        for (price, quantity) in updates {
+            array.set(price, quantity);
+            OrderbookBackend::set(&mut tree, price, quantity);
+
+            assert_eq!(array.len(), OrderbookBackend::len(&tree));
+            assert_eq!(array.lowest(), tree.lowest());
+            assert_eq!(array.highest(), tree.highest());
+            assert!(array.iter().eq(OrderbookBackend::iter(&tree)));
+            assert!(array.iter().rev().eq(OrderbookBackend::iter(&tree).rev()));
+        }
+        assert_eq!(array.get(9_999), 5);
+        assert_eq!(array.get(5_000), 0);
+
+        // Out of range prices are ignored
+        array.set(DOLLAR_SCALE + 1, 10);
+        array.set(-1, 10);
+        assert_eq!(array.len(), 2);
+
+        array.clear();
+        assert!(array.is_empty());
+        assert_eq!(array.highest(), None);
+    }
+}
@@ -1,26 +1,39 @@
 //! Core orderbook data structure.
 //!
-//! This implementation uses `BTreeMap` for sorted price levels, providing:
+//! The default implementation uses `BTreeMap` for sorted price levels,
+//! providing:
 //!
 //! - O(log n) insertion, deletion, and lookup
 //! - O(1) access to best bid/ask (via `first_key_value` / `last_key_value`)
 //! - Ordered iteration for depth-of-book queries
+//!
+//! [`ArrayOrderbook`] swaps in a [`PriceArray`] per side for O(1) updates.
 
 use std::collections::BTreeMap;
 
-use crate::types::messages::{OrderbookDeltaData, OrderbookSnapshotData};
-use crate::types::order::Side;
-use crate::types::{parse_count, parse_dollars, Price, Quantity, DOLLAR_SCALE};
+use super::backend::{OrderbookBackend, PriceArray};
+
+use crate::error::Error;
+use crate::types::market::Orderbook as RestOrderbook;
+use crate::types::messages::{OrderbookDeltaData, OrderbookDeltaMsg, OrderbookSnapshotData};
+use crate::types::order::{Action, CreateOrderRequest, Side};
+use crate::types::{parse_count, parse_dollars, Price, Quantity, COUNT_SCALE, DOLLAR_SCALE};
+
+/// One tick: $0.01 in ten-thousandths of a dollar
+const TICK: Price = DOLLAR_SCALE / 100;
 
 /// HFT-optimized orderbook for a single Kalshi market.
 ///
 /// # Design Decisions
 ///
-/// 1. **Integer prices**: Prices are stored as `u8` (1-99 cents), avoiding
-///    floating-point arithmetic and enabling exact comparisons.
+/// 1. **Integer prices**: Prices are stored as `i64` ten-thousandths of a
+///    dollar (centi-cents), avoiding floating-point arithmetic and enabling
+///    exact comparisons.
 ///
 /// 2. **BTreeMap**: Provides sorted price levels with O(log n) operations.
 ///    Best bid/ask are O(1) via `last_key_value()` / `first_key_value()`.
+///    The storage is an [`OrderbookBackend`] type parameter, so
+///    [`ArrayOrderbook`] can use a fixed price-indexed array instead.
 ///
 /// 3. **Sequence tracking**: The `sequence` field tracks WebSocket message
 ///    order to detect gaps and trigger re-synchronization.
@@ -33,31 +46,111 @@ use crate::types::{parse_count, parse_dollars, Price, Quantity, DOLLAR_SCALE};
 /// This struct is `Send + Sync` but not internally synchronized. For
 /// concurrent access, wrap in `parking_lot::RwLock` or `Arc<Mutex>`.
 #[derive(Debug, Clone)]
-pub struct Orderbook {
+pub struct Orderbook<L = BTreeMap<Price, Quantity>> {
     /// Market ticker
     market_ticker: String,
 
     /// Yes side bid levels: price -> quantity
     /// Sorted ascending by price (best bid = highest = last)
-    yes_bids: BTreeMap<Price, Quantity>,
+    yes_bids: L,
 
     /// Yes side ask levels: price -> quantity
     /// Sorted ascending by price (best ask = lowest = first)
-    yes_asks: BTreeMap<Price, Quantity>,
+    yes_asks: L,
 
     /// Last sequence number received (for gap detection)
     sequence: u64,
+
+    /// Optional smoothed mid price, `None` unless enabled
+    mid_ema: Option<MidEma>,
+}
+
+/// Orderbook storing each side in a [`PriceArray`]
+///
+/// Same API as [`Orderbook`], with O(1) level updates and best-price
+/// lookups at the cost of a fixed ~160 KB per book. Create one with
+/// [`Orderbook::with_backend`].
+///
+/// ```rust
+/// use kalshi_trading::orderbook::ArrayOrderbook;
+/// use kalshi_trading::types::order::Side;
+///
+/// let mut book = ArrayOrderbook::with_backend("KXBTC-25JAN");
+/// book.set_level(5_000, 100, Side::Yes);
+/// assert_eq!(book.best_bid(), Some((5_000, 100)));
+/// ```
+pub type ArrayOrderbook = Orderbook<PriceArray>;
+
+/// Exponentially-weighted moving average of the mid price
+#[derive(Debug, Clone, Copy)]
+struct MidEma {
+    alpha: f64,
+    /// Mid at the last update, used to skip updates that don't move it
+    last_mid: Option<f64>,
+    value: Option<f64>,
 }
 
 impl Orderbook {
     /// Create a new empty orderbook for the given market
     #[must_use]
     pub fn new(market_ticker: impl Into<String>) -> Self {
+        Self::with_backend(market_ticker)
+    }
+}
+
+impl<L: OrderbookBackend> Orderbook<L> {
+    /// Create a new empty orderbook storing its levels in backend `L`
+    #[must_use]
+    pub fn with_backend(market_ticker: impl Into<String>) -> Self {
         Self {
             market_ticker: market_ticker.into(),
-            yes_bids: BTreeMap::new(),
-            yes_asks: BTreeMap::new(),
+            yes_bids: L::default(),
+            yes_asks: L::default(),
             sequence: 0,
+            mid_ema: None,
+        }
+    }
+
+    /// Track an exponential moving average of the mid price
+    ///
+    /// The average is updated whenever the mid changes, weighting the new mid
+    /// by `alpha` (clamped to `0.0..=1.0`). Read it with
+    /// [`smoothed_mid`](Self::smoothed_mid). Books without this pay only a
+    /// single branch per update.
+    #[must_use]
+    pub fn with_mid_ema(mut self, alpha: f64) -> Self {
+        self.mid_ema = Some(MidEma {
+            alpha: alpha.clamp(0.0, 1.0),
+            last_mid: None,
+            value: None,
+        });
+        self.update_mid_ema();
+        self
+    }
+
+    /// Get the smoothed mid price
+    ///
+    /// Returns `None` if smoothing is disabled or no mid has been seen yet.
+    #[must_use]
+    pub fn smoothed_mid(&self) -> Option<f64> {
+        self.mid_ema.and_then(|ema| ema.value)
+    }
+
+    /// Fold the current mid into the EMA if it changed
+    fn update_mid_ema(&mut self) {
+        let Some(mut ema) = self.mid_ema else {
+            return;
+        };
+        let Some(mid) = self.mid_price() else {
+            return;
+        };
+        if ema.last_mid != Some(mid) {
+            ema.value = Some(match ema.value {
+                Some(prev) => ema.alpha * mid + (1.0 - ema.alpha) * prev,
+                None => mid,
+            });
+            ema.last_mid = Some(mid);
+            self.mid_ema = Some(ema);
         }
     }
 
@@ -84,24 +177,65 @@ impl Orderbook {
         for level in &snapshot.yes_dollars_fp {
             if let (Ok(price), Ok(quantity)) = (parse_dollars(&level[0]), parse_count(&level[1])) {
                 if quantity > 0 {
-                    self.yes_bids.insert(price, quantity);
+                    self.yes_bids.set(price, quantity);
                 }
             }
         }
 
         // No side in snapshot - convert to yes asks
-        // No bid at price P = Yes ask at price (100 - P)
+        // No bid at price P = Yes ask at price (DOLLAR_SCALE - P)
         for level in &snapshot.no_dollars_fp {
             if let (Ok(no_price), Ok(quantity)) = (parse_dollars(&level[0]), parse_count(&level[1]))
             {
                 if quantity > 0 {
                     let yes_price = DOLLAR_SCALE - no_price;
-                    self.yes_asks.insert(yes_price, quantity);
+                    self.yes_asks.set(yes_price, quantity);
                 }
             }
         }
 
         self.sequence = sequence;
+        self.update_mid_ema();
+    }
+
+    /// Replace the book with a REST orderbook snapshot
+    ///
+    /// Use this to seed the live book from
+    /// [`get_orderbook`](crate::KalshiClient::get_orderbook) before applying
+    /// WebSocket deltas. REST snapshots carry no WebSocket sequence, so the
+    /// sequence is reset and the next delta is accepted as the start of the
+    /// stream. Repeated prices are summed and empty levels are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi_trading::orderbook::Orderbook;
+    /// use kalshi_trading::types::market::Orderbook as RestOrderbook;
+    ///
+    /// let rest: RestOrderbook = serde_json::from_str(
+    ///     r#"{"yes_dollars":[["0.4500","10.00"]],"no_dollars":[["0.5000","5.00"]]}"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut book = Orderbook::new("KXBTC-25JAN");
+    /// book.seed_from_rest(&rest);
+    /// assert_eq!(book.best_bid(), Some((4_500, 1_000)));
+    /// assert_eq!(book.best_ask(), Some((5_000, 500)));
+    /// ```
+    pub fn seed_from_rest(&mut self, snapshot: &RestOrderbook) {
+        self.yes_bids.clear();
+        self.yes_asks.clear();
+
+        for &[price, quantity] in &snapshot.yes_dollars {
+            change_level(&mut self.yes_bids, price, quantity.max(0));
+        }
+        // No bid at price P = Yes ask at price (DOLLAR_SCALE - P)
+        for &[no_price, quantity] in &snapshot.no_dollars {
+            change_level(&mut self.yes_asks, DOLLAR_SCALE - no_price, quantity.max(0));
+        }
+
+        self.sequence = 0;
+        self.update_mid_ema();
     }
 
     /// Apply a delta update from WebSocket
@@ -115,7 +249,47 @@ impl Orderbook {
         }
 
         self.sequence = sequence;
+        self.apply_delta_levels(delta);
+        self.update_mid_ema();
 
+        true
+    }
+
+    /// Apply a contiguous batch of deltas atomically
+    ///
+    /// The whole batch is validated before anything is applied: the first
+    /// delta must follow the book's current sequence and each later delta
+    /// must follow the one before it. On a gap the book is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SequenceGap`] for the first out-of-order delta.
+    pub fn apply_deltas(&mut self, deltas: &[OrderbookDeltaMsg]) -> Result<(), Error> {
+        let mut expected = self.sequence + 1;
+        for (i, delta) in deltas.iter().enumerate() {
+            // A fresh book (sequence 0) accepts any starting sequence
+            let starts_fresh = i == 0 && self.sequence == 0;
+            if delta.seq != expected && !starts_fresh {
+                return Err(Error::SequenceGap {
+                    expected,
+                    got: delta.seq,
+                });
+            }
+            expected = delta.seq + 1;
+        }
+
+        for delta in deltas {
+            self.apply_delta_levels(&delta.msg);
+        }
+        if let Some(last) = deltas.last() {
+            self.sequence = last.seq;
+        }
+        self.update_mid_ema();
+        Ok(())
+    }
+
+    /// Apply a delta's quantity change without sequence checks
+    fn apply_delta_levels(&mut self, delta: &OrderbookDeltaData) {
         // Determine which side of the book to update
         let (book, price) = match delta.side {
             Side::Yes => (&mut self.yes_bids, delta.price_dollars),
@@ -126,26 +300,7 @@ impl Orderbook {
             }
         };
 
-        // Apply the delta
-        if delta.delta_fp == 0 {
-            // No change
-        } else if delta.delta_fp < 0 {
-            // Quantity decreased
-            let decrease = (-delta.delta_fp) as Quantity;
-            if let Some(current) = book.get_mut(&price) {
-                if *current <= decrease {
-                    book.remove(&price);
-                } else {
-                    *current -= decrease;
-                }
-            }
-        } else {
-            // Quantity increased
-            let increase = delta.delta_fp as Quantity;
-            *book.entry(price).or_insert(0) += increase;
-        }
-
-        true
+        change_level(book, price, delta.delta_fp);
     }
 
     /// Apply a delta directly (for manual updates)
@@ -156,28 +311,16 @@ impl Orderbook {
     /// * `delta` - Change in quantity (positive = add, negative = remove)
     /// * `side` - Which side of the book
     pub fn apply_delta(&mut self, price: Price, delta: i64, side: Side) {
-        let book = match side {
-            Side::Yes => &mut self.yes_bids,
-            Side::No => &mut self.yes_asks,
-        };
-
         if delta == 0 {
             return;
         }
 
-        if delta < 0 {
-            let decrease = (-delta) as Quantity;
-            if let Some(current) = book.get_mut(&price) {
-                if *current <= decrease {
-                    book.remove(&price);
-                } else {
-                    *current -= decrease;
-                }
-            }
-        } else {
-            let increase = delta as Quantity;
-            *book.entry(price).or_insert(0) += increase;
-        }
+        let book = match side {
+            Side::Yes => &mut self.yes_bids,
+            Side::No => &mut self.yes_asks,
+        };
+        change_level(book, price, delta);
+        self.update_mid_ema();
     }
 
     /// Set a price level directly
@@ -189,11 +332,8 @@ impl Orderbook {
             Side::No => &mut self.yes_asks,
         };
 
-        if quantity == 0 {
-            book.remove(&price);
-        } else {
-            book.insert(price, quantity);
-        }
+        book.set(price, quantity);
+        self.update_mid_ema();
     }
 
     /// Get the best bid (highest yes bid)
@@ -201,7 +341,7 @@ impl Orderbook {
     /// Returns `(price, quantity)` or `None` if no bids.
     #[must_use]
     pub fn best_bid(&self) -> Option<(Price, Quantity)> {
-        self.yes_bids.last_key_value().map(|(&p, &q)| (p, q))
+        self.yes_bids.highest()
     }
 
     /// Get the best ask (lowest yes ask)
@@ -209,7 +349,112 @@ impl Orderbook {
     /// Returns `(price, quantity)` or `None` if no asks.
     #[must_use]
     pub fn best_ask(&self) -> Option<(Price, Quantity)> {
-        self.yes_asks.first_key_value().map(|(&p, &q)| (p, q))
+        self.yes_asks.lowest()
+    }
+
+    /// Get the best No bid
+    ///
+    /// A resting No bid at `P` is a Yes ask at `$1 - P`, so this mirrors
+    /// [`best_ask`](Self::best_ask). Returns `(no_price, quantity)`.
+    #[must_use]
+    pub fn best_no_bid(&self) -> Option<(Price, Quantity)> {
+        self.best_ask().map(|(p, q)| (DOLLAR_SCALE - p, q))
+    }
+
+    /// Get the best No ask
+    ///
+    /// Buying No at `P` means selling Yes at `$1 - P`, so this mirrors
+    /// [`best_bid`](Self::best_bid). Returns `(no_price, quantity)`.
+    #[must_use]
+    pub fn best_no_ask(&self) -> Option<(Price, Quantity)> {
+        self.best_bid().map(|(p, q)| (DOLLAR_SCALE - p, q))
+    }
+
+    /// Get the best bid and ask on both the Yes and No sides at once
+    ///
+    /// The No quotes are the Yes quotes inverted, as in
+    /// [`best_no_bid`](Self::best_no_bid) and [`best_no_ask`](Self::best_no_ask).
+    #[must_use]
+    pub fn top_of_book(&self) -> TopOfBook {
+        let yes_bid = self.best_bid();
+        let yes_ask = self.best_ask();
+        TopOfBook {
+            yes_bid,
+            yes_ask,
+            no_bid: yes_ask.map(|(p, q)| (DOLLAR_SCALE - p, q)),
+            no_ask: yes_bid.map(|(p, q)| (DOLLAR_SCALE - p, q)),
+        }
+    }
+
+    /// Check if `order` would match resting liquidity in this book
+    ///
+    /// The order's limit is converted to a Yes price: buying Yes (or
+    /// selling No) crosses at or above the best ask, and selling Yes (or
+    /// buying No) crosses at or below the best bid. Orders without a price
+    /// always take liquidity. A post-only order for which this is `true`
+    /// would be rejected by the exchange.
+    #[must_use]
+    pub fn would_cross(&self, order: &CreateOrderRequest) -> bool {
+        let yes_price = order
+            .yes_price_dollars
+            .or(order.yes_price.map(|p| p * 100))
+            .or(order.no_price_dollars.map(|p| DOLLAR_SCALE - p))
+            .or(order.no_price.map(|p| DOLLAR_SCALE - p * 100));
+        let Some(yes_price) = yes_price else {
+            return true;
+        };
+
+        let buys_yes = matches!(
+            (order.side, order.action),
+            (Side::Yes, Action::Buy) | (Side::No, Action::Sell)
+        );
+        if buys_yes {
+            self.best_ask().is_some_and(|(ask, _)| yes_price >= ask)
+        } else {
+            self.best_bid().is_some_and(|(bid, _)| yes_price <= bid)
+        }
+    }
+
+    /// Check if `price` is the best price on a side
+    ///
+    /// `Side::Yes` checks the best bid and `Side::No` the best ask, matching
+    /// [`set_level`](Self::set_level). Returns `false` if that side is empty.
+    #[must_use]
+    pub fn is_best(&self, price: Price, side: Side) -> bool {
+        let best = match side {
+            Side::Yes => self.best_bid(),
+            Side::No => self.best_ask(),
+        };
+        best.is_some_and(|(best, _)| best == price)
+    }
+
+    /// Get how many ticks ($0.01) `price` is behind the best price on a side
+    ///
+    /// Positive means behind the best (a lower bid or higher ask), zero means
+    /// at the best, and negative means the price would improve the book.
+    /// Partial ticks round toward zero. Returns `None` if that side is empty.
+    #[must_use]
+    pub fn distance_from_best(&self, price: Price, side: Side) -> Option<i64> {
+        match side {
+            Side::Yes => self.best_bid().map(|(best, _)| (best - price) / TICK),
+            Side::No => self.best_ask().map(|(best, _)| (price - best) / TICK),
+        }
+    }
+
+    /// Estimate the contracts ahead of a new order at `price`
+    ///
+    /// Returns the total resting quantity at that level (`Side::Yes` for the
+    /// bid ladder, `Side::No` for the ask ladder), or 0 if the level is empty.
+    /// This is an upper bound: the book does not show which resting orders
+    /// will cancel before yours fills. Use the queue position endpoint for an
+    /// exact figure.
+    #[must_use]
+    pub fn estimated_queue_ahead(&self, price: Price, side: Side) -> Quantity {
+        let book = match side {
+            Side::Yes => &self.yes_bids,
+            Side::No => &self.yes_asks,
+        };
+        book.get(price)
     }
 
     /// Get the mid price
@@ -223,7 +468,46 @@ impl Orderbook {
         }
     }
 
-    /// Get the spread in cents
+    /// Get the mid price in centi-cents (ten-thousandths of a dollar)
+    ///
+    /// Integer counterpart of [`mid_price`](Self::mid_price), rounding half
+    /// up. Returns `None` if either side is empty.
+    #[must_use]
+    pub fn mid_price_centicents(&self) -> Option<Price> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some((bid, _)), Some((ask, _))) => Some((bid + ask + 1).div_euclid(2)),
+            _ => None,
+        }
+    }
+
+    /// Get the liquidity-weighted fair value as a probability from 0.0 to 1.0
+    ///
+    /// This is the microprice of the best Yes bid and best No bid, with the
+    /// No bid inverted to a Yes ask at `$1 - P`:
+    ///
+    /// ```text
+    /// fair = (yes_bid * no_qty + (1 - no_bid) * yes_qty) / (yes_qty + no_qty)
+    /// ```
+    ///
+    /// Each price is weighted by the size on the opposite side, so the value
+    /// leans toward the side more likely to trade through next. A heavy bid
+    /// pulls it toward the ask. Returns `None` if either side is empty.
+    #[must_use]
+    pub fn fair_value(&self) -> Option<f64> {
+        let (yes_bid, yes_qty) = self.best_bid()?;
+        let (no_bid, no_qty) = self.best_no_bid()?;
+        let ask = (DOLLAR_SCALE - no_bid) as f64;
+        let total = (yes_qty + no_qty) as f64;
+        if total <= 0.0 {
+            return None;
+        }
+        let micro = (yes_bid as f64 * no_qty as f64 + ask * yes_qty as f64) / total;
+        Some(micro / DOLLAR_SCALE as f64)
+    }
+
+    /// Get the spread in centi-cents
+    ///
+    /// The same for both sides, since the No book mirrors the Yes book.
     #[must_use]
     pub fn spread(&self) -> Option<Price> {
         match (self.best_bid(), self.best_ask()) {
@@ -245,12 +529,38 @@ impl Orderbook {
 
     /// Get all bid levels, sorted by price descending (best first)
     pub fn bids(&self) -> impl Iterator<Item = (Price, Quantity)> + '_ {
-        self.yes_bids.iter().rev().map(|(&p, &q)| (p, q))
+        self.yes_bids.iter().rev()
     }
 
     /// Get all ask levels, sorted by price ascending (best first)
     pub fn asks(&self) -> impl Iterator<Item = (Price, Quantity)> + '_ {
-        self.yes_asks.iter().map(|(&p, &q)| (p, q))
+        self.yes_asks.iter()
+    }
+
+    /// Get all No bid levels in No prices, sorted descending (best first)
+    pub fn no_bids(&self) -> impl Iterator<Item = (Price, Quantity)> + '_ {
+        self.asks().map(|(p, q)| (DOLLAR_SCALE - p, q))
+    }
+
+    /// Get all No ask levels in No prices, sorted ascending (best first)
+    pub fn no_asks(&self) -> impl Iterator<Item = (Price, Quantity)> + '_ {
+        self.bids().map(|(p, q)| (DOLLAR_SCALE - p, q))
+    }
+
+    /// Get bid levels with the running total quantity, best first
+    ///
+    /// Each item is a bid price and the quantity resting at that price or
+    /// better, which is the shape a depth chart plots.
+    pub fn cumulative_bids(&self) -> impl Iterator<Item = (Price, Quantity)> + '_ {
+        cumulative(self.bids())
+    }
+
+    /// Get ask levels with the running total quantity, best first
+    ///
+    /// Each item is an ask price and the quantity resting at that price or
+    /// better.
+    pub fn cumulative_asks(&self) -> impl Iterator<Item = (Price, Quantity)> + '_ {
+        cumulative(self.asks())
     }
 
     /// Get the top N bid levels
@@ -265,16 +575,243 @@ impl Orderbook {
         self.asks().take(n).collect()
     }
 
+    /// Get the cost of buying `quantity` Yes contracts by sweeping the asks
+    ///
+    /// Walks the ask ladder from the best price and returns
+    /// `(filled_quantity, total_cost)`, with the cost in centi-cents rounded
+    /// to the nearest centi-cent. If the asks hold less than `quantity`, the
+    /// result covers only what is available. Returns `None` if there are no
+    /// asks or `quantity` is not positive.
+    #[must_use]
+    pub fn buy_cost(&self, quantity: Quantity) -> Option<(Quantity, Price)> {
+        sweep(self.asks(), quantity)
+    }
+
+    /// Get the proceeds of selling `quantity` Yes contracts into the bids
+    ///
+    /// The bid-side counterpart of [`buy_cost`](Self::buy_cost), returning
+    /// `(filled_quantity, total_proceeds)`.
+    #[must_use]
+    pub fn sell_proceeds(&self, quantity: Quantity) -> Option<(Quantity, Price)> {
+        sweep(self.bids(), quantity)
+    }
+
+    /// Get the volume-weighted average price of sweeping `quantity` contracts
+    ///
+    /// `Side::Yes` walks the bid ladder (selling) and `Side::No` the ask
+    /// ladder (buying), matching [`set_level`](Self::set_level). The price is
+    /// in centi-cents and covers only the fillable quantity when the book is
+    /// too thin. Returns `None` if that side is empty or `quantity` is not
+    /// positive.
+    #[must_use]
+    pub fn vwap(&self, side: Side, quantity: Quantity) -> Option<f64> {
+        let (filled, total) = match side {
+            Side::Yes => self.sell_proceeds(quantity)?,
+            Side::No => self.buy_cost(quantity)?,
+        };
+        Some(total as f64 * COUNT_SCALE as f64 / filled as f64)
+    }
+
+    /// Get the volume-weighted average price of the top `max_levels` levels
+    ///
+    /// Unlike [`vwap`](Self::vwap), this weights whole levels rather than
+    /// sweeping a target quantity, giving a smoothed reference price for a
+    /// side. `Side::Yes` uses the bids and `Side::No` the asks, matching
+    /// [`set_level`](Self::set_level). The price is in centi-cents. Returns
+    /// `None` if that side is empty or `max_levels` is 0.
+    #[must_use]
+    pub fn vwap_depth(&self, side: Side, max_levels: usize) -> Option<f64> {
+        let (weighted, quantity) = match side {
+            Side::Yes => weigh_levels(self.bids().take(max_levels)),
+            Side::No => weigh_levels(self.asks().take(max_levels)),
+        };
+        (quantity > 0).then(|| weighted as f64 / quantity as f64)
+    }
+
+    /// Get the lowest and highest prices with resting quantity
+    ///
+    /// Covers both sides in Yes terms, so No bids count at their Yes ask
+    /// price. Returns `(low, high)`, or `None` if the book is empty.
+    #[must_use]
+    pub fn price_range(&self) -> Option<(Price, Price)> {
+        let low = match (self.yes_bids.lowest(), self.yes_asks.lowest()) {
+            (Some((bid, _)), Some((ask, _))) => bid.min(ask),
+            (Some((price, _)), None) | (None, Some((price, _))) => price,
+            (None, None) => return None,
+        };
+        let high = match (self.yes_bids.highest(), self.yes_asks.highest()) {
+            (Some((bid, _)), Some((ask, _))) => bid.max(ask),
+            (Some((price, _)), None) | (None, Some((price, _))) => price,
+            (None, None) => return None,
+        };
+        Some((low, high))
+    }
+
+    /// Get the cost to take all the liquidity at the best ask
+    ///
+    /// This is the immediate Yes liquidity at the touch: the best ask
+    /// price times its quantity, in centi-cents rounded half up like
+    /// [`bid_value`](Self::bid_value). Returns `None` if there are no asks.
+    #[must_use]
+    pub fn cost_to_cross(&self) -> Option<i64> {
+        self.best_ask()
+            .map(|level| notional(std::iter::once(level)))
+    }
+
+    /// Get the quantity that must trade to move the best price by `ticks`
+    ///
+    /// `Side::Yes` pushes the best bid down and `Side::No` pushes the best
+    /// ask up, matching [`set_level`](Self::set_level). Sums every level
+    /// less than `ticks * tick_size` from the best price, so levels past a
+    /// gap in the ladder are not counted. Returns 0 if the side is empty or
+    /// `ticks` or `tick_size` is not positive.
+    #[must_use]
+    pub fn quantity_to_move(&self, side: Side, ticks: i64, tick_size: Price) -> Quantity {
+        if ticks <= 0 || tick_size <= 0 {
+            return 0;
+        }
+        let distance = ticks.saturating_mul(tick_size);
+        match side {
+            Side::Yes => self.best_bid().map_or(0, |(best, _)| {
+                let floor = best.saturating_sub(distance);
+                self.bids()
+                    .take_while(|&(p, _)| p > floor)
+                    .map(|(_, q)| q)
+                    .sum()
+            }),
+            Side::No => self.best_ask().map_or(0, |(best, _)| {
+                let ceiling = best.saturating_add(distance);
+                self.asks()
+                    .take_while(|&(p, _)| p < ceiling)
+                    .map(|(_, q)| q)
+                    .sum()
+            }),
+        }
+    }
+
+    /// Get the ratio of depth behind the top of book to the top itself
+    ///
+    /// Sums the quantity in levels 2 through `levels` on both sides and
+    /// divides by the combined best bid and best ask quantity. A low ratio
+    /// means a thin book that a sweep will move easily. Returns `None` if
+    /// the book is empty.
+    #[must_use]
+    pub fn depth_ratio(&self, levels: usize) -> Option<f64> {
+        let (bids, asks) = (self.top_bids(levels), self.top_asks(levels));
+        let top: Quantity = bids
+            .first()
+            .into_iter()
+            .chain(asks.first())
+            .map(|&(_, q)| q)
+            .sum();
+        if top == 0 {
+            return None;
+        }
+        let depth: Quantity = bids
+            .iter()
+            .skip(1)
+            .chain(asks.iter().skip(1))
+            .map(|&(_, q)| q)
+            .sum();
+        Some(depth as f64 / top as f64)
+    }
+
+    /// Get the order imbalance over the top `depth` levels of each side
+    ///
+    /// `(bid_qty - ask_qty) / (bid_qty + ask_qty)`, from -1.0 (all asks) to
+    /// 1.0 (all bids). A `depth` of 1 gives the top-of-book imbalance.
+    /// Returns `None` if both sides are empty.
+    #[must_use]
+    pub fn imbalance(&self, depth: usize) -> Option<f64> {
+        let (bids, asks) = self.depth_quantities(depth);
+        let total = bids + asks;
+        (total > 0).then(|| (bids - asks) as f64 / total as f64)
+    }
+
+    /// Total bid and ask quantity in the top `depth` levels of each side
+    pub(crate) fn depth_quantities(&self, depth: usize) -> (Quantity, Quantity) {
+        (
+            self.bids().take(depth).map(|(_, q)| q).sum(),
+            self.asks().take(depth).map(|(_, q)| q).sum(),
+        )
+    }
+
+    /// Get the bid and ask quantity within `ticks` ($0.01) of the mid
+    ///
+    /// Levels exactly `ticks` away count. Returns `(0, 0)` unless both sides
+    /// are populated, since there is no mid to measure from.
+    #[must_use]
+    pub fn depth_within(&self, ticks: Price) -> (Quantity, Quantity) {
+        let Some((bids, asks)) = self.levels_within(ticks) else {
+            return (0, 0);
+        };
+        (bids.map(|(_, q)| q).sum(), asks.map(|(_, q)| q).sum())
+    }
+
+    /// Get the number of bid and ask levels within `ticks` ($0.01) of the mid
+    ///
+    /// Counts the levels [`depth_within`](Self::depth_within) sums over.
+    #[must_use]
+    pub fn level_count_within(&self, ticks: Price) -> (usize, usize) {
+        self.levels_within(ticks)
+            .map_or((0, 0), |(bids, asks)| (bids.count(), asks.count()))
+    }
+
+    /// Bid and ask levels within `ticks` of the mid, best first
+    fn levels_within(
+        &self,
+        ticks: Price,
+    ) -> Option<(
+        impl Iterator<Item = (Price, Quantity)> + '_,
+        impl Iterator<Item = (Price, Quantity)> + '_,
+    )> {
+        let mid = self.mid_price_centicents()?;
+        let distance = ticks.max(0).saturating_mul(TICK);
+        Some((
+            self.bids().take_while(move |&(p, _)| mid - p <= distance),
+            self.asks().take_while(move |&(p, _)| p - mid <= distance),
+        ))
+    }
+
+    /// Get the notional resting on the bid side
+    ///
+    /// Sums `price * quantity` over the Yes bids, in centi-cents per
+    /// contract times whole contracts, rounded half up.
+    #[must_use]
+    pub fn bid_value(&self) -> i64 {
+        notional(self.bids())
+    }
+
+    /// Get the notional resting on the ask side
+    ///
+    /// Asks are resting No bids, so each level is valued at its No price,
+    /// the amount its owners have committed. Same units as
+    /// [`bid_value`](Self::bid_value).
+    #[must_use]
+    pub fn ask_value(&self) -> i64 {
+        notional(self.no_bids())
+    }
+
+    /// Get the total notional resting on both sides of the book
+    ///
+    /// The sum of [`bid_value`](Self::bid_value) and
+    /// [`ask_value`](Self::ask_value), a liquidity measure that weights
+    /// each contract by the capital behind it.
+    #[must_use]
+    pub fn total_value_locked(&self) -> i64 {
+        self.bid_value() + self.ask_value()
+    }
+
     /// Get total bid quantity
     #[must_use]
     pub fn total_bid_quantity(&self) -> Quantity {
-        self.yes_bids.values().sum()
+        self.bids().map(|(_, q)| q).sum()
     }
 
     /// Get total ask quantity
     #[must_use]
     pub fn total_ask_quantity(&self) -> Quantity {
-        self.yes_asks.values().sum()
+        self.asks().map(|(_, q)| q).sum()
     }
 
     /// Clear the orderbook
@@ -282,6 +819,10 @@ impl Orderbook {
         self.yes_bids.clear();
         self.yes_asks.clear();
         self.sequence = 0;
+        if let Some(ema) = &mut self.mid_ema {
+            ema.last_mid = None;
+            ema.value = None;
+        }
     }
 
     /// Check if the orderbook is empty
@@ -303,6 +844,144 @@ impl Default for Orderbook {
     }
 }
 
+/// Sum of `price * quantity` and of quantity over `levels`
+fn weigh_levels(levels: impl Iterator<Item = (Price, Quantity)>) -> (i128, i128) {
+    levels.fold((0, 0), |(weighted, total), (price, quantity)| {
+        (
+            weighted + i128::from(price) * i128::from(quantity),
+            total + i128::from(quantity),
+        )
+    })
+}
+
+/// Add `delta` to the level at `price`, removing it if it drops to zero
+///
+/// A decrease at an empty level is ignored.
+fn change_level<L: OrderbookBackend>(book: &mut L, price: Price, delta: Quantity) {
+    let current = book.get(price);
+    if delta < 0 {
+        if current != 0 {
+            let decrease = -delta;
+            book.set(
+                price,
+                if current <= decrease {
+                    0
+                } else {
+                    current - decrease
+                },
+            );
+        }
+    } else if delta > 0 {
+        book.set(price, current + delta);
+    }
+}
+
+/// Best quotes on both sides of a market, from [`Orderbook::top_of_book`]
+///
+/// Each quote is `(price, quantity)`, with No prices in No terms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TopOfBook {
+    /// Best Yes bid
+    pub yes_bid: Option<(Price, Quantity)>,
+    /// Best Yes ask
+    pub yes_ask: Option<(Price, Quantity)>,
+    /// Best No bid
+    pub no_bid: Option<(Price, Quantity)>,
+    /// Best No ask
+    pub no_ask: Option<(Price, Quantity)>,
+}
+
+/// A sequence gap found by [`validate_delta_stream`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequenceGapReport {
+    /// Position of the offending delta in the stream
+    pub index: usize,
+    /// Sequence number that should have come next
+    pub expected: u64,
+    /// Sequence number actually received
+    pub got: u64,
+}
+
+/// Replay a recorded snapshot and delta stream, reporting every sequence gap
+///
+/// Deltas are applied in order to a scratch book built from `snapshot`.
+/// Unlike [`Orderbook::apply_deltas`], replay continues past a gap and
+/// expects the next delta to follow the one that caused it, so a single
+/// pass finds every gap in the capture. The snapshot carries no sequence
+/// number, so the first delta sets the starting point.
+#[must_use]
+pub fn validate_delta_stream(
+    snapshot: &OrderbookSnapshotData,
+    deltas: &[OrderbookDeltaMsg],
+) -> Vec<SequenceGapReport> {
+    let mut book = Orderbook::new(snapshot.market_ticker.as_str());
+    book.apply_snapshot(snapshot, 0);
+
+    let mut gaps = Vec::new();
+    for (index, delta) in deltas.iter().enumerate() {
+        let expected = book.sequence + 1;
+        if index > 0 && delta.seq != expected {
+            gaps.push(SequenceGapReport {
+                index,
+                expected,
+                got: delta.seq,
+            });
+        }
+        book.apply_delta_levels(&delta.msg);
+        book.sequence = delta.seq;
+    }
+    gaps
+}
+
+/// Replace each level's quantity with the total up to and including it
+fn cumulative(
+    levels: impl Iterator<Item = (Price, Quantity)>,
+) -> impl Iterator<Item = (Price, Quantity)> {
+    levels.scan(0, |total, (price, quantity)| {
+        *total += quantity;
+        Some((price, *total))
+    })
+}
+
+/// Sum `price * quantity` over `levels`, in centi-cent contracts rounded half up
+fn notional(levels: impl Iterator<Item = (Price, Quantity)>) -> i64 {
+    let total: i128 = levels
+        .map(|(price, quantity)| i128::from(price) * i128::from(quantity))
+        .sum();
+    let scale = i128::from(COUNT_SCALE);
+    ((total + scale / 2) / scale) as i64
+}
+
+/// Fill up to `quantity` from `levels` in order, returning `(filled, notional)`
+///
+/// The notional is in centi-cents, rounded half up.
+fn sweep(
+    levels: impl Iterator<Item = (Price, Quantity)>,
+    quantity: Quantity,
+) -> Option<(Quantity, Price)> {
+    if quantity <= 0 {
+        return None;
+    }
+
+    let mut filled = 0;
+    // Price times scaled quantity, divided by COUNT_SCALE once at the end
+    let mut notional: i128 = 0;
+    for (price, available) in levels {
+        let take = available.min(quantity - filled);
+        filled += take;
+        notional += i128::from(price) * i128::from(take);
+        if filled == quantity {
+            break;
+        }
+    }
+
+    if filled == 0 {
+        return None;
+    }
+    let scale = i128::from(COUNT_SCALE);
+    Some((filled, ((notional + scale / 2) / scale) as Price))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -359,6 +1038,120 @@ mod tests {
         assert_eq!(book.spread(), Some(1_000));
     }
 
+    #[test]
+    fn test_no_side_view() {
+        let mut book = Orderbook::new("TEST");
+        let snapshot: OrderbookSnapshotData = serde_json::from_str(
+            r#"{"market_ticker":"TEST","market_id":"id","yes_dollars_fp":[],
+                "no_dollars_fp":[["0.4000","5.00"],["0.4250","2.00"]]}"#,
+        )
+        .unwrap();
+        book.apply_snapshot(&snapshot, 1);
+
+        // Only No bids: visible directly, and as Yes asks
+        assert_eq!(book.best_no_bid(), Some((4_250, 200)));
+        assert_eq!(book.best_ask(), Some((5_750, 200)));
+        assert_eq!(book.best_no_ask(), None);
+        let no_bids: Vec<_> = book.no_bids().collect();
+        assert_eq!(no_bids, [(4_250, 200), (4_000, 500)]);
+        assert_eq!(book.spread(), None);
+        assert!(!book.is_crossed());
+
+        // A Yes bid is a No ask on the other side
+        book.set_level(5_500, 100, Side::Yes);
+        assert_eq!(book.best_no_ask(), Some((4_500, 100)));
+        assert_eq!(book.no_asks().collect::<Vec<_>>(), [(4_500, 100)]);
+        assert_eq!(book.spread(), Some(250));
+        assert!(!book.is_crossed());
+
+        // No bid above the No ask crosses the book
+        book.set_level(DOLLAR_SCALE - 4_600, 10, Side::No);
+        assert_eq!(book.best_no_bid(), Some((4_600, 10)));
+        assert!(book.is_crossed());
+    }
+
+    #[test]
+    fn test_would_cross() {
+        let mut book = Orderbook::new("TEST");
+        book.set_level(4_500, 100, Side::Yes);
+        book.set_level(5_000, 100, Side::No);
+
+        let bid =
+            |price| CreateOrderRequest::limit("TEST", Side::Yes, Action::Buy, 1, price).post_only();
+        assert!(!book.would_cross(&bid(4_900)));
+        assert!(book.would_cross(&bid(5_000)));
+
+        let mut sell = CreateOrderRequest::limit("TEST", Side::Yes, Action::Sell, 1, 4_600);
+        assert!(!book.would_cross(&sell));
+        sell.yes_price_dollars = Some(4_500);
+        assert!(book.would_cross(&sell));
+
+        // Buying No at $0.55 sells Yes at $0.45, hitting the bid
+        let mut no_bid = CreateOrderRequest::market("TEST", Side::No, Action::Buy, 1);
+        assert!(book.would_cross(&no_bid));
+        no_bid.no_price_dollars = Some(5_500);
+        assert!(book.would_cross(&no_bid));
+        no_bid.no_price_dollars = Some(5_000);
+        assert!(!book.would_cross(&no_bid));
+    }
+
+    #[test]
+    fn test_array_backend_matches_default() {
+        let snapshot: OrderbookSnapshotData = serde_json::from_str(
+            r#"{"market_ticker":"TEST","market_id":"id",
+                "yes_dollars_fp":[["0.4500","3.00"],["0.4400","1.00"]],
+                "no_dollars_fp":[["0.5000","2.00"],["0.4250","4.00"]]}"#,
+        )
+        .unwrap();
+        let mut tree = Orderbook::new("TEST");
+        let mut array = ArrayOrderbook::with_backend("TEST");
+        tree.apply_snapshot(&snapshot, 1);
+        array.apply_snapshot(&snapshot, 1);
+
+        let deltas = [
+            delta(2, 4_500, -300),
+            delta(3, 4_600, 150),
+            delta(4, 4_400, -50),
+        ];
+        tree.apply_deltas(&deltas).unwrap();
+        array.apply_deltas(&deltas).unwrap();
+        tree.apply_delta(5_800, 75, Side::No);
+        array.apply_delta(5_800, 75, Side::No);
+
+        assert_eq!(array.top_of_book(), tree.top_of_book());
+        assert!(array.bids().eq(tree.bids()));
+        assert!(array.asks().eq(tree.asks()));
+        assert_eq!(array.num_levels(), tree.num_levels());
+        assert_eq!(array.buy_cost(500), tree.buy_cost(500));
+        assert_eq!(
+            array.quantity_to_move(Side::Yes, 2, TICK),
+            tree.quantity_to_move(Side::Yes, 2, TICK)
+        );
+
+        array.clear();
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn test_top_of_book() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.top_of_book(), TopOfBook::default());
+
+        book.set_level(4_500, 300, Side::Yes);
+        book.set_level(4_400, 100, Side::Yes);
+        book.set_level(5_200, 120, Side::No);
+
+        let top = book.top_of_book();
+        assert_eq!(top.yes_bid, Some((4_500, 300)));
+        assert_eq!(top.yes_ask, Some((5_200, 120)));
+        assert_eq!(top.no_bid, Some((4_800, 120)));
+        assert_eq!(top.no_ask, Some((5_500, 300)));
+        assert_eq!(top.yes_bid, book.best_bid());
+        assert_eq!(top.yes_ask, book.best_ask());
+        assert_eq!(top.no_bid, book.best_no_bid());
+        assert_eq!(top.no_ask, book.best_no_ask());
+    }
+
     #[test]
     fn test_top_levels() {
         let mut book = Orderbook::new("TEST");
@@ -373,6 +1166,242 @@ mod tests {
         assert_eq!(top[1], (4_400, 200));
     }
 
+    #[test]
+    fn test_sweep_cost() {
+        let mut book = Orderbook::new("TEST");
+        // Asks: 2 @ $0.50, 3 @ $0.52; bids: 1 @ $0.48, 4 @ $0.45
+        book.set_level(5_000, 200, Side::No);
+        book.set_level(5_200, 300, Side::No);
+        book.set_level(4_800, 100, Side::Yes);
+        book.set_level(4_500, 400, Side::Yes);
+
+        // Fits in the best level
+        assert_eq!(book.buy_cost(100), Some((100, 5_000)));
+        // Crosses into the second level: 2 * 0.50 + 2 * 0.52
+        assert_eq!(book.buy_cost(400), Some((400, 20_400)));
+        assert_eq!(book.vwap(Side::No, 400), Some(5_100.0));
+
+        // More than the book holds fills partially
+        assert_eq!(book.buy_cost(1_000), Some((500, 25_600)));
+        assert_eq!(book.sell_proceeds(1_000), Some((500, 22_800)));
+        assert_eq!(book.vwap(Side::Yes, 200), Some(4_650.0));
+
+        // Fractional contracts round to the nearest centi-cent
+        assert_eq!(book.sell_proceeds(150), Some((150, 7_050)));
+        assert_eq!(book.buy_cost(1), Some((1, 50)));
+
+        assert_eq!(book.buy_cost(0), None);
+        book.clear();
+        assert_eq!(book.buy_cost(100), None);
+        assert_eq!(book.vwap(Side::Yes, 100), None);
+    }
+
+    #[test]
+    fn test_vwap_depth() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.vwap_depth(Side::Yes, 3), None);
+
+        // Bids: 1 @ $0.48, 3 @ $0.46, 4 @ $0.45; asks: 2 @ $0.50, 2 @ $0.54
+        book.set_level(4_800, 100, Side::Yes);
+        book.set_level(4_600, 300, Side::Yes);
+        book.set_level(4_500, 400, Side::Yes);
+        book.set_level(5_000, 200, Side::No);
+        book.set_level(5_400, 200, Side::No);
+
+        // One level is the best price
+        let best_bid = book.best_bid().unwrap().0 as f64;
+        assert_eq!(book.vwap_depth(Side::Yes, 1), Some(best_bid));
+
+        // Deeper levels pull the bid reference below the best bid
+        assert_eq!(book.vwap_depth(Side::Yes, 2), Some(4_650.0));
+        let all = book.vwap_depth(Side::Yes, 10).unwrap();
+        assert!((all - 4_575.0).abs() < 1e-9);
+        assert!(all < best_bid);
+
+        // and the ask reference above the best ask
+        assert_eq!(book.vwap_depth(Side::No, 1), Some(5_000.0));
+        assert_eq!(book.vwap_depth(Side::No, 2), Some(5_200.0));
+
+        assert_eq!(book.vwap_depth(Side::No, 0), None);
+    }
+
+    #[test]
+    fn test_price_range() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.price_range(), None);
+
+        // One side alone bounds the range
+        book.set_level(4_500, 100, Side::Yes);
+        book.set_level(3_000, 100, Side::Yes);
+        assert_eq!(book.price_range(), Some((3_000, 4_500)));
+
+        // Asks are keyed at their Yes price, so a No bid at $0.05 is the
+        // high end at $0.95
+        book.set_level(6_000, 200, Side::No);
+        book.set_level(9_500, 200, Side::No);
+        assert_eq!(book.price_range(), Some((3_000, 9_500)));
+
+        // A crossed level below every bid moves the low end
+        book.set_level(2_000, 50, Side::No);
+        assert_eq!(book.price_range(), Some((2_000, 9_500)));
+
+        book.set_level(4_500, 0, Side::Yes);
+        book.set_level(3_000, 0, Side::Yes);
+        assert_eq!(book.price_range(), Some((2_000, 9_500)));
+    }
+
+    #[test]
+    fn test_cumulative_depth() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.cumulative_bids().next(), None);
+        assert_eq!(book.cumulative_asks().next(), None);
+
+        book.set_level(4_500, 100, Side::Yes);
+        book.set_level(4_000, 250, Side::Yes);
+        book.set_level(3_000, 50, Side::Yes);
+        book.set_level(5_500, 300, Side::No);
+        book.set_level(6_000, 200, Side::No);
+
+        let bids: Vec<_> = book.cumulative_bids().collect();
+        assert_eq!(bids, [(4_500, 100), (4_000, 350), (3_000, 400)]);
+        let asks: Vec<_> = book.cumulative_asks().collect();
+        assert_eq!(asks, [(5_500, 300), (6_000, 500)]);
+
+        // Each call starts from zero again
+        assert_eq!(book.cumulative_bids().collect::<Vec<_>>(), bids);
+        assert_eq!(
+            book.cumulative_bids().last(),
+            Some((3_000, book.total_bid_quantity()))
+        );
+    }
+
+    #[test]
+    fn test_cost_to_cross() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.cost_to_cross(), None);
+
+        // Bids alone leave nothing to lift
+        book.set_level(4_800, 500, Side::Yes);
+        assert_eq!(book.cost_to_cross(), None);
+
+        // 3 @ $0.52 at the touch, 10 @ $0.55 behind it
+        book.set_level(5_200, 300, Side::No);
+        book.set_level(5_500, 1_000, Side::No);
+        assert_eq!(book.cost_to_cross(), Some(15_600));
+
+        // Fractional contracts round half up: 0.33 * $0.52 = $0.1716
+        book.set_level(5_200, 33, Side::No);
+        assert_eq!(book.cost_to_cross(), Some(1_716));
+        book.set_level(5_100, 1, Side::No);
+        assert_eq!(book.cost_to_cross(), Some(51));
+
+        book.set_level(5_100, 0, Side::No);
+        book.set_level(5_200, 0, Side::No);
+        assert_eq!(book.cost_to_cross(), Some(55_000));
+    }
+
+    #[test]
+    fn test_quantity_to_move() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.quantity_to_move(Side::Yes, 1, TICK), 0);
+
+        book.set_level(5_000, 100, Side::Yes);
+        book.set_level(4_900, 50, Side::Yes);
+        book.set_level(4_500, 30, Side::Yes);
+        book.set_level(5_200, 40, Side::No);
+        book.set_level(5_250, 60, Side::No);
+        book.set_level(5_300, 80, Side::No);
+
+        // One tick means clearing the top bid level
+        assert_eq!(book.quantity_to_move(Side::Yes, 1, TICK), 100);
+        assert_eq!(book.quantity_to_move(Side::Yes, 2, TICK), 150);
+        // Gap below 4_900: clearing it already moves the bid five ticks
+        assert_eq!(book.quantity_to_move(Side::Yes, 3, TICK), 150);
+        assert_eq!(book.quantity_to_move(Side::Yes, 6, TICK), 180);
+
+        // Sub-tick level inside the first tick must be cleared too
+        assert_eq!(book.quantity_to_move(Side::No, 1, TICK), 100);
+        assert_eq!(book.quantity_to_move(Side::No, 2, TICK), 180);
+
+        assert_eq!(book.quantity_to_move(Side::Yes, 0, TICK), 0);
+        assert_eq!(book.quantity_to_move(Side::No, 1, 0), 0);
+    }
+
+    #[test]
+    fn test_depth_ratio() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.depth_ratio(5), None);
+
+        // Shallow: a single level per side
+        book.set_level(4_800, 100, Side::Yes);
+        book.set_level(5_200, 100, Side::No);
+        assert_eq!(book.depth_ratio(5), Some(0.0));
+
+        // Deep: 1,000 contracts behind 200 at the top
+        book.set_level(4_700, 300, Side::Yes);
+        book.set_level(4_600, 200, Side::Yes);
+        book.set_level(5_300, 500, Side::No);
+        assert_eq!(book.depth_ratio(5), Some(5.0));
+
+        // Only levels within the window count
+        assert_eq!(book.depth_ratio(2), Some(4.0));
+        assert_eq!(book.depth_ratio(1), Some(0.0));
+    }
+
+    #[test]
+    fn test_fair_value() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.fair_value(), None);
+
+        // Balanced book: equal to the mid
+        book.set_level(4_000, 500, Side::Yes);
+        book.set_level(6_000, 500, Side::No);
+        assert!((book.fair_value().unwrap() - 0.5).abs() < 1e-9);
+        assert_eq!(book.mid_price(), Some(5_000.0));
+
+        // Heavy Yes bid leans toward the ask, away from the naive mid
+        book.set_level(4_000, 900, Side::Yes);
+        book.set_level(6_000, 100, Side::No);
+        assert!((book.fair_value().unwrap() - 0.58).abs() < 1e-9);
+
+        // Heavy No bid leans toward the Yes bid
+        book.set_level(4_000, 100, Side::Yes);
+        book.set_level(6_000, 900, Side::No);
+        assert!((book.fair_value().unwrap() - 0.42).abs() < 1e-9);
+        assert_eq!(book.mid_price(), Some(5_000.0));
+    }
+
+    #[test]
+    fn test_imbalance() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.imbalance(3), None);
+
+        book.set_level(4_800, 300, Side::Yes);
+        book.set_level(4_700, 300, Side::Yes);
+        book.set_level(5_200, 200, Side::No);
+        assert_eq!(book.imbalance(1), Some(0.2));
+        assert_eq!(book.imbalance(3), Some(0.5));
+    }
+
+    #[test]
+    fn test_depth_within() {
+        let mut book = Orderbook::new("TEST");
+        book.set_level(4_800, 300, Side::Yes);
+        assert_eq!(book.depth_within(5), (0, 0));
+        assert_eq!(book.level_count_within(5), (0, 0));
+
+        // Mid is 0.5000
+        book.set_level(4_700, 200, Side::Yes);
+        book.set_level(4_500, 100, Side::Yes);
+        book.set_level(5_200, 50, Side::No);
+        book.set_level(5_400, 25, Side::No);
+        assert_eq!(book.depth_within(0), (0, 0));
+        assert_eq!(book.depth_within(2), (300, 50));
+        assert_eq!(book.level_count_within(3), (2, 1));
+        assert_eq!(book.depth_within(5), (600, 75));
+        assert_eq!(book.level_count_within(100), (3, 2));
+    }
+
     #[test]
     fn test_crossed_book() {
         let mut book = Orderbook::new("TEST");
@@ -383,6 +1412,23 @@ mod tests {
         assert!(book.is_crossed());
     }
 
+    #[test]
+    fn test_total_value_locked() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.total_value_locked(), 0);
+
+        book.set_level(4_500, 1_000, Side::Yes);
+        book.set_level(4_000, 250, Side::Yes);
+        book.set_level(5_500, 400, Side::No);
+        book.set_level(6_000, 150, Side::No);
+
+        // 10 @ 0.45 + 2.5 @ 0.40
+        assert_eq!(book.bid_value(), 55_000);
+        // No prices: 4 @ 0.45 + 1.5 @ 0.40
+        assert_eq!(book.ask_value(), 24_000);
+        assert_eq!(book.total_value_locked(), 79_000);
+    }
+
     #[test]
     fn test_clear() {
         let mut book = Orderbook::new("TEST");
@@ -396,4 +1442,222 @@ mod tests {
         assert!(book.is_empty());
         assert_eq!(book.sequence(), 0);
     }
+
+    #[test]
+    fn test_distance_from_best() {
+        let mut book = Orderbook::new("TEST");
+        book.set_level(4_500, 100, Side::Yes);
+        book.set_level(4_300, 100, Side::Yes);
+        book.set_level(5_500, 100, Side::No);
+
+        // At best
+        assert!(book.is_best(4_500, Side::Yes));
+        assert!(book.is_best(5_500, Side::No));
+        assert_eq!(book.distance_from_best(4_500, Side::Yes), Some(0));
+
+        // Behind best
+        assert!(!book.is_best(4_300, Side::Yes));
+        assert_eq!(book.distance_from_best(4_300, Side::Yes), Some(2));
+        assert_eq!(book.distance_from_best(5_700, Side::No), Some(2));
+
+        // Inside the spread
+        assert_eq!(book.distance_from_best(4_600, Side::Yes), Some(-1));
+
+        // Empty side
+        book.set_level(5_500, 0, Side::No);
+        assert!(!book.is_best(5_500, Side::No));
+        assert_eq!(book.distance_from_best(5_500, Side::No), None);
+    }
+
+    #[test]
+    fn test_mid_price_centicents() {
+        let mut book = Orderbook::new("TEST");
+        assert_eq!(book.mid_price_centicents(), None);
+
+        book.set_level(4_500, 100, Side::Yes);
+        book.set_level(5_501, 100, Side::No);
+
+        let float_mid = book.mid_price().unwrap();
+        assert_eq!(book.mid_price_centicents(), Some(5_001));
+        assert_eq!(
+            book.mid_price_centicents(),
+            Some(float_mid.round() as Price)
+        );
+    }
+
+    fn delta(seq: u64, price: Price, delta_fp: i64) -> OrderbookDeltaMsg {
+        OrderbookDeltaMsg {
+            sid: 1,
+            seq,
+            msg: OrderbookDeltaData {
+                market_ticker: "TEST".to_string(),
+                market_id: "mid".to_string(),
+                price_dollars: price,
+                delta_fp,
+                side: Side::Yes,
+                ts: None,
+                client_order_id: None,
+                subaccount: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_seed_from_rest() {
+        let rest: RestOrderbook = serde_json::from_str(
+            r#"{"yes_dollars":[["0.4500","10.00"],["0.4400","0.00"],["0.4500","2.00"]],
+                "no_dollars":[["0.5000","5.00"],["0.4900","1.00"]]}"#,
+        )
+        .unwrap();
+
+        // Stale levels and sequence from an earlier stream are discarded
+        let mut book = Orderbook::new("TEST");
+        book.apply_deltas(&[delta(7, 3_000, 100)]).unwrap();
+
+        book.seed_from_rest(&rest);
+        assert_eq!(book.bids().collect::<Vec<_>>(), vec![(4_500, 1_200)]);
+        assert_eq!(
+            book.asks().collect::<Vec<_>>(),
+            vec![(5_000, 500), (5_100, 100)]
+        );
+        assert_eq!(book.sequence(), 0);
+
+        // WebSocket deltas continue from the seeded book
+        book.apply_deltas(&[delta(42, 4_500, -200), delta(43, 4_600, 50)])
+            .unwrap();
+        assert_eq!(book.best_bid(), Some((4_600, 50)));
+        assert_eq!(book.bids().nth(1), Some((4_500, 1_000)));
+        assert_eq!(book.sequence(), 43);
+
+        // A ragged row is rejected when the REST response is parsed
+        assert!(serde_json::from_str::<RestOrderbook>(r#"{"yes_dollars":[["0.4500"]]}"#).is_err());
+    }
+
+    #[test]
+    fn test_apply_deltas_contiguous() {
+        let mut book = Orderbook::new("TEST");
+        book.set_level(5_000, 100, Side::Yes);
+        book.sequence = 1;
+
+        book.apply_deltas(&[
+            delta(2, 5_000, 50),
+            delta(3, 5_100, 25),
+            delta(4, 5_000, -150),
+        ])
+        .unwrap();
+
+        assert_eq!(book.sequence(), 4);
+        assert_eq!(book.top_bids(2), vec![(5_100, 25)]);
+    }
+
+    #[test]
+    fn test_apply_deltas_gap_rolls_back() {
+        let mut book = Orderbook::new("TEST");
+        book.set_level(5_000, 100, Side::Yes);
+        book.sequence = 1;
+
+        let result = book.apply_deltas(&[delta(2, 5_000, 50), delta(4, 5_100, 25)]);
+
+        assert!(matches!(
+            result,
+            Err(Error::SequenceGap {
+                expected: 3,
+                got: 4
+            })
+        ));
+        assert_eq!(book.sequence(), 1);
+        assert_eq!(book.top_bids(2), vec![(5_000, 100)]);
+    }
+
+    #[test]
+    fn test_validate_delta_stream_reports_all_gaps() {
+        let snapshot = OrderbookSnapshotData {
+            market_ticker: "TEST".to_string(),
+            market_id: "mid".to_string(),
+            yes_dollars_fp: vec![["0.5000".to_string(), "100.00".to_string()]],
+            no_dollars_fp: vec![],
+        };
+        let deltas = [
+            delta(10, 5_000, 50),
+            delta(11, 5_000, 50),
+            delta(13, 5_100, 25),
+            delta(14, 5_100, 25),
+            delta(14, 5_000, -50),
+            delta(20, 5_000, -50),
+        ];
+
+        let gaps = validate_delta_stream(&snapshot, &deltas);
+        assert_eq!(
+            gaps,
+            vec![
+                SequenceGapReport {
+                    index: 2,
+                    expected: 12,
+                    got: 13
+                },
+                SequenceGapReport {
+                    index: 4,
+                    expected: 15,
+                    got: 14
+                },
+                SequenceGapReport {
+                    index: 5,
+                    expected: 15,
+                    got: 20
+                },
+            ]
+        );
+
+        assert!(validate_delta_stream(&snapshot, &deltas[..2]).is_empty());
+        assert!(validate_delta_stream(&snapshot, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_estimated_queue_ahead() {
+        let mut book = Orderbook::new("TEST");
+        book.set_level(4_500, 300, Side::Yes);
+        book.set_level(5_500, 120, Side::No);
+
+        assert_eq!(book.estimated_queue_ahead(4_500, Side::Yes), 300);
+        assert_eq!(book.estimated_queue_ahead(5_500, Side::No), 120);
+
+        // Empty levels, including a populated price on the other ladder
+        assert_eq!(book.estimated_queue_ahead(4_400, Side::Yes), 0);
+        assert_eq!(book.estimated_queue_ahead(4_500, Side::No), 0);
+    }
+
+    #[test]
+    fn test_mid_ema_converges() {
+        let mut book = Orderbook::new("TEST").with_mid_ema(0.5);
+        assert_eq!(book.smoothed_mid(), None);
+
+        book.set_level(4_900, 100, Side::Yes);
+        book.set_level(5_100, 100, Side::No);
+        assert_eq!(book.smoothed_mid(), Some(5_000.0));
+
+        // Mid jumps to 6,000 and stays there; EMA halves the gap each move
+        book.set_level(5_900, 100, Side::Yes);
+        book.set_level(6_100, 100, Side::No);
+        book.set_level(4_900, 0, Side::Yes);
+        book.set_level(5_100, 0, Side::No);
+        assert_eq!(book.mid_price(), Some(6_000.0));
+        let first = book.smoothed_mid().unwrap();
+
+        // Size-only changes don't move the mid, so they don't count
+        for _ in 0..20 {
+            book.apply_delta(5_900, 10, Side::Yes);
+        }
+        assert_eq!(book.smoothed_mid(), Some(first));
+
+        // Oscillate the ask by one unit to keep feeding ~6,000
+        for i in 0..30 {
+            book.set_level(6_100 + (i % 2), 100, Side::No);
+            book.set_level(6_100 + ((i + 1) % 2), 0, Side::No);
+        }
+        let converged = book.smoothed_mid().unwrap();
+        assert!((converged - 6_000.0).abs() < 1.0, "{converged}");
+        assert!(first < converged);
+
+        assert_eq!(Orderbook::new("TEST").smoothed_mid(), None);
+    }
 }
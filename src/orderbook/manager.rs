@@ -14,12 +14,19 @@
 //! When a gap is detected, the orderbook is marked as stale and should be
 //! re-synchronized via a snapshot request.
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use rustc_hash::FxHashMap;
 
 use parking_lot::RwLock;
+use tokio::sync::Notify;
 
 use crate::error::Error;
+use crate::types::market::Orderbook as RestOrderbook;
 use crate::types::messages::{OrderbookDeltaMsg, OrderbookSnapshotMsg, WsMessage};
+use crate::types::order::Side;
+use crate::types::{Price, Quantity, TimestampMs};
 
 use super::Orderbook;
 
@@ -40,6 +47,42 @@ struct OrderbookEntry {
     book: Orderbook,
     state: OrderbookState,
     subscription_id: Option<u64>,
+    /// Local receive time of the last applied snapshot or delta, in Unix ms
+    last_update_ms: Option<TimestampMs>,
+}
+
+/// A price level that differs between the local book and a REST snapshot
+///
+/// Levels are in REST terms: `Side::Yes` is a Yes bid at a Yes price and
+/// `Side::No` is a No bid at a No price. A quantity of 0 means the level is
+/// missing on that side of the comparison.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelDiscrepancy {
+    /// Which bid ladder the level is on
+    pub side: Side,
+    /// Level price in that side's terms
+    pub price: Price,
+    /// Quantity in the locally maintained book
+    pub local: Quantity,
+    /// Quantity in the REST snapshot
+    pub remote: Quantity,
+}
+
+/// Result of [`OrderbookManager::reconcile`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconcileReport {
+    /// Market that was compared
+    pub market_ticker: String,
+    /// Every level that differs, Yes side first, each in ascending price
+    pub discrepancies: Vec<LevelDiscrepancy>,
+}
+
+impl ReconcileReport {
+    /// Check if the local book matched the snapshot exactly
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
 }
 
 /// Manager for multiple orderbooks with WebSocket integration.
@@ -82,6 +125,8 @@ struct OrderbookEntry {
 pub struct OrderbookManager {
     /// Orderbooks by market ticker
     books: RwLock<FxHashMap<String, RwLock<OrderbookEntry>>>,
+    /// Woken whenever a book may have become synchronized
+    synced: Notify,
 }
 
 impl OrderbookManager {
@@ -90,6 +135,7 @@ impl OrderbookManager {
     pub fn new() -> Self {
         Self {
             books: RwLock::new(FxHashMap::default()),
+            synced: Notify::new(),
         }
     }
 
@@ -104,6 +150,7 @@ impl OrderbookManager {
                 book: Orderbook::new(&ticker),
                 state: OrderbookState::WaitingForSnapshot,
                 subscription_id: None,
+                last_update_ms: None,
             })
         });
     }
@@ -112,6 +159,8 @@ impl OrderbookManager {
     pub fn remove_market(&self, market_ticker: &str) {
         let mut books = self.books.write();
         books.remove(market_ticker);
+        drop(books);
+        self.synced.notify_waiters();
     }
 
     /// Set the subscription ID for a market
@@ -148,13 +197,132 @@ impl OrderbookManager {
             .collect()
     }
 
+    /// Get when a market's book last changed, in Unix milliseconds
+    ///
+    /// This is the local time the last snapshot or delta was applied. Returns
+    /// `None` if the market is not tracked or has not received a snapshot.
+    #[must_use]
+    pub fn last_update_ms(&self, market_ticker: &str) -> Option<TimestampMs> {
+        let books = self.books.read();
+        books
+            .get(market_ticker)
+            .and_then(|e| e.read().last_update_ms)
+    }
+
+    /// Get markets whose books have not updated within `max_age` of `now`
+    ///
+    /// `now` is Unix milliseconds, normally the current time. Markets that
+    /// never received a snapshot are included. Use this to spot feeds that
+    /// went quiet while the connection stayed up.
+    #[must_use]
+    pub fn stale_markets(&self, max_age: Duration, now: TimestampMs) -> Vec<String> {
+        let max_age_ms = i64::try_from(max_age.as_millis()).unwrap_or(i64::MAX);
+        let books = self.books.read();
+        books
+            .iter()
+            .filter(|(_, entry)| {
+                entry
+                    .read()
+                    .last_update_ms
+                    .map_or(true, |last| now.saturating_sub(last) > max_age_ms)
+            })
+            .map(|(ticker, _)| ticker.clone())
+            .collect()
+    }
+
+    /// Check if every tracked orderbook is synchronized
+    ///
+    /// Returns `true` when no markets are tracked.
+    #[must_use]
+    pub fn all_synchronized(&self) -> bool {
+        let books = self.books.read();
+        books
+            .values()
+            .all(|entry| entry.read().state == OrderbookState::Synchronized)
+    }
+
+    /// Wait until every tracked orderbook is synchronized
+    ///
+    /// Call this after subscribing and before trading to avoid acting on
+    /// partial data. Resolves immediately if all books are already
+    /// synchronized.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Timeout`] if some book is still waiting for a snapshot
+    /// or needs resync when `timeout` elapses.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kalshi_trading::orderbook::OrderbookManager;
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> kalshi_trading::Result<()> {
+    /// let manager = Arc::new(OrderbookManager::new());
+    /// manager.add_market("KXBTC-25JAN");
+    ///
+    /// // Feed messages from another task, then:
+    /// manager.wait_until_synchronized(Duration::from_secs(10)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_until_synchronized(&self, timeout: Duration) -> Result<(), Error> {
+        let wait = async {
+            loop {
+                // Register before checking so a snapshot landing in between
+                // still wakes us
+                let notified = self.synced.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                if self.all_synchronized() {
+                    return;
+                }
+                notified.await;
+            }
+        };
+
+        tokio::time::timeout(timeout, wait)
+            .await
+            .map_err(|_| Error::Timeout)
+    }
+
     /// Get a snapshot of an orderbook
     ///
     /// Returns a cloned copy of the orderbook for safe reading without holding locks.
+    /// The clone copies every price level on both sides; on hot read paths prefer
+    /// [`with_orderbook`](Self::with_orderbook), which borrows the book instead.
     #[must_use]
     pub fn get_orderbook(&self, market_ticker: &str) -> Option<Orderbook> {
+        self.with_orderbook(market_ticker, Orderbook::clone)
+    }
+
+    /// Run a closure against an orderbook without cloning it
+    ///
+    /// The closure runs while holding the market's read lock, so it should be
+    /// short: updates to the market (and adding or removing markets) wait until
+    /// it returns. Returns `None` if the market is not tracked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kalshi_trading::orderbook::OrderbookManager;
+    ///
+    /// let manager = OrderbookManager::new();
+    /// manager.add_market("KXBTC-25JAN");
+    ///
+    /// let levels = manager.with_orderbook("KXBTC-25JAN", |book| book.num_levels());
+    /// assert_eq!(levels, Some((0, 0)));
+    /// ```
+    pub fn with_orderbook<R>(
+        &self,
+        market_ticker: &str,
+        f: impl FnOnce(&Orderbook) -> R,
+    ) -> Option<R> {
         let books = self.books.read();
-        books.get(market_ticker).map(|e| e.read().book.clone())
+        books.get(market_ticker).map(|e| f(&e.read().book))
     }
 
     /// Get best bid for a market
@@ -184,6 +352,17 @@ impl OrderbookManager {
             .and_then(|e| e.read().book.mid_price())
     }
 
+    /// Get mid price for a market in centi-cents (ten-thousandths of a dollar)
+    ///
+    /// Integer-exact alternative to [`mid_price`](Self::mid_price).
+    #[must_use]
+    pub fn mid_price_centicents(&self, market_ticker: &str) -> Option<Price> {
+        let books = self.books.read();
+        books
+            .get(market_ticker)
+            .and_then(|e| e.read().book.mid_price_centicents())
+    }
+
     /// Get spread for a market
     #[must_use]
     pub fn spread(&self, market_ticker: &str) -> Option<i64> {
@@ -193,6 +372,23 @@ impl OrderbookManager {
             .and_then(|e| e.read().book.spread())
     }
 
+    /// Get the quantity-weighted order imbalance across all tracked markets
+    ///
+    /// Each book's [`Orderbook::imbalance`] over `depth` levels is weighted
+    /// by its quantity in those levels, which equals total bid minus total
+    /// ask quantity over their sum. Empty books are skipped. Returns `None`
+    /// if every book is empty.
+    #[must_use]
+    pub fn aggregate_imbalance(&self, depth: usize) -> Option<f64> {
+        let books = self.books.read();
+        let (bids, asks) = books
+            .values()
+            .map(|entry| entry.read().book.depth_quantities(depth))
+            .fold((0, 0), |(bids, asks), (b, a)| (bids + b, asks + a));
+        let total = bids + asks;
+        (total > 0).then(|| (bids - asks) as f64 / total as f64)
+    }
+
     /// Process a WebSocket message
     ///
     /// Automatically routes snapshots and deltas to the appropriate orderbook.
@@ -227,6 +423,11 @@ impl OrderbookManager {
     /// Note: This method may briefly acquire a write lock on the books map
     /// if the market doesn't exist and needs to be added.
     fn apply_snapshot(&self, snapshot: &OrderbookSnapshotMsg) {
+        self.apply_snapshot_inner(snapshot);
+        self.synced.notify_waiters();
+    }
+
+    fn apply_snapshot_inner(&self, snapshot: &OrderbookSnapshotMsg) {
         let ticker = &snapshot.msg.market_ticker;
 
         // First, try with a read lock (common case - market already tracked)
@@ -237,6 +438,7 @@ impl OrderbookManager {
                 e.book.apply_snapshot(&snapshot.msg, snapshot.seq);
                 e.state = OrderbookState::Synchronized;
                 e.subscription_id = Some(snapshot.sid);
+                e.last_update_ms = Some(now_ms());
                 return;
             }
         }
@@ -251,6 +453,7 @@ impl OrderbookManager {
             e.book.apply_snapshot(&snapshot.msg, snapshot.seq);
             e.state = OrderbookState::Synchronized;
             e.subscription_id = Some(snapshot.sid);
+            e.last_update_ms = Some(now_ms());
         } else {
             // Create new entry
             let mut book = Orderbook::new(ticker);
@@ -261,6 +464,7 @@ impl OrderbookManager {
                     book,
                     state: OrderbookState::Synchronized,
                     subscription_id: Some(snapshot.sid),
+                    last_update_ms: Some(now_ms()),
                 }),
             );
         }
@@ -284,6 +488,7 @@ impl OrderbookManager {
 
             // Apply delta and check sequence
             if e.book.apply_delta_msg(&delta.msg, delta.seq) {
+                e.last_update_ms = Some(now_ms());
                 Ok(true)
             } else {
                 // Sequence gap detected
@@ -299,6 +504,54 @@ impl OrderbookManager {
         }
     }
 
+    /// Compare a market's local book against a REST snapshot
+    ///
+    /// Reports every level whose quantity differs, including levels present
+    /// on only one side. The local book is not modified; call
+    /// [`mark_needs_resync`](Self::mark_needs_resync) to act on drift.
+    /// [`RestClient::reconcile_orderbook`](crate::client::RestClient::reconcile_orderbook)
+    /// fetches the snapshot and calls this.
+    ///
+    /// The two views are taken at slightly different times, so a busy
+    /// market can show transient differences. Repeated discrepancies at the
+    /// same levels point to real drift.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] if the market is not tracked.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kalshi_trading::orderbook::OrderbookManager;
+    ///
+    /// # async fn example(manager: &OrderbookManager, client: &kalshi_trading::client::RestClient) -> kalshi_trading::Result<()> {
+    /// let snapshot = client.get_orderbook("KXBTC-25JAN").await?.orderbook_fp;
+    /// let report = manager.reconcile("KXBTC-25JAN", &snapshot)?;
+    /// if !report.is_consistent() {
+    ///     manager.mark_needs_resync(&report.market_ticker);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reconcile(
+        &self,
+        market_ticker: &str,
+        snapshot: &RestOrderbook,
+    ) -> Result<ReconcileReport, Error> {
+        let book = self
+            .get_orderbook(market_ticker)
+            .ok_or_else(|| untracked(market_ticker))?;
+
+        let mut discrepancies = diff_levels(Side::Yes, book.bids(), &snapshot.yes_dollars);
+        discrepancies.extend(diff_levels(Side::No, book.no_bids(), &snapshot.no_dollars));
+
+        Ok(ReconcileReport {
+            market_ticker: market_ticker.to_string(),
+            discrepancies,
+        })
+    }
+
     /// Mark an orderbook as needing resync
     pub fn mark_needs_resync(&self, market_ticker: &str) {
         let books = self.books.read();
@@ -311,6 +564,8 @@ impl OrderbookManager {
     pub fn clear(&self) {
         let mut books = self.books.write();
         books.clear();
+        drop(books);
+        self.synced.notify_waiters();
     }
 
     /// Get number of tracked markets
@@ -332,11 +587,52 @@ impl OrderbookManager {
     }
 }
 
+/// Levels where `local` and `remote` quantities differ, in ascending price
+fn diff_levels(
+    side: Side,
+    local: impl Iterator<Item = (Price, Quantity)>,
+    remote: &[[i64; 2]],
+) -> Vec<LevelDiscrepancy> {
+    let local: BTreeMap<Price, Quantity> = local.collect();
+    let mut remote_levels: BTreeMap<Price, Quantity> = BTreeMap::new();
+    for &[price, quantity] in remote {
+        if quantity > 0 {
+            *remote_levels.entry(price).or_insert(0) += quantity;
+        }
+    }
+
+    let prices: BTreeSet<Price> = local.keys().chain(remote_levels.keys()).copied().collect();
+    prices
+        .into_iter()
+        .filter_map(|price| {
+            let local = local.get(&price).copied().unwrap_or(0);
+            let remote = remote_levels.get(&price).copied().unwrap_or(0);
+            (local != remote).then_some(LevelDiscrepancy {
+                side,
+                price,
+                local,
+                remote,
+            })
+        })
+        .collect()
+}
+
+/// Error for a market the manager does not track
+fn untracked(market_ticker: &str) -> Error {
+    Error::Config(format!("market {market_ticker} is not tracked"))
+}
+
+/// Current Unix time in milliseconds
+fn now_ms() -> TimestampMs {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as TimestampMs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::messages::{OrderbookDeltaData, OrderbookSnapshotData};
-    use crate::types::order::Side;
 
     #[test]
     fn test_add_market() {
@@ -482,4 +778,188 @@ mod tests {
         assert_eq!(needing_resync.len(), 1);
         assert_eq!(needing_resync[0], "TEST2");
     }
+
+    fn empty_snapshot(ticker: &str) -> OrderbookSnapshotMsg {
+        OrderbookSnapshotMsg {
+            sid: 1,
+            seq: 1,
+            msg: OrderbookSnapshotData {
+                market_ticker: ticker.to_string(),
+                market_id: "mid".to_string(),
+                yes_dollars_fp: vec![],
+                no_dollars_fp: vec![],
+            },
+        }
+    }
+
+    #[test]
+    fn test_aggregate_imbalance() {
+        let manager = OrderbookManager::new();
+        assert_eq!(manager.aggregate_imbalance(5), None);
+
+        manager.apply_snapshot(&empty_snapshot("BID_HEAVY"));
+        manager.apply_snapshot(&empty_snapshot("ASK_HEAVY"));
+        manager.add_market("EMPTY");
+        {
+            let books = manager.books.read();
+            // Imbalance 0.5 over 400 contracts
+            let mut bid_heavy = books["BID_HEAVY"].write();
+            bid_heavy.book.set_level(4_800, 300, Side::Yes);
+            bid_heavy.book.set_level(5_200, 100, Side::No);
+            // Imbalance -0.5 over 100 contracts
+            let mut ask_heavy = books["ASK_HEAVY"].write();
+            ask_heavy.book.set_level(4_800, 25, Side::Yes);
+            ask_heavy.book.set_level(5_200, 75, Side::No);
+        }
+
+        // (0.5 * 400 - 0.5 * 100) / 500
+        assert_eq!(manager.aggregate_imbalance(5), Some(0.3));
+    }
+
+    #[test]
+    fn test_stale_markets() {
+        let manager = OrderbookManager::new();
+        manager.apply_snapshot(&empty_snapshot("FRESH"));
+        manager.apply_snapshot(&empty_snapshot("STALE"));
+        manager.add_market("NEVER");
+
+        let now = manager.last_update_ms("FRESH").unwrap();
+        assert!(now > 0);
+        manager.books.read()["STALE"].write().last_update_ms = Some(now - 60_000);
+
+        let mut stale = manager.stale_markets(Duration::from_secs(30), now);
+        stale.sort();
+        assert_eq!(stale, ["NEVER", "STALE"]);
+        assert_eq!(manager.last_update_ms("NEVER"), None);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_synchronized() {
+        use std::sync::Arc;
+
+        let manager = Arc::new(OrderbookManager::new());
+        manager.add_market("TEST1");
+        manager.add_market("TEST2");
+        assert!(!manager.all_synchronized());
+
+        let feeder = Arc::clone(&manager);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            feeder.apply_snapshot(&empty_snapshot("TEST1"));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            feeder.apply_snapshot(&empty_snapshot("TEST2"));
+        });
+
+        manager
+            .wait_until_synchronized(Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert!(manager.all_synchronized());
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_synchronized_timeout() {
+        let manager = OrderbookManager::new();
+        manager.add_market("TEST1");
+        manager.add_market("TEST2");
+        manager.apply_snapshot(&empty_snapshot("TEST1"));
+
+        let result = manager
+            .wait_until_synchronized(Duration::from_millis(20))
+            .await;
+        assert!(matches!(result, Err(Error::Timeout)));
+    }
+
+    #[test]
+    fn test_with_orderbook() {
+        let manager = OrderbookManager::new();
+        let mut snapshot = empty_snapshot("TEST");
+        snapshot.msg.yes_dollars_fp = vec![["0.4500".to_string(), "2.00".to_string()]];
+        manager.apply_snapshot(&snapshot);
+
+        assert_eq!(
+            manager.with_orderbook("TEST", Orderbook::best_bid),
+            Some(Some((4_500, 200)))
+        );
+        assert_eq!(manager.with_orderbook("MISSING", Orderbook::best_bid), None);
+        assert_eq!(
+            manager.get_orderbook("TEST").unwrap().best_bid(),
+            Some((4_500, 200))
+        );
+    }
+
+    #[test]
+    fn test_mid_price_centicents() {
+        let manager = OrderbookManager::new();
+        let mut snapshot = empty_snapshot("TEST");
+        snapshot.msg.yes_dollars_fp = vec![["0.4500".to_string(), "1.00".to_string()]];
+        snapshot.msg.no_dollars_fp = vec![["0.4499".to_string(), "1.00".to_string()]];
+        manager.apply_snapshot(&snapshot);
+
+        // Bid 0.4500, ask 1.0000 - 0.4499 = 0.5501
+        let float_mid = manager.mid_price("TEST").unwrap();
+        assert_eq!(manager.mid_price_centicents("TEST"), Some(5_001));
+        assert_eq!(
+            manager.mid_price_centicents("TEST"),
+            Some(float_mid.round() as Price)
+        );
+        assert_eq!(manager.mid_price_centicents("MISSING"), None);
+    }
+
+    #[test]
+    fn test_reconcile() {
+        let manager = OrderbookManager::new();
+        manager.apply_snapshot(&OrderbookSnapshotMsg {
+            sid: 1,
+            seq: 1,
+            msg: OrderbookSnapshotData {
+                market_ticker: "TEST".to_string(),
+                market_id: "mid".to_string(),
+                yes_dollars_fp: vec![
+                    ["0.4500".to_string(), "2.00".to_string()],
+                    ["0.5000".to_string(), "1.00".to_string()],
+                ],
+                no_dollars_fp: vec![["0.4000".to_string(), "1.50".to_string()]],
+            },
+        });
+
+        let rest: RestOrderbook = serde_json::from_str(
+            r#"{"yes_dollars": [["0.4500", "2.00"], ["0.5000", "1.00"]],
+                "no_dollars": [["0.4000", "1.50"]]}"#,
+        )
+        .unwrap();
+        let report = manager.reconcile("TEST", &rest).unwrap();
+        assert_eq!(report.market_ticker, "TEST");
+        assert!(report.is_consistent());
+
+        // Missed a fill at 0.50, never saw a new No level at 0.42
+        let drifted: RestOrderbook = serde_json::from_str(
+            r#"{"yes_dollars": [["0.4500", "2.00"], ["0.5000", "0.50"]],
+                "no_dollars": [["0.4000", "1.50"], ["0.4200", "3.00"]]}"#,
+        )
+        .unwrap();
+        let report = manager.reconcile("TEST", &drifted).unwrap();
+        assert_eq!(
+            report.discrepancies,
+            vec![
+                LevelDiscrepancy {
+                    side: Side::Yes,
+                    price: 5_000,
+                    local: 100,
+                    remote: 50
+                },
+                LevelDiscrepancy {
+                    side: Side::No,
+                    price: 4_200,
+                    local: 0,
+                    remote: 300
+                },
+            ]
+        );
+
+        assert!(matches!(
+            manager.reconcile("UNKNOWN", &rest),
+            Err(Error::Config(_))
+        ));
+    }
 }
@@ -9,8 +9,11 @@
 //! # Components
 //!
 //! - [`Orderbook`] - Single market orderbook with delta/snapshot support
+//! - [`ArrayOrderbook`] - The same orderbook on a price-indexed array backend
+//! - [`validate_delta_stream`] - Audit a recorded feed for sequence gaps
 //! - [`OrderbookManager`] - Thread-safe container for multiple orderbooks
 //! - [`OrderbookState`] - State enum for tracking sync status
+//! - [`OrderbookPublisher`] - Per-market `watch` channels fed by a manager
 //!
 //! # Example
 //!
@@ -30,8 +33,12 @@
 //! }
 //! ```
 
+pub mod backend;
 pub mod book;
 pub mod manager;
+pub mod publisher;
 
-pub use book::Orderbook;
-pub use manager::{OrderbookManager, OrderbookState};
+pub use backend::{OrderbookBackend, PriceArray};
+pub use book::{validate_delta_stream, ArrayOrderbook, Orderbook, SequenceGapReport, TopOfBook};
+pub use manager::{LevelDiscrepancy, OrderbookManager, OrderbookState, ReconcileReport};
+pub use publisher::OrderbookPublisher;
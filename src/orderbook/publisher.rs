@@ -0,0 +1,199 @@
+//! Per-market orderbook fan-out over `tokio::sync::watch` channels.
+//!
+//! [`OrderbookPublisher`] wraps an [`OrderbookManager`] and publishes the latest
+//! [`Orderbook`] for a market on its own `watch` channel after every update.
+//! This suits consumers that bind one task or UI widget per market and only
+//! care about the most recent state.
+//!
+//! A market that hits a sequence gap is published as an empty book, the same
+//! as before its first snapshot, until a new snapshot resynchronizes it.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rustc_hash::FxHashMap;
+use tokio::sync::watch;
+
+use crate::error::Error;
+use crate::types::messages::WsMessage;
+
+use super::{Orderbook, OrderbookManager, OrderbookState};
+
+/// Publishes each tracked market's orderbook on a dedicated `watch` channel.
+///
+/// # Example
+///
+/// ```rust
+/// use kalshi_trading::orderbook::OrderbookPublisher;
+///
+/// let (publisher, receivers) = OrderbookPublisher::new(&["KXBTC-25JAN", "KXBTC-26JAN"]);
+///
+/// // In your WebSocket loop:
+/// // publisher.process_message(&ws_message);
+///
+/// let rx = &receivers["KXBTC-25JAN"];
+/// println!("Best bid: {:?}", rx.borrow().best_bid());
+/// # let _ = publisher;
+/// ```
+#[derive(Debug)]
+pub struct OrderbookPublisher {
+    manager: Arc<OrderbookManager>,
+    senders: FxHashMap<String, watch::Sender<Orderbook>>,
+}
+
+impl OrderbookPublisher {
+    /// Create a publisher for the given markets
+    ///
+    /// Returns the publisher and one receiver per market ticker. Each receiver
+    /// starts with an empty book until the first snapshot arrives.
+    #[must_use]
+    pub fn new(market_tickers: &[&str]) -> (Self, HashMap<String, watch::Receiver<Orderbook>>) {
+        let manager = Arc::new(OrderbookManager::new());
+        let mut senders = FxHashMap::default();
+        let mut receivers = HashMap::with_capacity(market_tickers.len());
+
+        for &ticker in market_tickers {
+            manager.add_market(ticker);
+            let (tx, rx) = watch::channel(Orderbook::new(ticker));
+            senders.insert(ticker.to_string(), tx);
+            receivers.insert(ticker.to_string(), rx);
+        }
+
+        (Self { manager, senders }, receivers)
+    }
+
+    /// Get the underlying manager
+    #[must_use]
+    pub fn manager(&self) -> &Arc<OrderbookManager> {
+        &self.manager
+    }
+
+    /// Process a WebSocket message and publish the updated book
+    ///
+    /// Messages for markets without a channel are applied to the manager but
+    /// not published. Returns the same values as
+    /// [`OrderbookManager::process_message`]; after a sequence gap the
+    /// market's receiver is given an empty book.
+    pub fn process_message(&self, message: &WsMessage) -> Result<Option<String>, Error> {
+        let result = self.manager.process_message(message);
+
+        let ticker = match message {
+            WsMessage::OrderbookSnapshot(snapshot) => Some(&snapshot.msg.market_ticker),
+            WsMessage::OrderbookDelta(delta) => Some(&delta.msg.market_ticker),
+            _ => None,
+        };
+        if let Some(ticker) = ticker {
+            self.publish(ticker);
+        }
+
+        result
+    }
+
+    /// Publish the manager's current book for `market_ticker`
+    ///
+    /// Use this instead of [`process_message`](Self::process_message) when
+    /// the manager is fed elsewhere, for example by a
+    /// [`ReconnectingWebSocket`](crate::client::websocket::ReconnectingWebSocket)
+    /// with the manager attached. A synchronized book is sent as is; a
+    /// market that is waiting for a snapshot or needs a resync is sent an
+    /// empty book, once. Returns `true` if anything was sent.
+    pub fn publish(&self, market_ticker: &str) -> bool {
+        let Some(tx) = self.senders.get(market_ticker) else {
+            return false;
+        };
+
+        if self.manager.get_state(market_ticker) == Some(OrderbookState::Synchronized) {
+            if let Some(book) = self.manager.get_orderbook(market_ticker) {
+                tx.send_replace(book);
+                return true;
+            }
+        }
+        tx.send_if_modified(|book| {
+            if book.is_empty() && book.sequence() == 0 {
+                return false;
+            }
+            *book = Orderbook::new(market_ticker);
+            true
+        })
+    }
+
+    /// Check if every receiver has been dropped
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.senders.values().all(|tx| tx.is_closed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(ticker: &str, bid: &str) -> WsMessage {
+        serde_json::from_str(&format!(
+            r#"{{"type":"orderbook_snapshot","sid":1,"seq":1,"msg":{{
+                "market_ticker":"{}","market_id":"id",
+                "yes_dollars_fp":[["{}","10.00"]],"no_dollars_fp":[]}}}}"#,
+            ticker, bid
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_publishes_per_market() {
+        let (publisher, mut receivers) = OrderbookPublisher::new(&["A", "B"]);
+        let mut rx_a = receivers.remove("A").unwrap();
+        let rx_b = receivers.remove("B").unwrap();
+
+        assert!(rx_a.borrow().is_empty());
+
+        publisher.process_message(&snapshot("A", "0.4200")).unwrap();
+
+        assert!(rx_a.has_changed().unwrap());
+        assert_eq!(rx_a.borrow_and_update().best_bid(), Some((4_200, 1_000)));
+        assert!(!rx_b.has_changed().unwrap());
+        assert!(rx_b.borrow().is_empty());
+
+        // Untracked markets are applied but not published
+        publisher.process_message(&snapshot("C", "0.1000")).unwrap();
+        assert_eq!(publisher.manager().best_bid("C"), Some((1_000, 1_000)));
+    }
+
+    #[test]
+    fn test_gapped_books_are_not_published() {
+        let (publisher, mut receivers) = OrderbookPublisher::new(&["A"]);
+        let mut rx = receivers.remove("A").unwrap();
+        let delta = |seq: u64| -> WsMessage {
+            serde_json::from_str(&format!(
+                r#"{{"type":"orderbook_delta","sid":1,"seq":{},"msg":{{
+                    "market_ticker":"A","market_id":"id","price_dollars":"0.4200",
+                    "delta_fp":"1.00","side":"yes"}}}}"#,
+                seq
+            ))
+            .unwrap()
+        };
+
+        publisher.process_message(&snapshot("A", "0.4200")).unwrap();
+        publisher.process_message(&delta(2)).unwrap();
+        assert_eq!(rx.borrow_and_update().best_bid(), Some((4_200, 1_100)));
+
+        // A gap replaces the book with an empty one
+        assert!(publisher.process_message(&delta(4)).is_err());
+        assert!(rx.has_changed().unwrap());
+        assert!(rx.borrow_and_update().is_empty());
+
+        // Deltas skipped while resyncing publish nothing further
+        publisher.process_message(&delta(5)).unwrap();
+        assert!(!rx.has_changed().unwrap());
+
+        publisher.process_message(&snapshot("A", "0.4500")).unwrap();
+        assert_eq!(rx.borrow_and_update().best_bid(), Some((4_500, 1_000)));
+    }
+
+    #[test]
+    fn test_is_closed() {
+        let (publisher, receivers) = OrderbookPublisher::new(&["A"]);
+        assert!(!publisher.is_closed());
+        drop(receivers);
+        assert!(publisher.is_closed());
+    }
+}
@@ -4,6 +4,8 @@ use serde::{Deserialize, Deserializer, Serializer};
 
 use crate::error::Error;
 
+use super::Price;
+
 pub const DOLLAR_SCALE: i64 = 10_000;
 pub const COUNT_SCALE: i64 = 100;
 
@@ -91,6 +93,20 @@ fn scaled_to_string(value: i64, scale: i64) -> String {
     format!("{sign}{whole}.{frac:0precision$}")
 }
 
+pub fn serialize_dollars<S>(value: &i64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&scaled_to_string(*value, DOLLAR_SCALE))
+}
+
+pub fn serialize_count<S>(value: &i64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&scaled_to_string(*value, COUNT_SCALE))
+}
+
 pub fn serialize_optional_dollars<S>(value: &Option<i64>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -183,6 +199,30 @@ where
     })
 }
 
+/// Parse a `[price_dollars, count_fp]` orderbook row into scaled integers.
+pub(crate) fn parse_level(level: &[String]) -> Result<[i64; 2], String> {
+    match level {
+        [price, count] => Ok([
+            parse_decimal_to_scaled(price, DOLLAR_SCALE)?,
+            parse_decimal_to_scaled(count, COUNT_SCALE)?,
+        ]),
+        _ => Err(format!(
+            "orderbook level must have 2 elements, got {}",
+            level.len()
+        )),
+    }
+}
+
+pub(crate) fn deserialize_levels<'de, D>(deserializer: D) -> Result<Vec<[i64; 2]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let rows = Vec::<Vec<String>>::deserialize(deserializer)?;
+    rows.iter()
+        .map(|row| parse_level(row).map_err(serde::de::Error::custom))
+        .collect()
+}
+
 pub fn parse_dollars(value: &str) -> Result<i64, Error> {
     parse_decimal_to_scaled(value, DOLLAR_SCALE).map_err(Error::Config)
 }
@@ -191,6 +231,17 @@ pub fn parse_count(value: &str) -> Result<i64, Error> {
     parse_decimal_to_scaled(value, COUNT_SCALE).map_err(Error::Config)
 }
 
+/// Convert a JSON float to a scaled integer without binary rounding error.
+///
+/// Goes through the shortest decimal representation of the float, which is
+/// exactly what the API sent for values with at most `scale` fractional digits.
+pub(crate) fn float_to_scaled(value: f64, scale: i64) -> Option<i64> {
+    if !value.is_finite() {
+        return None;
+    }
+    parse_decimal_to_scaled(&value.to_string(), scale).ok()
+}
+
 pub fn format_dollars(value: i64) -> String {
     scaled_to_string(value, DOLLAR_SCALE)
 }
@@ -199,6 +250,48 @@ pub fn format_count(value: i64) -> String {
     scaled_to_string(value, COUNT_SCALE)
 }
 
+/// Conversions between [`Price`] (ten-thousandths of a dollar) and
+/// floating-point units.
+///
+/// `Price` is an alias for `i64`, so these methods are available on any
+/// `i64`; only call them on prices.
+///
+/// ```rust
+/// use kalshi_trading::types::{Price, PriceExt};
+///
+/// let price: Price = 5_050;
+/// assert_eq!(price.to_cents(), 50.5);
+/// assert_eq!(Price::from_dollars(0.505), price);
+/// ```
+pub trait PriceExt {
+    /// Price in dollars, e.g. `5_050` -> `0.505`
+    fn to_dollars(self) -> f64;
+    /// Price in cents, e.g. `5_050` -> `50.5`
+    fn to_cents(self) -> f64;
+    /// Implied probability from 0.0 to 1.0, since a contract pays $1
+    fn to_probability(self) -> f64;
+    /// Price from dollars, rounded to the nearest ten-thousandth
+    fn from_dollars(dollars: f64) -> Self;
+}
+
+impl PriceExt for Price {
+    fn to_dollars(self) -> f64 {
+        self as f64 / DOLLAR_SCALE as f64
+    }
+
+    fn to_cents(self) -> f64 {
+        self as f64 / (DOLLAR_SCALE / 100) as f64
+    }
+
+    fn to_probability(self) -> f64 {
+        self.to_dollars()
+    }
+
+    fn from_dollars(dollars: f64) -> Self {
+        (dollars * DOLLAR_SCALE as f64).round() as Price
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,6 +308,28 @@ mod tests {
         assert_eq!(parse_count("3").unwrap(), 300);
     }
 
+    #[test]
+    fn converts_floats_exactly() {
+        assert_eq!(float_to_scaled(50_000.0, DOLLAR_SCALE), Some(500_000_000));
+        assert_eq!(float_to_scaled(0.1 + 0.2, DOLLAR_SCALE), None);
+        assert_eq!(float_to_scaled(97_999.99, DOLLAR_SCALE), Some(979_999_900));
+        assert_eq!(float_to_scaled(f64::NAN, DOLLAR_SCALE), None);
+    }
+
+    #[test]
+    fn converts_prices() {
+        assert_eq!(100.to_dollars(), 0.01);
+        assert_eq!(100.to_cents(), 1.0);
+        assert_eq!(5_050.to_cents(), 50.5);
+        assert_eq!(5_050.to_probability(), 0.505);
+        assert_eq!(9_900.to_dollars(), 0.99);
+
+        for price in [100, 5_050, 9_900] {
+            assert_eq!(Price::from_dollars(price.to_dollars()), price);
+        }
+        assert_eq!(Price::from_dollars(0.12345), 1_235);
+    }
+
     #[test]
     fn formats_scaled_values() {
         assert_eq!(format_dollars(5_600), "0.5600");
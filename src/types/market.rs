@@ -2,11 +2,23 @@
 
 //! Market and portfolio types.
 
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::error::Error;
+use crate::types::messages::FillData;
+use crate::types::order::{Action, CreateOrderRequest, Side};
+#[cfg(feature = "chrono")]
+use crate::types::parse_utc;
 use crate::types::{
-    deserialize_count, deserialize_dollars, deserialize_optional_count,
-    deserialize_optional_dollars,
+    deserialize_count, deserialize_dollars, deserialize_levels, deserialize_optional_count,
+    deserialize_optional_dollars, float_to_scaled, serialize_count, serialize_dollars,
+    serialize_optional_count, serialize_optional_dollars, ParseEnumError, Price, Quantity,
+    COUNT_SCALE, DOLLAR_SCALE,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -23,6 +35,101 @@ pub enum MarketStatus {
     Finalized,
 }
 
+impl MarketStatus {
+    /// Wire name, as used by serde.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Initialized => "initialized",
+            Self::Inactive => "inactive",
+            Self::Active => "active",
+            Self::Closed => "closed",
+            Self::Determined => "determined",
+            Self::Disputed => "disputed",
+            Self::Amended => "amended",
+            Self::Finalized => "finalized",
+        }
+    }
+}
+
+impl fmt::Display for MarketStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MarketStatus {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "initialized" => Ok(Self::Initialized),
+            "inactive" => Ok(Self::Inactive),
+            "active" => Ok(Self::Active),
+            "closed" => Ok(Self::Closed),
+            "determined" => Ok(Self::Determined),
+            "disputed" => Ok(Self::Disputed),
+            "amended" => Ok(Self::Amended),
+            "finalized" => Ok(Self::Finalized),
+            _ => Err(ParseEnumError::new("market status", s)),
+        }
+    }
+}
+
+/// Status filter for `RestClient::get_markets_filtered`.
+///
+/// The list endpoint filters on these coarser states rather than
+/// [`MarketStatus`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum MarketStatusFilter {
+    Unopened,
+    Open,
+    Closed,
+    Settled,
+    /// No status filter
+    #[default]
+    All,
+}
+
+impl MarketStatusFilter {
+    /// Value for the `status` query parameter, or `None` for [`Self::All`].
+    #[must_use]
+    pub const fn as_query(self) -> Option<&'static str> {
+        match self {
+            Self::Unopened => Some("unopened"),
+            Self::Open => Some("open"),
+            Self::Closed => Some("closed"),
+            Self::Settled => Some("settled"),
+            Self::All => None,
+        }
+    }
+}
+
+/// Settlement filter for `RestClient::get_positions_filtered`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum SettlementStatus {
+    Settled,
+    Unsettled,
+    /// No settlement filter
+    #[default]
+    All,
+}
+
+impl SettlementStatus {
+    /// Value for the `settlement_status` query parameter, or `None` for
+    /// [`Self::All`].
+    #[must_use]
+    pub const fn as_query(self) -> Option<&'static str> {
+        match self {
+            Self::Settled => Some("settled"),
+            Self::Unsettled => Some("unsettled"),
+            Self::All => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
@@ -47,17 +154,26 @@ fn deserialize_optional_settlement<'de, D>(
 where
     D: serde::Deserializer<'de>,
 {
-    use serde::de::IntoDeserializer;
-
     let opt: Option<String> = Option::deserialize(deserializer)?;
     match opt {
         None => Ok(None),
-        Some(s) if s.is_empty() => Ok(None),
-        Some(s) => SettlementResult::deserialize(s.into_deserializer()).map(Some),
+        Some(s) => parse_settlement_result(&s).map_err(serde::de::Error::custom),
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// Parse a settlement outcome, treating an empty string as unsettled.
+pub(super) fn parse_settlement_result(
+    s: &str,
+) -> Result<Option<SettlementResult>, serde::de::value::Error> {
+    use serde::de::IntoDeserializer;
+
+    if s.is_empty() {
+        return Ok(None);
+    }
+    SettlementResult::deserialize(s.into_deserializer()).map(Some)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Market {
     pub ticker: String,
     pub event_ticker: String,
@@ -80,35 +196,92 @@ pub struct Market {
     pub series_ticker: Option<String>,
     #[serde(default)]
     pub response_price_units: Option<String>,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub notional_value_dollars: i64,
-    #[serde(deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub yes_bid_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_count",
+        serialize_with = "serialize_optional_count"
+    )]
     pub yes_bid_size_fp: Option<i64>,
-    #[serde(deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub yes_ask_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_count",
+        serialize_with = "serialize_optional_count"
+    )]
     pub yes_ask_size_fp: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub no_bid_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub no_ask_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub last_price_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub previous_yes_bid_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub previous_yes_ask_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub previous_price_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_count",
+        serialize_with = "serialize_optional_count"
+    )]
     pub volume_fp: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_count",
+        serialize_with = "serialize_optional_count"
+    )]
     pub volume_24h_fp: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_dollars",
+        serialize_with = "serialize_optional_dollars"
+    )]
     pub liquidity_dollars: Option<i64>,
-    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_count",
+        serialize_with = "serialize_optional_count"
+    )]
     pub open_interest_fp: Option<i64>,
     #[serde(default, deserialize_with = "deserialize_optional_settlement")]
     pub result: Option<SettlementResult>,
@@ -127,6 +300,10 @@ pub struct Market {
     pub cap_strike: Option<f64>,
     #[serde(default)]
     pub category: Option<String>,
+    #[serde(default)]
+    pub risk_limit_cents: Option<i64>,
+    #[serde(default)]
+    pub maker_fee_bps: Option<i64>,
 }
 
 impl Market {
@@ -146,10 +323,148 @@ impl Market {
         }
     }
 
+    /// Mid price as a probability from 0.0 to 1.0.
+    ///
+    /// Falls back to `last_price_dollars` when the bid or ask is missing.
+    #[must_use]
+    pub fn implied_probability(&self) -> Option<f64> {
+        self.reference_price()
+            .map(|price| (price as f64 / DOLLAR_SCALE as f64).clamp(0.0, 1.0))
+    }
+
+    /// `(yes, no)` prices from the mid, where `no = $1 - yes`.
+    ///
+    /// Falls back to `last_price_dollars` when the bid or ask is missing.
+    #[must_use]
+    pub fn yes_no_prices(&self) -> Option<(Price, Price)> {
+        self.reference_price()
+            .map(|yes| yes.clamp(0, DOLLAR_SCALE))
+            .map(|yes| (yes, DOLLAR_SCALE - yes))
+    }
+
+    fn reference_price(&self) -> Option<Price> {
+        self.mid_price().or(self.last_price_dollars)
+    }
+
     #[must_use]
     pub const fn is_tradeable(&self) -> bool {
         matches!(self.status, MarketStatus::Active)
     }
+
+    /// `created_time` parsed as RFC 3339, or `None` if empty or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn created_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.created_time)
+    }
+
+    /// `updated_time` parsed as RFC 3339, or `None` if empty or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn updated_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.updated_time)
+    }
+
+    /// `open_time` parsed as RFC 3339, or `None` if empty or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn open_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.open_time)
+    }
+
+    /// `close_time` parsed as RFC 3339, or `None` if empty or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn close_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.close_time)
+    }
+
+    /// `expiration_time` parsed as RFC 3339, or `None` if empty or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn expiration_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.expiration_time)
+    }
+
+    /// `latest_expiration_time` parsed as RFC 3339, or `None` if empty or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn latest_expiration_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.latest_expiration_time)
+    }
+
+    /// `expected_expiration_time` parsed as RFC 3339, or `None` if unset or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn expected_expiration_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.expected_expiration_time.as_deref().and_then(parse_utc)
+    }
+
+    /// Floor strike scaled by 10,000 (four decimal places), e.g. `50000.0` -> `500_000_000`.
+    ///
+    /// Returns `None` if unset or if the strike has more than four decimal places.
+    #[must_use]
+    pub fn floor_strike_scaled(&self) -> Option<i64> {
+        self.floor_strike
+            .and_then(|strike| float_to_scaled(strike, DOLLAR_SCALE))
+    }
+
+    /// Cap strike scaled by 10,000 (four decimal places), e.g. `50000.0` -> `500_000_000`.
+    ///
+    /// Returns `None` if unset or if the strike has more than four decimal places.
+    #[must_use]
+    pub fn cap_strike_scaled(&self) -> Option<i64> {
+        self.cap_strike
+            .and_then(|strike| float_to_scaled(strike, DOLLAR_SCALE))
+    }
+
+    /// Spread captured by a round trip (buy at `bid`, sell at `ask`) net of
+    /// maker fees on both legs, in centi-cents per contract.
+    ///
+    /// Each leg's fee is `price * maker_fee_bps / 10_000`, rounded up.
+    /// Negative means the spread does not cover fees. Returns `None` if
+    /// `maker_fee_bps` is unknown.
+    #[must_use]
+    pub fn net_edge(&self, bid: Price, ask: Price) -> Option<i64> {
+        let bps = self.maker_fee_bps?;
+        let fee = |price: Price| (price * bps + 9_999).div_euclid(10_000);
+        Some(ask - bid - fee(bid) - fee(ask))
+    }
+
+    /// Cents of exposure still allowed under `risk_limit_cents`.
+    ///
+    /// Returns `None` if the market has no risk limit (missing or zero).
+    /// Negative when the limit is already exceeded.
+    #[must_use]
+    pub fn risk_limit_remaining(&self, current_exposure_cents: i64) -> Option<i64> {
+        self.risk_limit_cents
+            .filter(|&limit| limit > 0)
+            .map(|limit| limit - current_exposure_cents)
+    }
+
+    /// Check that `order` fits within the market's risk limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the order's worst-case cost
+    /// ([`CreateOrderRequest::max_cost_cents`]) exceeds the remaining limit.
+    pub fn check_risk_limit(
+        &self,
+        order: &CreateOrderRequest,
+        current_exposure_cents: i64,
+    ) -> Result<(), Error> {
+        let Some(remaining) = self.risk_limit_remaining(current_exposure_cents) else {
+            return Ok(());
+        };
+        let added = order.max_cost_cents();
+        if added > remaining {
+            return Err(Error::InvalidOrder(format!(
+                "order adds {} cents of exposure but only {} cents remain under the risk limit for {}",
+                added, remaining, self.ticker
+            )));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -167,6 +482,8 @@ pub struct Event {
     pub mutually_exclusive: bool,
     #[serde(default)]
     pub strike_date: Option<String>,
+    /// Only populated when the API nests markets; see
+    /// `RestClient::get_events_lightweight`.
     #[serde(default)]
     pub markets: Vec<Market>,
 }
@@ -193,13 +510,13 @@ pub struct SettlementSource {
     pub name: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetMarketsResponse {
     pub markets: Vec<Market>,
     pub cursor: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetMarketResponse {
     pub market: Market,
 }
@@ -241,42 +558,218 @@ pub struct GetBalanceResponse {
     pub portfolio_value: i64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Position {
     pub ticker: String,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub total_traded_dollars: i64,
-    #[serde(deserialize_with = "deserialize_count")]
+    #[serde(
+        deserialize_with = "deserialize_count",
+        serialize_with = "serialize_count"
+    )]
     pub position_fp: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub market_exposure_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub realized_pnl_dollars: i64,
     pub resting_orders_count: i32,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub fees_paid_dollars: i64,
     #[serde(default)]
     pub last_updated_ts: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// Average-cost accounting for one trade in yes-contract terms.
+///
+/// `cost_basis` is the sum of `count_fp * price` over the open position.
+/// Returns the new position, new cost basis, and P&L realized by the trade
+/// (in `count_fp * price` units). A trade that crosses zero closes the old
+/// position and opens the remainder at `price`.
+pub(crate) fn average_cost_trade(
+    position_fp: Quantity,
+    cost_basis: i64,
+    delta: Quantity,
+    price: Price,
+) -> (Quantity, i64, i64) {
+    if delta == 0 {
+        return (position_fp, cost_basis, 0);
+    }
+
+    let same_direction = position_fp == 0 || (position_fp > 0) == (delta > 0);
+    if same_direction {
+        return (position_fp + delta, cost_basis + delta.abs() * price, 0);
+    }
+
+    let open = position_fp.abs();
+    let closed = delta.abs().min(open);
+    let closed_cost = cost_basis * closed / open;
+    let pnl = closed * price - closed_cost;
+    // Closing a long sells at `price`; closing a short buys at it
+    let realized = if position_fp > 0 { pnl } else { -pnl };
+
+    // Any remainder opens a new position on the other side
+    let opened = delta.abs() - closed;
+    let cost_basis = if opened > 0 {
+        opened * price
+    } else {
+        cost_basis - closed_cost
+    };
+    (position_fp + delta, cost_basis, realized)
+}
+
+impl Position {
+    /// Position after applying `fill`, using average-cost accounting.
+    ///
+    /// `market_exposure_dollars` is treated as the cost of the open position
+    /// on its own side (yes or no). Adding to a position raises the cost;
+    /// reducing it realizes P&L against the average entry, and a fill that
+    /// crosses zero flips the position with the remainder at the fill price.
+    /// Fees and traded value are accumulated too.
+    ///
+    /// Fills for another ticker, or with an unrecognized `side`, leave the
+    /// position unchanged.
+    #[must_use]
+    pub fn with_fill(&self, fill: &Fill) -> Position {
+        let mut next = self.clone();
+        if fill.market_ticker != self.ticker {
+            return next;
+        }
+        let Ok(side) = fill.side.parse::<Side>() else {
+            return next;
+        };
+        let buys_yes = match fill.action {
+            Action::Buy => side == Side::Yes,
+            Action::Sell => side == Side::No,
+        };
+
+        // Work in yes-price terms (`count_fp * price` units): a no position's
+        // cost is the complement of its yes cost, in either direction.
+        let flip = |position_fp: i64, cost: i64| {
+            if position_fp >= 0 {
+                cost
+            } else {
+                position_fp.abs() * DOLLAR_SCALE - cost
+            }
+        };
+        let delta = if buys_yes {
+            fill.count_fp
+        } else {
+            -fill.count_fp
+        };
+        let (position_fp, cost_basis, realized) = average_cost_trade(
+            self.position_fp,
+            flip(self.position_fp, self.market_exposure_dollars * COUNT_SCALE),
+            delta,
+            fill.yes_price_dollars,
+        );
+
+        next.position_fp = position_fp;
+        next.market_exposure_dollars = flip(position_fp, cost_basis) / COUNT_SCALE;
+        next.realized_pnl_dollars += realized / COUNT_SCALE;
+        next.fees_paid_dollars += fill.fee_cost;
+        let paid = if side == Side::Yes {
+            fill.yes_price_dollars
+        } else {
+            fill.no_price_dollars
+        };
+        next.total_traded_dollars += fill.count_fp * paid / COUNT_SCALE;
+        next
+    }
+
+    /// Value of the open position at a yes price, in centi-cents.
+    ///
+    /// `current_price` is the yes price in centi-cents (`10_000` = $1). A
+    /// long (yes) position is worth `contracts * current_price`; a short (no)
+    /// position is worth `contracts * (10_000 - current_price)`. Flat is 0.
+    #[must_use]
+    pub fn market_value(&self, current_price: Price) -> i64 {
+        let price = if self.position_fp >= 0 {
+            current_price
+        } else {
+            DOLLAR_SCALE - current_price
+        };
+        self.position_fp.abs() * price / COUNT_SCALE
+    }
+
+    /// Mark-to-market P&L of the open position, in centi-cents.
+    ///
+    /// [`Self::market_value`] at the yes price `current_price_centicents`,
+    /// less the position's cost (`market_exposure_dollars`). Excludes
+    /// realized P&L and fees. Flat positions return 0.
+    #[must_use]
+    pub fn unrealized_pnl(&self, current_price_centicents: Price) -> i64 {
+        if self.position_fp == 0 {
+            return 0;
+        }
+        self.market_value(current_price_centicents) - self.market_exposure_dollars
+    }
+
+    /// Yes probability at which the open position breaks even, from 0.0 to 1.0.
+    ///
+    /// A long (yes) position breaks even when yes is as likely as its
+    /// average cost per contract; a short (no) position when no is, i.e. at
+    /// `1 - avg_no_cost`. Uses `market_exposure_dollars` as the cost basis
+    /// and excludes fees. Returns `None` for a flat position.
+    #[must_use]
+    pub fn break_even_probability(&self) -> Option<f64> {
+        if self.position_fp == 0 {
+            return None;
+        }
+        let contracts = self.position_fp.abs() as f64 / COUNT_SCALE as f64;
+        let avg_cost = self.market_exposure_dollars as f64 / contracts / DOLLAR_SCALE as f64;
+        Some(if self.position_fp > 0 {
+            avg_cost
+        } else {
+            1.0 - avg_cost
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EventPosition {
     pub event_ticker: String,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub total_cost_dollars: i64,
-    #[serde(deserialize_with = "deserialize_count")]
+    #[serde(
+        deserialize_with = "deserialize_count",
+        serialize_with = "serialize_count"
+    )]
     pub total_cost_shares_fp: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub event_exposure_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub realized_pnl_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub fees_paid_dollars: i64,
     #[serde(default)]
     pub resting_orders_count: i64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetPositionsResponse {
     #[serde(default)]
     pub market_positions: Vec<Position>,
@@ -286,30 +779,132 @@ pub struct GetPositionsResponse {
     pub event_positions: Vec<EventPosition>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Trade {
     pub trade_id: String,
     pub ticker: String,
     #[serde(default)]
     pub price: Option<i64>,
-    #[serde(deserialize_with = "deserialize_count")]
+    #[serde(
+        deserialize_with = "deserialize_count",
+        serialize_with = "serialize_count"
+    )]
     pub count_fp: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub yes_price_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub no_price_dollars: i64,
-    pub taker_side: String,
+    #[serde(default)]
+    pub taker_side: Option<Side>,
     #[serde(default)]
     pub created_time: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl Trade {
+    /// `created_time` parsed as RFC 3339, or `None` if unset or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn created_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.created_time.as_deref().and_then(parse_utc)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetTradesResponse {
     pub trades: Vec<Trade>,
     pub cursor: Option<String>,
 }
 
+/// One OHLC period from the candlesticks endpoint.
+///
+/// Prices are trade prices in ten-thousandths of a dollar; each is `None`
+/// when nothing traded during the period.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RawCandlestick")]
+pub struct Candlestick {
+    /// End of the period, Unix seconds.
+    pub end_period_ts: i64,
+    pub open: Option<Price>,
+    pub high: Option<Price>,
+    pub low: Option<Price>,
+    pub close: Option<Price>,
+    pub volume_fp: i64,
+    pub open_interest_fp: i64,
+}
+
+/// Wire shape of [`Candlestick`], which nests prices and sends both integer
+/// and fixed-point variants of most fields.
+#[derive(Deserialize)]
+struct RawCandlestick {
+    end_period_ts: i64,
+    #[serde(default)]
+    price: Option<RawCandlestickPrice>,
+    #[serde(default)]
+    volume: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    volume_fp: Option<i64>,
+    #[serde(default)]
+    open_interest: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    open_interest_fp: Option<i64>,
+}
+
+/// Integer fields are cents, which `deserialize_optional_dollars` scales.
+#[derive(Default, Deserialize)]
+struct RawCandlestickPrice {
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    open: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    open_dollars: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    high: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    high_dollars: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    low: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    low_dollars: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    close: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    close_dollars: Option<i64>,
+}
+
+impl From<RawCandlestick> for Candlestick {
+    fn from(raw: RawCandlestick) -> Self {
+        let price = raw.price.unwrap_or_default();
+        Self {
+            end_period_ts: raw.end_period_ts,
+            open: price.open_dollars.or(price.open),
+            high: price.high_dollars.or(price.high),
+            low: price.low_dollars.or(price.low),
+            close: price.close_dollars.or(price.close),
+            volume_fp: raw
+                .volume_fp
+                .or(raw.volume.map(|v| v * COUNT_SCALE))
+                .unwrap_or(0),
+            open_interest_fp: raw
+                .open_interest_fp
+                .or(raw.open_interest.map(|v| v * COUNT_SCALE))
+                .unwrap_or(0),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
+pub struct GetCandlesticksResponse {
+    #[serde(default)]
+    pub ticker: Option<String>,
+    pub candlesticks: Vec<Candlestick>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Fill {
     pub fill_id: String,
     pub trade_id: String,
@@ -319,17 +914,29 @@ pub struct Fill {
     pub ticker: String,
     pub market_ticker: String,
     pub side: String,
-    pub action: String,
-    #[serde(deserialize_with = "deserialize_count")]
+    pub action: Action,
+    #[serde(
+        deserialize_with = "deserialize_count",
+        serialize_with = "serialize_count"
+    )]
     pub count_fp: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub yes_price_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub no_price_dollars: i64,
     pub is_taker: bool,
     #[serde(default)]
     pub created_time: Option<String>,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub fee_cost: i64,
     #[serde(default)]
     pub subaccount_number: Option<i32>,
@@ -337,7 +944,43 @@ pub struct Fill {
     pub ts: Option<i64>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl Fill {
+    /// `created_time` parsed as RFC 3339, or `None` if unset or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn created_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.created_time.as_deref().and_then(parse_utc)
+    }
+}
+
+impl From<FillData> for Fill {
+    /// Convert a WebSocket fill into the REST shape.
+    ///
+    /// The WebSocket message has no separate fill ID, so `fill_id` reuses
+    /// `trade_id`; `no_price_dollars` is derived as `$1 - yes_price_dollars`.
+    fn from(fill: FillData) -> Self {
+        Self {
+            fill_id: fill.trade_id.clone(),
+            trade_id: fill.trade_id,
+            order_id: fill.order_id,
+            client_order_id: fill.client_order_id,
+            ticker: fill.market_ticker.clone(),
+            market_ticker: fill.market_ticker,
+            side: fill.side.to_string(),
+            action: fill.action,
+            count_fp: fill.count_fp,
+            yes_price_dollars: fill.yes_price_dollars,
+            no_price_dollars: DOLLAR_SCALE - fill.yes_price_dollars,
+            is_taker: fill.is_taker,
+            created_time: None,
+            fee_cost: fill.fee_cost,
+            subaccount_number: fill.subaccount,
+            ts: Some(fill.ts),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetFillsResponse {
     pub fills: Vec<Fill>,
     pub cursor: Option<String>,
@@ -366,6 +1009,21 @@ pub struct Settlement {
     pub value: Option<i64>,
 }
 
+impl Settlement {
+    /// Typed `market_result`; `None` if empty or unrecognized.
+    #[must_use]
+    pub fn result_enum(&self) -> Option<SettlementResult> {
+        parse_settlement_result(&self.market_result).ok().flatten()
+    }
+
+    /// `settled_time` parsed as RFC 3339, or `None` if empty or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn settled_time_utc(&self) -> Option<DateTime<Utc>> {
+        parse_utc(&self.settled_time)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct GetSettlementsResponse {
     pub settlements: Vec<Settlement>,
@@ -382,10 +1040,12 @@ pub struct OrderbookLevel {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Orderbook {
-    #[serde(default)]
-    pub yes_dollars: Vec<[String; 2]>,
-    #[serde(default)]
-    pub no_dollars: Vec<[String; 2]>,
+    /// Yes bids as `[price, count_fp]`, scaled like the other fixed-point fields.
+    #[serde(default, deserialize_with = "deserialize_levels")]
+    pub yes_dollars: Vec<[i64; 2]>,
+    /// No bids as `[price, count_fp]`, scaled like the other fixed-point fields.
+    #[serde(default, deserialize_with = "deserialize_levels")]
+    pub no_dollars: Vec<[i64; 2]>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -397,6 +1057,58 @@ pub struct GetOrderbookResponse {
 pub struct ExchangeStatus {
     pub trading_active: bool,
     pub exchange_active: bool,
+    /// When a paused exchange expects to resume, RFC 3339
+    #[serde(default)]
+    pub exchange_estimated_resume_time: Option<String>,
+    #[serde(default)]
+    pub announcements: Vec<Announcement>,
+}
+
+impl ExchangeStatus {
+    /// Whether orders can be placed: the exchange is up and trading.
+    #[must_use]
+    pub fn is_trading_open(&self) -> bool {
+        self.trading_active && self.exchange_active
+    }
+
+    /// `exchange_estimated_resume_time` parsed as RFC 3339, or `None` if
+    /// absent or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn estimated_resume_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.exchange_estimated_resume_time
+            .as_deref()
+            .and_then(parse_utc)
+    }
+}
+
+/// An exchange-wide notice, such as scheduled maintenance.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Announcement {
+    /// `info`, `warning` or `error`
+    #[serde(rename = "type", default)]
+    pub announcement_type: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub delivery_time: String,
+    /// `active` or `inactive`
+    #[serde(default)]
+    pub status: String,
+}
+
+impl Announcement {
+    /// Whether the announcement is currently in effect.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetExchangeAnnouncementsResponse {
+    #[serde(default)]
+    pub announcements: Vec<Announcement>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -439,9 +1151,8 @@ pub struct GetExchangeScheduleResponse {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_market_mid_price() {
-        let market = Market {
+    fn test_market() -> Market {
+        Market {
             ticker: "TEST".to_string(),
             event_ticker: "TEST-EVENT".to_string(),
             market_type: MarketType::Binary,
@@ -486,16 +1197,436 @@ mod tests {
             floor_strike: None,
             cap_strike: None,
             category: None,
-        };
+            risk_limit_cents: None,
+            maker_fee_bps: None,
+        }
+    }
+
+    #[test]
+    fn test_market_status_display_round_trip() {
+        for status in [MarketStatus::Active, MarketStatus::Finalized] {
+            let text = status.to_string();
+            assert_eq!(
+                serde_json::to_string(&status).unwrap(),
+                format!("\"{}\"", text)
+            );
+            assert_eq!(text.parse::<MarketStatus>(), Ok(status));
+        }
+        assert_eq!("determined".parse(), Ok(MarketStatus::Determined));
+        assert_eq!(
+            "settled".parse::<MarketStatus>().unwrap_err().value(),
+            "settled"
+        );
+    }
 
+    #[test]
+    fn test_market_mid_price() {
+        let market = test_market();
         assert_eq!(market.mid_price(), Some(5_000));
         assert_eq!(market.spread(), Some(1_000));
         assert!(market.is_tradeable());
     }
 
+    #[test]
+    fn test_implied_probability() {
+        let mut market = test_market();
+        market.yes_bid_dollars = Some(6_000);
+        market.yes_ask_dollars = Some(6_400);
+        assert_eq!(market.implied_probability(), Some(0.62));
+        assert_eq!(market.yes_no_prices(), Some((6_200, 3_800)));
+
+        // One-sided quotes fall back to the last trade
+        market.yes_ask_dollars = None;
+        assert_eq!(market.implied_probability(), Some(0.5));
+        assert_eq!(market.yes_no_prices(), Some((5_000, 5_000)));
+        market.yes_bid_dollars = None;
+        market.yes_ask_dollars = Some(6_400);
+        assert_eq!(market.yes_no_prices(), Some((5_000, 5_000)));
+
+        market.yes_ask_dollars = None;
+        market.last_price_dollars = Some(9_900);
+        assert_eq!(market.yes_no_prices(), Some((9_900, 100)));
+
+        market.last_price_dollars = None;
+        assert_eq!(market.implied_probability(), None);
+        assert_eq!(market.yes_no_prices(), None);
+    }
+
+    #[test]
+    fn test_market_serde_round_trip() {
+        let mut market = test_market();
+        market.result = Some(SettlementResult::Yes);
+
+        let json = serde_json::to_value(&market).unwrap();
+        assert_eq!(json["yes_bid_dollars"], "0.4500");
+        assert_eq!(json["volume_fp"], "100.00");
+        assert_eq!(json["result"], "yes");
+
+        let parsed: Market = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed.yes_bid_dollars, Some(4_500));
+        assert_eq!(parsed.notional_value_dollars, 10_000);
+        assert_eq!(parsed.result, Some(SettlementResult::Yes));
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_strikes_scaled_exactly() {
+        let json = r#"{
+            "ticker": "KXBTC-25JAN-B50000",
+            "event_ticker": "KXBTC-25JAN",
+            "market_type": "binary",
+            "title": "Bitcoin range",
+            "subtitle": "",
+            "yes_sub_title": "",
+            "no_sub_title": "",
+            "status": "active",
+            "created_time": "",
+            "updated_time": "",
+            "open_time": "",
+            "close_time": "",
+            "expiration_time": "",
+            "latest_expiration_time": "",
+            "settlement_timer_seconds": 0,
+            "notional_value_dollars": "1.0000",
+            "yes_bid_dollars": "0.4500",
+            "yes_ask_dollars": "0.5500",
+            "can_close_early": false,
+            "fractional_trading_enabled": false,
+            "expiration_value": "",
+            "rules_primary": "",
+            "rules_secondary": "",
+            "floor_strike": 50000.0,
+            "cap_strike": 50249.99
+        }"#;
+        let market: Market = serde_json::from_str(json).unwrap();
+        assert_eq!(market.floor_strike_scaled(), Some(500_000_000));
+        assert_eq!(market.cap_strike_scaled(), Some(502_499_900));
+
+        let mut market = test_market();
+        market.floor_strike = Some(0.12345);
+        assert_eq!(market.floor_strike_scaled(), None);
+        assert_eq!(market.cap_strike_scaled(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "chrono")]
+    fn test_timestamps_utc() {
+        let mut market = test_market();
+        let open = market.open_time_utc().unwrap();
+        assert_eq!(open.to_rfc3339(), "2024-01-01T00:00:00+00:00");
+        assert_eq!(
+            market.close_time_utc().unwrap() - open,
+            chrono::Duration::days(1)
+        );
+        assert_eq!(market.expected_expiration_time_utc(), None);
+
+        // Offsets are normalized to UTC; malformed values are None
+        market.expected_expiration_time = Some("2024-01-02T05:30:00+05:30".to_string());
+        assert_eq!(
+            market.expected_expiration_time_utc(),
+            market.expiration_time_utc()
+        );
+        market.updated_time = "yesterday".to_string();
+        assert_eq!(market.updated_time_utc(), None);
+
+        let trade: Trade = serde_json::from_str(
+            r#"{"trade_id":"t1","ticker":"TEST","count_fp":"1.00",
+                "yes_price_dollars":"0.5000","no_price_dollars":"0.5000","taker_side":"yes",
+                "created_time":"2024-01-01T12:00:00.123456Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            trade.created_time_utc().unwrap().timestamp_millis(),
+            1_704_110_400_123
+        );
+    }
+
+    #[test]
+    fn test_trade_and_fill_typed_sides() {
+        let trades: GetTradesResponse = serde_json::from_str(
+            r#"{"cursor":"","trades":[
+                {"trade_id":"0b3c6a1e","ticker":"KXBTCD-25JAN0317-T97999.99","count_fp":"12.00",
+                 "yes_price_dollars":"0.6300","no_price_dollars":"0.3700","taker_side":"no",
+                 "created_time":"2025-01-03T16:21:07.148211Z"},
+                {"trade_id":"7d91f2c4","ticker":"KXBTCD-25JAN0317-T97999.99","count_fp":"1.00",
+                 "yes_price_dollars":"0.6400","no_price_dollars":"0.3600",
+                 "created_time":"2025-01-03T16:21:05.001000Z"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(trades.trades[0].taker_side, Some(Side::No));
+        assert_eq!(trades.trades[1].taker_side, None);
+
+        let fills: GetFillsResponse = serde_json::from_str(
+            r#"{"cursor":"","fills":[
+                {"fill_id":"f8a3","trade_id":"0b3c6a1e","order_id":"ee21","client_order_id":"c-1",
+                 "ticker":"KXBTCD-25JAN0317-T97999.99","market_ticker":"KXBTCD-25JAN0317-T97999.99",
+                 "side":"yes","action":"sell","count_fp":"12.00","yes_price_dollars":"0.6300",
+                 "no_price_dollars":"0.3700","is_taker":false,"fee_cost":"0.0000",
+                 "created_time":"2025-01-03T16:21:07.148211Z","ts":1735921267}]}"#,
+        )
+        .unwrap();
+        assert_eq!(fills.fills[0].action, Action::Sell);
+
+        // Values are serialized back in wire form
+        let json = serde_json::to_value(&fills.fills[0]).unwrap();
+        assert_eq!(json["action"], "sell");
+        let json = serde_json::to_value(&trades.trades[0]).unwrap();
+        assert_eq!(json["taker_side"], "no");
+
+        let bad = r#"{"trade_id":"t","ticker":"T","count_fp":"1.00","yes_price_dollars":"0.5000",
+            "no_price_dollars":"0.5000","taker_side":"maybe"}"#;
+        assert!(serde_json::from_str::<Trade>(bad).is_err());
+    }
+
     #[test]
     fn test_market_status_serde() {
         let json = serde_json::to_string(&MarketStatus::Active).unwrap();
         assert_eq!(json, "\"active\"");
     }
+
+    fn settlement_with_result(result: &str) -> Settlement {
+        serde_json::from_str(&format!(
+            r#"{{
+                "ticker": "TEST", "event_ticker": "EVT", "market_result": "{}",
+                "yes_count_fp": "1.00", "yes_total_cost": 50, "yes_total_cost_dollars": "0.5000",
+                "no_count_fp": "0.00", "no_total_cost": 0, "no_total_cost_dollars": "0.0000",
+                "revenue": 100, "settled_time": "2025-01-01T00:00:00Z", "fee_cost": "0.0100"
+            }}"#,
+            result
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_settlement_result_enum() {
+        assert_eq!(
+            settlement_with_result("yes").result_enum(),
+            Some(SettlementResult::Yes)
+        );
+        assert_eq!(
+            settlement_with_result("no").result_enum(),
+            Some(SettlementResult::No)
+        );
+        assert_eq!(settlement_with_result("").result_enum(), None);
+    }
+
+    #[test]
+    fn test_risk_limit() {
+        use crate::types::order::{Action, Side};
+
+        let mut market = test_market();
+        assert_eq!(market.risk_limit_remaining(500), None);
+
+        market.risk_limit_cents = Some(1_000);
+        assert_eq!(market.risk_limit_remaining(400), Some(600));
+
+        // 10 contracts at $0.60 = 600 cents: exactly at the limit
+        let order = CreateOrderRequest::limit("TEST", Side::Yes, Action::Buy, 10, 6_000);
+        assert_eq!(order.max_cost_cents(), 600);
+        assert!(market.check_risk_limit(&order, 400).is_ok());
+
+        // One cent beyond the limit
+        assert!(matches!(
+            market.check_risk_limit(&order, 401),
+            Err(Error::InvalidOrder(_))
+        ));
+
+        // Selling yes at $0.60 buys no at $0.40
+        let sell = CreateOrderRequest::limit("TEST", Side::Yes, Action::Sell, 10, 6_000);
+        assert_eq!(sell.max_cost_cents(), 400);
+        assert!(market.check_risk_limit(&sell, 600).is_ok());
+    }
+
+    #[test]
+    fn test_net_edge() {
+        let mut market = test_market();
+        assert_eq!(market.net_edge(4_950, 5_000), None);
+
+        market.maker_fee_bps = Some(175);
+
+        // Tight: fees of 87 + 88 swamp a 50 centi-cent spread
+        assert_eq!(market.net_edge(4_950, 5_000), Some(-125));
+
+        // Wide: fees of 79 + 97 on a 1,000 centi-cent spread
+        assert_eq!(market.net_edge(4_500, 5_500), Some(824));
+    }
+
+    #[test]
+    fn test_exchange_status_payloads() {
+        // Older responses carry only the two flags
+        let minimal: ExchangeStatus =
+            serde_json::from_str(r#"{"trading_active":true,"exchange_active":true}"#).unwrap();
+        assert!(minimal.is_trading_open());
+        assert_eq!(minimal.exchange_estimated_resume_time, None);
+        assert!(minimal.announcements.is_empty());
+
+        let paused: ExchangeStatus = serde_json::from_str(
+            r#"{
+                "trading_active": false, "exchange_active": true,
+                "exchange_estimated_resume_time": "2026-01-05T14:00:00Z",
+                "announcements": [{
+                    "type": "warning", "message": "Scheduled maintenance",
+                    "delivery_time": "2026-01-05T12:00:00Z", "status": "active"
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert!(!paused.is_trading_open());
+        #[cfg(feature = "chrono")]
+        assert_eq!(
+            paused.estimated_resume_time_utc().map(|t| t.to_rfc3339()),
+            Some("2026-01-05T14:00:00+00:00".to_string())
+        );
+        assert_eq!(paused.announcements[0].announcement_type, "warning");
+        assert!(paused.announcements[0].is_active());
+    }
+
+    #[test]
+    fn test_orderbook_levels() {
+        let json = r#"{"orderbook_fp":{
+            "yes_dollars":[["0.4500","12.00"]],
+            "no_dollars":[["0.5000","3.50"]]
+        }}"#;
+        let response: GetOrderbookResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(response.orderbook_fp.yes_dollars, vec![[4_500, 1_200]]);
+        assert_eq!(response.orderbook_fp.no_dollars, vec![[5_000, 350]]);
+
+        let malformed = r#"{"orderbook_fp":{"yes_dollars":[["0.4500"]],"no_dollars":[]}}"#;
+        let err = serde_json::from_str::<GetOrderbookResponse>(malformed).unwrap_err();
+        assert!(err.to_string().contains("2 elements"));
+    }
+
+    fn position(position_fp: i64, exposure: i64) -> Position {
+        Position {
+            ticker: "TEST".to_string(),
+            total_traded_dollars: 0,
+            position_fp,
+            market_exposure_dollars: exposure,
+            realized_pnl_dollars: 0,
+            resting_orders_count: 0,
+            fees_paid_dollars: 0,
+            last_updated_ts: None,
+        }
+    }
+
+    fn rest_fill(side: &str, action: &str, yes_price: i64, count_fp: i64) -> Fill {
+        Fill {
+            fill_id: "f".to_string(),
+            trade_id: "t".to_string(),
+            order_id: "o".to_string(),
+            client_order_id: None,
+            ticker: "TEST".to_string(),
+            market_ticker: "TEST".to_string(),
+            side: side.to_string(),
+            action: action.parse().unwrap(),
+            count_fp,
+            yes_price_dollars: yes_price,
+            no_price_dollars: DOLLAR_SCALE - yes_price,
+            is_taker: false,
+            created_time: None,
+            fee_cost: 10,
+            subaccount_number: None,
+            ts: None,
+        }
+    }
+
+    #[test]
+    fn test_position_unrealized_pnl() {
+        // Long 10 yes at $0.40, marked at $0.55
+        let long = position(1_000, 40_000);
+        assert_eq!(long.market_value(5_500), 55_000);
+        assert_eq!(long.unrealized_pnl(5_500), 15_000);
+        assert_eq!(long.unrealized_pnl(3_000), -10_000);
+
+        // Short 10 yes (long 10 no at $0.30), yes marked at $0.55
+        let short = position(-1_000, 30_000);
+        assert_eq!(short.market_value(5_500), 45_000);
+        assert_eq!(short.unrealized_pnl(5_500), 15_000);
+        assert_eq!(short.unrealized_pnl(8_000), -10_000);
+
+        let flat = position(0, 0);
+        assert_eq!(flat.market_value(5_500), 0);
+        assert_eq!(flat.unrealized_pnl(5_500), 0);
+    }
+
+    #[test]
+    fn test_position_break_even_probability() {
+        // Long 10 yes at an average of $0.40
+        let long = position(1_000, 40_000);
+        assert!((long.break_even_probability().unwrap() - 0.40).abs() < 1e-9);
+
+        // Long 4 no at an average of $0.25: yes must be below 75%
+        let short = position(-400, 10_000);
+        assert!((short.break_even_probability().unwrap() - 0.75).abs() < 1e-9);
+
+        assert_eq!(position(0, 0).break_even_probability(), None);
+    }
+
+    #[test]
+    fn test_position_with_fill_adds() {
+        // 10 yes at $0.40, buy 10 more at $0.60
+        let next = position(1_000, 40_000).with_fill(&rest_fill("yes", "buy", 6_000, 1_000));
+        assert_eq!(next.position_fp, 2_000);
+        assert_eq!(next.market_exposure_dollars, 100_000);
+        assert_eq!(next.realized_pnl_dollars, 0);
+        assert_eq!(next.total_traded_dollars, 60_000);
+        assert_eq!(next.fees_paid_dollars, 10);
+    }
+
+    #[test]
+    fn test_position_with_fill_reduces() {
+        // 20 yes at an average of $0.50, sell 5 at $0.70
+        let next = position(2_000, 100_000).with_fill(&rest_fill("yes", "sell", 7_000, 500));
+        assert_eq!(next.position_fp, 1_500);
+        assert_eq!(next.market_exposure_dollars, 75_000);
+        assert_eq!(next.realized_pnl_dollars, 10_000);
+    }
+
+    #[test]
+    fn test_position_with_fill_flips() {
+        // 10 yes at $0.50, buy 15 no at $0.70: close 10 at yes $0.30, open 5 no
+        let start = position(1_000, 50_000);
+        let next = start.with_fill(&rest_fill("no", "buy", 3_000, 1_500));
+        assert_eq!(next.position_fp, -500);
+        assert_eq!(next.market_exposure_dollars, 35_000);
+        assert_eq!(next.realized_pnl_dollars, -20_000);
+
+        // Selling the 5 no at $0.80 realizes $0.10 each
+        let flat = next.with_fill(&rest_fill("no", "sell", 2_000, 500));
+        assert_eq!(flat.position_fp, 0);
+        assert_eq!(flat.market_exposure_dollars, 0);
+        assert_eq!(flat.realized_pnl_dollars, -20_000 + 5_000);
+
+        // The original is untouched
+        assert_eq!(start.position_fp, 1_000);
+    }
+
+    #[test]
+    fn test_fill_from_ws_fill() {
+        let json = r#"{
+            "trade_id": "t1", "order_id": "o1", "market_ticker": "TEST",
+            "is_taker": true, "side": "no", "yes_price_dollars": "0.3500",
+            "count_fp": "4.00", "fee_cost": "0.0200", "action": "sell", "ts": 1700000000,
+            "client_order_id": "c1", "post_position_fp": "0.00", "purchased_side": "yes",
+            "subaccount": 2
+        }"#;
+        let ws_fill: FillData = serde_json::from_str(json).unwrap();
+        let fill = Fill::from(ws_fill);
+
+        assert_eq!(fill.fill_id, "t1");
+        assert_eq!(fill.trade_id, "t1");
+        assert_eq!(fill.order_id, "o1");
+        assert_eq!(fill.client_order_id.as_deref(), Some("c1"));
+        assert_eq!(fill.ticker, "TEST");
+        assert_eq!(fill.market_ticker, "TEST");
+        assert_eq!(fill.side, "no");
+        assert_eq!(fill.action, Action::Sell);
+        assert_eq!(fill.count_fp, 400);
+        assert_eq!(fill.yes_price_dollars, 3_500);
+        assert_eq!(fill.no_price_dollars, 6_500);
+        assert!(fill.is_taker);
+        assert_eq!(fill.fee_cost, 200);
+        assert_eq!(fill.subaccount_number, Some(2));
+        assert_eq!(fill.ts, Some(1_700_000_000));
+    }
 }
@@ -4,10 +4,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::market::{parse_settlement_result, SettlementResult};
 use super::order::{Action, SelfTradePrevention, Side};
 use super::{
     deserialize_count, deserialize_dollars, deserialize_optional_count,
-    deserialize_optional_dollars, TimestampMs,
+    deserialize_optional_dollars, TimestampMs, COUNT_SCALE, DOLLAR_SCALE,
 };
 
 #[derive(Debug, Clone, Serialize)]
@@ -68,9 +69,39 @@ pub struct UpdateSubscriptionParams {
     pub action: UpdateSubscriptionAction,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "type", rename_all = "snake_case")]
+#[derive(Debug, Clone)]
 pub enum WsMessage {
+    Subscribed(SubscribedMsg),
+    Unsubscribed(UnsubscribedMsg),
+    /// Plain command acknowledgement with no payload.
+    Ok(OkMsg),
+    /// Response to `list_subscriptions`.
+    SubscriptionsList(SubscriptionsListMsg),
+    /// Response to `update_subscription`.
+    SubscriptionUpdated(SubscriptionUpdatedMsg),
+    Error(ErrorMsg),
+    OrderbookSnapshot(OrderbookSnapshotMsg),
+    OrderbookDelta(OrderbookDeltaMsg),
+    Ticker(TickerMsg),
+    Trade(TradeMsg),
+    Fill(FillMsg),
+    MarketPosition(MarketPositionMsg),
+    UserOrder(UserOrderMsg),
+    /// Use [`MarketLifecycleMsg::as_settlement`] for `settled` events.
+    MarketLifecycle(MarketLifecycleMsg),
+    EventLifecycle(EventLifecycleMsg),
+    OrderGroupUpdates(OrderGroupUpdatesMsg),
+    /// WebSocket ping frame payload, surfaced only when auto-pong is disabled.
+    Ping(Vec<u8>),
+    /// A message `type` this crate does not recognize yet.
+    Unknown(UnknownMsg),
+}
+
+/// Wire shape of [`WsMessage`]; every `"ok"` response shares one tag and is
+/// split by payload afterwards.
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum RawWsMessage {
     Subscribed(SubscribedMsg),
     Unsubscribed(UnsubscribedMsg),
     #[serde(rename = "ok")]
@@ -89,6 +120,128 @@ pub enum WsMessage {
     OrderGroupUpdates(OrderGroupUpdatesMsg),
 }
 
+/// `type` tags handled by [`RawWsMessage`]
+const KNOWN_TYPES: &[&str] = &[
+    "subscribed",
+    "unsubscribed",
+    "ok",
+    "error",
+    "orderbook_snapshot",
+    "orderbook_delta",
+    "ticker",
+    "trade",
+    "fill",
+    "market_position",
+    "user_order",
+    "market_lifecycle_v2",
+    "event_lifecycle",
+    "order_group_updates",
+];
+
+impl WsMessage {
+    /// Parse a WebSocket text frame.
+    ///
+    /// Known message types are deserialized straight from `text`; only a
+    /// frame that fails that pass is parsed again as a [`serde_json::Value`]
+    /// to become [`WsMessage::Unknown`] or report the error. Prefer this
+    /// over `serde_json::from_str`, whose generic path buffers the message
+    /// before dispatching on its type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `text` is not JSON, has no `type`, or is a known
+    /// type with an invalid payload.
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+        match serde_json::from_str::<RawWsMessage>(text) {
+            Ok(raw) => Ok(raw.into()),
+            Err(err) => match serde_json::from_str(text) {
+                Ok(value) => Self::from_value(value),
+                Err(_) => Err(err),
+            },
+        }
+    }
+
+    /// Convert a message that did not parse as a known type
+    fn from_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        use serde::de::Error as _;
+
+        let Some(msg_type) = value.get("type").and_then(|t| t.as_str()) else {
+            return Err(serde_json::Error::missing_field("type"));
+        };
+        if KNOWN_TYPES.contains(&msg_type) {
+            // Surface the payload error rather than hiding it as Unknown
+            return RawWsMessage::deserialize(&value).map(Into::into);
+        }
+        Ok(Self::Unknown(UnknownMsg {
+            msg_type: msg_type.to_string(),
+            raw: value,
+        }))
+    }
+}
+
+impl<'de> Deserialize<'de> for WsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Wire {
+            Known(Box<RawWsMessage>),
+            Other(serde_json::Value),
+        }
+
+        match Wire::deserialize(deserializer)? {
+            Wire::Known(raw) => Ok((*raw).into()),
+            Wire::Other(value) => Self::from_value(value).map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+impl From<RawWsMessage> for WsMessage {
+    fn from(raw: RawWsMessage) -> Self {
+        match raw {
+            RawWsMessage::Subscribed(msg) => Self::Subscribed(msg),
+            RawWsMessage::Unsubscribed(msg) => Self::Unsubscribed(msg),
+            RawWsMessage::Ok(mut ok) => match ok.msg.take() {
+                Some(OkMsgData::SubscriptionList(subscriptions)) => {
+                    Self::SubscriptionsList(SubscriptionsListMsg {
+                        id: ok.id,
+                        subscriptions,
+                    })
+                }
+                Some(OkMsgData::SubscriptionUpdate(update)) => {
+                    Self::SubscriptionUpdated(SubscriptionUpdatedMsg {
+                        id: ok.id,
+                        sid: ok.sid,
+                        seq: ok.seq,
+                        market_tickers: update.market_tickers,
+                    })
+                }
+                None => Self::Ok(ok),
+            },
+            RawWsMessage::Error(msg) => Self::Error(msg),
+            RawWsMessage::OrderbookSnapshot(msg) => Self::OrderbookSnapshot(msg),
+            RawWsMessage::OrderbookDelta(msg) => Self::OrderbookDelta(msg),
+            RawWsMessage::Ticker(msg) => Self::Ticker(msg),
+            RawWsMessage::Trade(msg) => Self::Trade(msg),
+            RawWsMessage::Fill(msg) => Self::Fill(msg),
+            RawWsMessage::MarketPosition(msg) => Self::MarketPosition(msg),
+            RawWsMessage::UserOrder(msg) => Self::UserOrder(msg),
+            RawWsMessage::MarketLifecycle(msg) => Self::MarketLifecycle(msg),
+            RawWsMessage::EventLifecycle(msg) => Self::EventLifecycle(msg),
+            RawWsMessage::OrderGroupUpdates(msg) => Self::OrderGroupUpdates(msg),
+        }
+    }
+}
+
+/// A message with an unrecognized `type`, kept as raw JSON.
+#[derive(Debug, Clone)]
+pub struct UnknownMsg {
+    pub msg_type: String,
+    pub raw: serde_json::Value,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct SubscribedMsg {
     pub id: Option<u64>,
@@ -113,6 +266,8 @@ pub struct OkMsg {
     pub id: Option<u64>,
     pub sid: Option<u64>,
     pub seq: Option<u64>,
+    /// Always `None` on [`WsMessage::Ok`]; responses with a payload arrive
+    /// as [`WsMessage::SubscriptionsList`] or [`WsMessage::SubscriptionUpdated`].
     #[serde(default)]
     pub msg: Option<OkMsgData>,
 }
@@ -130,6 +285,20 @@ pub struct SubscriptionUpdateOk {
     pub market_tickers: Vec<String>,
 }
 
+#[derive(Debug, Clone)]
+pub struct SubscriptionsListMsg {
+    pub id: Option<u64>,
+    pub subscriptions: Vec<SubscriptionInfo>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SubscriptionUpdatedMsg {
+    pub id: Option<u64>,
+    pub sid: Option<u64>,
+    pub seq: Option<u64>,
+    pub market_tickers: Vec<String>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ErrorMsg {
     pub id: Option<u64>,
@@ -146,6 +315,63 @@ pub struct ErrorDetails {
     pub market_ticker: Option<String>,
 }
 
+impl ErrorDetails {
+    #[must_use]
+    pub const fn kind(&self) -> WsErrorKind {
+        WsErrorKind::from_code(self.code)
+    }
+}
+
+impl ErrorMsg {
+    #[must_use]
+    pub const fn kind(&self) -> WsErrorKind {
+        self.msg.kind()
+    }
+
+    /// Whether reconnecting cannot fix this error.
+    #[must_use]
+    pub const fn is_fatal(&self) -> bool {
+        self.kind().is_fatal()
+    }
+}
+
+/// Classification of a WebSocket error code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum WsErrorKind {
+    /// Authentication missing or rejected (code 9).
+    Auth,
+    /// Rate limited. Kalshi has not published a WebSocket code for this, so
+    /// `from_code` does not produce it yet.
+    RateLimit,
+    /// Malformed command or bad subscription parameters (codes 1-8, 10-16, 19-22).
+    InvalidSubscription,
+    /// Exchange-side failure or command timeout (codes 17, 18).
+    Internal,
+    Unknown(u32),
+}
+
+impl WsErrorKind {
+    #[must_use]
+    pub const fn from_code(code: u32) -> Self {
+        match code {
+            9 => Self::Auth,
+            17 | 18 => Self::Internal,
+            1..=8 | 10..=16 | 19..=22 => Self::InvalidSubscription,
+            other => Self::Unknown(other),
+        }
+    }
+
+    /// Whether reconnecting cannot fix this error.
+    ///
+    /// Only authentication failures are fatal; everything else is either
+    /// transient or scoped to a single command.
+    #[must_use]
+    pub const fn is_fatal(self) -> bool {
+        matches!(self, Self::Auth)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct OrderbookSnapshotMsg {
     pub sid: u64,
@@ -203,6 +429,15 @@ pub struct TickerData {
     pub yes_bid_dollars: i64,
     #[serde(deserialize_with = "deserialize_dollars")]
     pub yes_ask_dollars: i64,
+    /// Contracts resting at `yes_bid_dollars`, if reported.
+    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    pub yes_bid_size_fp: Option<i64>,
+    /// Contracts resting at `yes_ask_dollars`, if reported.
+    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    pub yes_ask_size_fp: Option<i64>,
+    /// Size of the trade at `price_dollars`, if reported.
+    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    pub last_trade_size_fp: Option<i64>,
     #[serde(deserialize_with = "deserialize_count")]
     pub volume_fp: i64,
     #[serde(deserialize_with = "deserialize_count")]
@@ -393,6 +628,82 @@ pub struct MarketLifecycleMetadata {
     pub cap_strike: Option<f64>,
 }
 
+/// A market settlement, derived from a `settled` market lifecycle event.
+///
+/// Kalshi has no dedicated settlement channel; get one from a
+/// [`WsMessage::MarketLifecycle`] with [`MarketLifecycleMsg::as_settlement`].
+#[derive(Debug, Clone)]
+pub struct SettlementMsg {
+    pub sid: u64,
+    pub msg: SettlementData,
+}
+
+#[derive(Debug, Clone)]
+pub struct SettlementData {
+    pub market_ticker: String,
+    /// `None` if the result is missing or unrecognized.
+    pub result: Option<SettlementResult>,
+    /// Payout per Yes contract in ten-thousandths of a dollar.
+    pub settlement_value: Option<i64>,
+    pub settled_ts: Option<TimestampMs>,
+    pub determination_ts: Option<TimestampMs>,
+}
+
+impl SettlementData {
+    /// Payout per Yes contract, falling back to the result when
+    /// `settlement_value` is missing. `None` for scalar or void markets
+    /// without a value.
+    #[must_use]
+    pub fn yes_payout(&self) -> Option<i64> {
+        self.settlement_value.or(match self.result? {
+            SettlementResult::Yes => Some(DOLLAR_SCALE),
+            SettlementResult::No => Some(0),
+            _ => None,
+        })
+    }
+
+    /// Revenue in ten-thousandths of a dollar for a position of
+    /// `position_fp` contracts (scaled by 100, negative for No).
+    ///
+    /// Settlement messages don't carry account revenue, so this applies the
+    /// payout to your position. Fees are not included.
+    #[must_use]
+    pub fn revenue(&self, position_fp: i64) -> Option<i64> {
+        let yes_payout = self.yes_payout()?;
+        let payout = if position_fp >= 0 {
+            yes_payout
+        } else {
+            DOLLAR_SCALE - yes_payout
+        };
+        Some(position_fp.abs() * payout / COUNT_SCALE)
+    }
+}
+
+impl MarketLifecycleMsg {
+    /// The settlement carried by a `settled` event, or `None` for other
+    /// event types.
+    #[must_use]
+    pub fn as_settlement(&self) -> Option<SettlementMsg> {
+        let data = &self.msg;
+        if data.event_type != "settled" {
+            return None;
+        }
+        Some(SettlementMsg {
+            sid: self.sid,
+            msg: SettlementData {
+                market_ticker: data.market_ticker.clone(),
+                result: data
+                    .result
+                    .as_deref()
+                    .and_then(|r| parse_settlement_result(r).ok().flatten()),
+                settlement_value: data.settlement_value,
+                settled_ts: data.settled_ts,
+                determination_ts: data.determination_ts,
+            },
+        })
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct EventLifecycleMsg {
     pub sid: u64,
@@ -476,4 +787,174 @@ mod tests {
             _ => panic!("Expected OrderbookDelta"),
         }
     }
+
+    #[test]
+    fn test_ticker_deserialization() {
+        let json = r#"{"type":"ticker","sid":2,"msg":{
+            "market_ticker":"KXBTC-25JAN","market_id":"id","price_dollars":"0.5200",
+            "yes_bid_dollars":"0.5100","yes_ask_dollars":"0.5300",
+            "yes_bid_size_fp":"120.00","yes_ask_size_fp":"35.50","last_trade_size_fp":"4.00",
+            "volume_fp":"1000.00","open_interest_fp":"250.00",
+            "dollar_volume":520,"dollar_open_interest":130,"ts":1700000000000,"time":""}}"#;
+
+        let WsMessage::Ticker(ticker) = serde_json::from_str(json).unwrap() else {
+            panic!("expected ticker");
+        };
+        assert_eq!(ticker.msg.yes_bid_dollars, 5_100);
+        assert_eq!(ticker.msg.yes_bid_size_fp, Some(12_000));
+        assert_eq!(ticker.msg.yes_ask_size_fp, Some(3_550));
+        assert_eq!(ticker.msg.last_trade_size_fp, Some(400));
+        assert_eq!(ticker.msg.volume_fp, 100_000);
+
+        // Sizes are optional
+        let json = json.replace(
+            r#""yes_bid_size_fp":"120.00","yes_ask_size_fp":"35.50","last_trade_size_fp":"4.00","#,
+            "",
+        );
+        let WsMessage::Ticker(ticker) = serde_json::from_str(&json).unwrap() else {
+            panic!("expected ticker");
+        };
+        assert_eq!(ticker.msg.yes_bid_size_fp, None);
+        assert_eq!(ticker.msg.last_trade_size_fp, None);
+    }
+
+    #[test]
+    fn test_ok_deserialization() {
+        let msg: WsMessage = serde_json::from_str(r#"{"type":"ok","id":1}"#).unwrap();
+        assert!(matches!(msg, WsMessage::Ok(OkMsg { id: Some(1), .. })));
+    }
+
+    #[test]
+    fn test_subscriptions_list_deserialization() {
+        let json = r#"{
+            "type": "ok",
+            "id": 3,
+            "msg": [
+                {"channel": "orderbook_delta", "sid": 1},
+                {"channel": "ticker", "sid": 2}
+            ]
+        }"#;
+
+        let WsMessage::SubscriptionsList(list) = serde_json::from_str(json).unwrap() else {
+            panic!("expected SubscriptionsList");
+        };
+        assert_eq!(list.id, Some(3));
+        assert_eq!(list.subscriptions.len(), 2);
+        assert_eq!(list.subscriptions[1].channel, "ticker");
+        assert_eq!(list.subscriptions[1].sid, 2);
+    }
+
+    #[test]
+    fn test_subscription_updated_deserialization() {
+        let json = r#"{
+            "type": "ok",
+            "id": 124,
+            "sid": 456,
+            "seq": 222,
+            "msg": {"market_tickers": ["A", "B"]}
+        }"#;
+
+        let WsMessage::SubscriptionUpdated(update) = serde_json::from_str(json).unwrap() else {
+            panic!("expected SubscriptionUpdated");
+        };
+        assert_eq!(update.id, Some(124));
+        assert_eq!(update.sid, Some(456));
+        assert_eq!(update.seq, Some(222));
+        assert_eq!(update.market_tickers, ["A", "B"]);
+    }
+
+    #[test]
+    fn test_error_kind_from_code() {
+        assert_eq!(WsErrorKind::from_code(9), WsErrorKind::Auth);
+        assert_eq!(WsErrorKind::from_code(17), WsErrorKind::Internal);
+        assert_eq!(WsErrorKind::from_code(18), WsErrorKind::Internal);
+        assert_eq!(WsErrorKind::from_code(6), WsErrorKind::InvalidSubscription);
+        assert_eq!(WsErrorKind::from_code(16), WsErrorKind::InvalidSubscription);
+        assert_eq!(WsErrorKind::from_code(999), WsErrorKind::Unknown(999));
+
+        let json = r#"{"type":"error","id":3,"msg":{"code":9,"msg":"Authentication required"}}"#;
+        match serde_json::from_str(json).unwrap() {
+            WsMessage::Error(err) => {
+                assert_eq!(err.kind(), WsErrorKind::Auth);
+                assert!(err.is_fatal());
+            }
+            _ => panic!("Expected Error"),
+        }
+        assert!(!WsErrorKind::Internal.is_fatal());
+    }
+
+    #[test]
+    fn test_settlement_from_lifecycle() {
+        let json = r#"{
+            "type": "market_lifecycle_v2",
+            "sid": 13,
+            "msg": {
+                "market_ticker": "KXBTC-25JAN",
+                "event_type": "settled",
+                "result": "yes",
+                "settlement_value": "1.0000",
+                "settled_ts": 1737000000
+            }
+        }"#;
+
+        let WsMessage::MarketLifecycle(lifecycle) = serde_json::from_str(json).unwrap() else {
+            panic!("expected MarketLifecycle");
+        };
+        assert_eq!(lifecycle.msg.event_type, "settled");
+        let settlement = lifecycle.as_settlement().unwrap();
+        assert_eq!(settlement.sid, 13);
+        assert_eq!(settlement.msg.market_ticker, "KXBTC-25JAN");
+        assert_eq!(settlement.msg.result, Some(SettlementResult::Yes));
+        assert_eq!(settlement.msg.settled_ts, Some(1_737_000_000));
+        // 10 Yes contracts pay $10; 5 No contracts pay nothing
+        assert_eq!(settlement.msg.revenue(1_000), Some(100_000));
+        assert_eq!(settlement.msg.revenue(-500), Some(0));
+
+        // Other lifecycle events carry no settlement
+        let json = r#"{"type":"market_lifecycle_v2","sid":13,"msg":{"market_ticker":"T","event_type":"closed"}}"#;
+        let WsMessage::MarketLifecycle(lifecycle) = serde_json::from_str(json).unwrap() else {
+            panic!("expected MarketLifecycle");
+        };
+        assert!(lifecycle.as_settlement().is_none());
+    }
+
+    #[test]
+    fn test_unknown_message_type() {
+        let json = r#"{"type":"brand_new_channel","sid":9,"msg":{"value":1}}"#;
+        let WsMessage::Unknown(unknown) = serde_json::from_str(json).unwrap() else {
+            panic!("expected Unknown");
+        };
+        assert_eq!(unknown.msg_type, "brand_new_channel");
+        assert_eq!(unknown.raw["msg"]["value"], 1);
+
+        // Known types with bad payloads are still errors
+        let bad = r#"{"type":"orderbook_delta","sid":1}"#;
+        assert!(serde_json::from_str::<WsMessage>(bad).is_err());
+        assert!(WsMessage::from_json(bad).is_err());
+        assert!(WsMessage::from_json(r#"{"sid":1}"#).is_err());
+        assert!(WsMessage::from_json("not json").is_err());
+
+        let WsMessage::Unknown(unknown) = WsMessage::from_json(json).unwrap() else {
+            panic!("expected Unknown");
+        };
+        assert_eq!(unknown.raw["sid"], 9);
+        let delta = r#"{"type":"orderbook_delta","sid":1,"seq":2,"msg":{
+            "market_ticker":"T","market_id":"m","price_dollars":"0.5000",
+            "delta_fp":"1.00","side":"yes"}}"#;
+        assert!(matches!(
+            WsMessage::from_json(delta).unwrap(),
+            WsMessage::OrderbookDelta(_)
+        ));
+    }
+
+    #[test]
+    fn test_known_types_match_raw_tags() {
+        for msg_type in KNOWN_TYPES {
+            let json = format!(r#"{{"type":"{}"}}"#, msg_type);
+            // Payload errors are fine; an unknown tag is not
+            if let Err(err) = serde_json::from_str::<RawWsMessage>(&json) {
+                assert!(!err.to_string().contains("unknown variant"), "{}", msg_type);
+            }
+        }
+    }
 }
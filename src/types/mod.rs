@@ -6,34 +6,46 @@
 //! - [`order`] - Order-related types (Side, Action, CreateOrderRequest, etc.)
 //! - [`market`] - Market and event types
 //! - [`messages`] - WebSocket message types
+//! - [`rfq`] - Request-for-quote and quote types
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 
 mod fixed_point;
 pub mod market;
 pub mod messages;
 pub mod order;
+pub mod rfq;
 
 pub(crate) use fixed_point::{
-    deserialize_count, deserialize_dollars, deserialize_optional_count,
-    deserialize_optional_dollars, serialize_optional_count, serialize_optional_dollars,
-    DOLLAR_SCALE,
+    deserialize_count, deserialize_dollars, deserialize_levels, deserialize_optional_count,
+    deserialize_optional_dollars, float_to_scaled, serialize_count, serialize_dollars,
+    serialize_optional_count, serialize_optional_dollars, COUNT_SCALE, DOLLAR_SCALE,
 };
-pub use fixed_point::{format_count, format_dollars, parse_count, parse_dollars};
+pub use fixed_point::{format_count, format_dollars, parse_count, parse_dollars, PriceExt};
 pub use market::{
-    Balance, Event, EventPosition, ExchangeSchedule, ExchangeStatus, Fill, GetBalanceResponse,
-    GetEventResponse, GetEventsResponse, GetExchangeScheduleResponse, GetFillsResponse,
+    Announcement, Balance, Candlestick, Event, EventPosition, ExchangeSchedule, ExchangeStatus,
+    Fill, GetBalanceResponse, GetCandlesticksResponse, GetEventResponse, GetEventsResponse,
+    GetExchangeAnnouncementsResponse, GetExchangeScheduleResponse, GetFillsResponse,
     GetMarketResponse, GetMarketsResponse, GetOrderbookResponse, GetPositionsResponse,
     GetSeriesListResponse, GetSeriesResponse, GetSettlementsResponse, GetTradesResponse, Market,
-    MarketStatus, Orderbook, OrderbookLevel, Position, Series, Settlement, SettlementResult,
-    SettlementSource, Trade,
+    MarketStatus, MarketStatusFilter, Orderbook, OrderbookLevel, Position, Series, Settlement,
+    SettlementResult, SettlementSource, SettlementStatus, Trade,
 };
-pub use messages::WsMessage;
+pub use messages::{WsErrorKind, WsMessage};
 pub use order::{
     Action, AmendOrderRequest, AmendOrderResponse, BatchCancelOrdersRequest,
     BatchCancelOrdersResponse, BatchCancelResult, BatchCreateOrdersRequest,
-    BatchCreateOrdersResponse, BatchOrderError, BatchOrderResult, CancelOrderResponse,
-    CreateOrderRequest, CreateOrderResponse, DecreaseOrderRequest, DecreaseOrderResponse,
-    GetOrderQueuePositionsResponse, GetOrderResponse, GetOrdersResponse, Order, OrderStatus,
-    OrderType, QueuePosition, SelfTradePrevention, Side, TimeInForce,
+    BatchCreateOrdersRequestBuilder, BatchCreateOrdersResponse, BatchOrderError, BatchOrderResult,
+    CancelOrderGroupResponse, CancelOrderResponse, CreateOrderRequest, CreateOrderResponse,
+    DecreaseOrderRequest, DecreaseOrderResponse, GetOrderGroupResponse,
+    GetOrderQueuePositionsResponse, GetOrderResponse, GetOrdersResponse, MarketOrderEstimate,
+    Order, OrderStatus, OrderStatusFilter, OrderType, QueuePosition, SelfTradePrevention, Side,
+    TimeInForce,
+};
+pub use rfq::{
+    CreateQuoteRequest, CreateQuoteResponse, CreateRfqRequest, CreateRfqResponse, GetRfqsResponse,
+    Quote, QuoteStatus, Rfq, RfqStatus,
 };
 
 /// Price in ten-thousandths of a dollar.
@@ -51,3 +63,34 @@ pub type Quantity = i64;
 
 /// Unix timestamp in seconds.
 pub type TimestampMs = i64;
+
+/// Error returned when parsing an enum from its wire name fails.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid {kind}: {value:?}")]
+pub struct ParseEnumError {
+    kind: &'static str,
+    value: String,
+}
+
+impl ParseEnumError {
+    pub(crate) fn new(kind: &'static str, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.to_string(),
+        }
+    }
+
+    /// The string that failed to parse
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// Parse an RFC 3339 timestamp as UTC, or `None` if empty or malformed.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_utc(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
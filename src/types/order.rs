@@ -2,11 +2,20 @@
 
 //! Order-related types.
 
+use std::fmt;
+use std::str::FromStr;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::error::Error;
+#[cfg(feature = "chrono")]
+use crate::types::parse_utc;
 use crate::types::{
-    deserialize_count, deserialize_dollars, deserialize_optional_count, serialize_optional_count,
-    serialize_optional_dollars,
+    deserialize_count, deserialize_dollars, deserialize_optional_count, format_dollars,
+    serialize_count, serialize_dollars, serialize_optional_count, serialize_optional_dollars,
+    ParseEnumError, COUNT_SCALE, DOLLAR_SCALE,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -25,6 +34,33 @@ impl Side {
             Side::No => Side::Yes,
         }
     }
+
+    /// Wire name, as used by serde.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Yes => "yes",
+            Self::No => "no",
+        }
+    }
+}
+
+impl fmt::Display for Side {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Side {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "yes" => Ok(Self::Yes),
+            "no" => Ok(Self::No),
+            _ => Err(ParseEnumError::new("side", s)),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -35,6 +71,35 @@ pub enum Action {
     Sell,
 }
 
+impl Action {
+    /// Wire name, as used by serde.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Buy => "buy",
+            Self::Sell => "sell",
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Action {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "buy" => Ok(Self::Buy),
+            "sell" => Ok(Self::Sell),
+            _ => Err(ParseEnumError::new("action", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
@@ -44,6 +109,64 @@ pub enum OrderStatus {
     Executed,
 }
 
+impl OrderStatus {
+    /// Wire name, as used by serde.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Resting => "resting",
+            Self::Canceled => "canceled",
+            Self::Executed => "executed",
+        }
+    }
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OrderStatus {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "resting" => Ok(Self::Resting),
+            "canceled" => Ok(Self::Canceled),
+            "executed" => Ok(Self::Executed),
+            _ => Err(ParseEnumError::new("order status", s)),
+        }
+    }
+}
+
+/// Status filter for `RestClient::get_orders_filtered`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum OrderStatusFilter {
+    Resting,
+    Executed,
+    Canceled,
+    Pending,
+    /// No status filter
+    #[default]
+    All,
+}
+
+impl OrderStatusFilter {
+    /// Value for the `status` query parameter, or `None` for [`Self::All`].
+    #[must_use]
+    pub const fn as_query(self) -> Option<&'static str> {
+        match self {
+            Self::Resting => Some("resting"),
+            Self::Executed => Some("executed"),
+            Self::Canceled => Some("canceled"),
+            Self::Pending => Some("pending"),
+            Self::All => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
@@ -53,6 +176,35 @@ pub enum OrderType {
     Market,
 }
 
+impl OrderType {
+    /// Wire name, as used by serde.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Limit => "limit",
+            Self::Market => "market",
+        }
+    }
+}
+
+impl fmt::Display for OrderType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OrderType {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "limit" => Ok(Self::Limit),
+            "market" => Ok(Self::Market),
+            _ => Err(ParseEnumError::new("order type", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -70,6 +222,37 @@ pub enum TimeInForce {
     ImmediateOrCancel,
 }
 
+impl TimeInForce {
+    /// Wire name, as used by serde.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::FillOrKill => "fill_or_kill",
+            Self::GoodTillCanceled => "good_till_canceled",
+            Self::ImmediateOrCancel => "immediate_or_cancel",
+        }
+    }
+}
+
+impl fmt::Display for TimeInForce {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TimeInForce {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "fill_or_kill" => Ok(Self::FillOrKill),
+            "good_till_canceled" => Ok(Self::GoodTillCanceled),
+            "immediate_or_cancel" => Ok(Self::ImmediateOrCancel),
+            _ => Err(ParseEnumError::new("time in force", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct CreateOrderRequest {
     pub ticker: String,
@@ -118,6 +301,11 @@ pub struct CreateOrderRequest {
     pub cancel_order_on_pause: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subaccount: Option<i32>,
+    /// Set by [`limit`](Self::limit) and [`market`](Self::market) and used
+    /// by [`validate`](Self::validate); not sent, as Kalshi infers the type
+    /// from the price fields.
+    #[serde(skip)]
+    pub order_type: OrderType,
 }
 
 impl CreateOrderRequest {
@@ -150,6 +338,7 @@ impl CreateOrderRequest {
             order_group_id: None,
             cancel_order_on_pause: None,
             subaccount: None,
+            order_type: OrderType::Limit,
         }
     }
 
@@ -176,9 +365,135 @@ impl CreateOrderRequest {
             order_group_id: None,
             cancel_order_on_pause: None,
             subaccount: None,
+            order_type: OrderType::Market,
         }
     }
 
+    /// Check the order locally before sending it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidOrder`] if the count is not positive, a price
+    /// is outside `$0.0001..=$0.9999`, more than one price is set, a limit
+    /// order has no price, or a post-only order has no price or an immediate
+    /// time in force.
+    pub fn validate(&self) -> Result<(), Error> {
+        let count_fp = self.count_fp.or(self.count.map(|c| c * COUNT_SCALE));
+        if !count_fp.is_some_and(|c| c > 0) {
+            return Err(Error::InvalidOrder(format!(
+                "{}: count must be positive",
+                self.ticker
+            )));
+        }
+
+        let prices = [
+            ("yes_price", self.yes_price.map(|p| p * 100)),
+            ("no_price", self.no_price.map(|p| p * 100)),
+            ("yes_price_dollars", self.yes_price_dollars),
+            ("no_price_dollars", self.no_price_dollars),
+        ];
+        for (field, price) in prices {
+            if let Some(price) = price {
+                if price <= 0 || price >= DOLLAR_SCALE {
+                    return Err(Error::InvalidOrder(format!(
+                        "{}: {} {} is outside the valid price band",
+                        self.ticker,
+                        field,
+                        format_dollars(price)
+                    )));
+                }
+            }
+        }
+
+        // A limit order is priced on exactly one side; market orders have none
+        let set: Vec<&str> = prices
+            .iter()
+            .filter(|(_, price)| price.is_some())
+            .map(|(field, _)| *field)
+            .collect();
+        if set.len() > 1 {
+            return Err(Error::InvalidOrder(format!(
+                "{}: limit order must set exactly one price, got {}",
+                self.ticker,
+                set.join(" and ")
+            )));
+        }
+        if self.order_type == OrderType::Limit && set.is_empty() {
+            return Err(Error::InvalidOrder(format!(
+                "{}: limit order must set exactly one price, got none",
+                self.ticker
+            )));
+        }
+
+        if self.post_only == Some(true) {
+            if set.is_empty() {
+                return Err(Error::InvalidOrder(format!(
+                    "{}: post-only order needs a limit price",
+                    self.ticker
+                )));
+            }
+            if matches!(
+                self.time_in_force,
+                Some(TimeInForce::FillOrKill | TimeInForce::ImmediateOrCancel)
+            ) {
+                return Err(Error::InvalidOrder(format!(
+                    "{}: post-only order cannot be fill-or-kill or immediate-or-cancel",
+                    self.ticker
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Worst-case cost of the order in cents if fully filled.
+    ///
+    /// Uses the limit price of the contract being bought (selling yes buys no
+    /// and vice versa). Without a price, assumes $1 per contract capped by
+    /// `buy_max_cost`.
+    #[must_use]
+    pub fn max_cost_cents(&self) -> i64 {
+        let count_fp = self
+            .count_fp
+            .or(self.count.map(|c| c * COUNT_SCALE))
+            .unwrap_or(0);
+
+        let yes_price = self.yes_price_dollars.or(self.yes_price.map(|p| p * 100));
+        let no_price = self.no_price_dollars.or(self.no_price.map(|p| p * 100));
+        let bought = match self.action {
+            Action::Buy => self.side,
+            Action::Sell => self.side.opposite(),
+        };
+        let price = match bought {
+            Side::Yes => yes_price.or(no_price.map(|p| DOLLAR_SCALE - p)),
+            Side::No => no_price.or(yes_price.map(|p| DOLLAR_SCALE - p)),
+        };
+
+        match price {
+            Some(price) => {
+                // count_fp * price is in ten-thousandths of a cent; round up
+                // so partial cents count against the budget
+                let scale = COUNT_SCALE * 100;
+                (count_fp * price + scale - 1) / scale
+            }
+            None => {
+                let worst = count_fp;
+                self.buy_max_cost.map_or(worst, |max| worst.min(max))
+            }
+        }
+    }
+
+    /// The JSON body [`create_order`](crate::KalshiClient::create_order)
+    /// would POST for this request.
+    ///
+    /// Unset fields are omitted and prices and counts use their wire
+    /// formats, so the output can be compared directly with the API docs.
+    #[must_use]
+    pub fn to_wire_json(&self) -> String {
+        // Every field is a string, number, bool or plain enum
+        serde_json::to_string(self).expect("CreateOrderRequest always serializes")
+    }
+
     #[must_use]
     pub fn with_client_order_id(mut self, id: impl Into<String>) -> Self {
         self.client_order_id = Some(id.into());
@@ -191,6 +506,18 @@ impl CreateOrderRequest {
         self
     }
 
+    /// Mark the order post-only.
+    ///
+    /// Sets Kalshi's native `post_only` flag: the exchange rejects the order
+    /// instead of matching it if it would take liquidity. Use
+    /// [`Orderbook::would_cross`](crate::orderbook::Orderbook::would_cross)
+    /// to check a local book before sending.
+    #[must_use]
+    pub fn post_only(mut self) -> Self {
+        self.post_only = Some(true);
+        self
+    }
+
     #[must_use]
     pub fn with_time_in_force(mut self, tif: TimeInForce) -> Self {
         self.time_in_force = Some(tif);
@@ -210,7 +537,7 @@ impl CreateOrderRequest {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Order {
     pub order_id: String,
     pub user_id: String,
@@ -221,24 +548,66 @@ pub struct Order {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub status: OrderStatus,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub yes_price_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub no_price_dollars: i64,
-    #[serde(deserialize_with = "deserialize_count")]
+    #[serde(
+        deserialize_with = "deserialize_count",
+        serialize_with = "serialize_count"
+    )]
     pub fill_count_fp: i64,
-    #[serde(deserialize_with = "deserialize_count")]
+    #[serde(
+        deserialize_with = "deserialize_count",
+        serialize_with = "serialize_count"
+    )]
     pub remaining_count_fp: i64,
-    #[serde(deserialize_with = "deserialize_count")]
+    #[serde(
+        default,
+        deserialize_with = "deserialize_count",
+        serialize_with = "serialize_count"
+    )]
     pub initial_count_fp: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub taker_fill_cost_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub maker_fill_cost_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub taker_fees_dollars: i64,
-    #[serde(deserialize_with = "deserialize_dollars")]
+    #[serde(
+        deserialize_with = "deserialize_dollars",
+        serialize_with = "serialize_dollars"
+    )]
     pub maker_fees_dollars: i64,
+    #[serde(
+        default,
+        alias = "maker_fill_count",
+        deserialize_with = "deserialize_optional_count",
+        serialize_with = "serialize_optional_count"
+    )]
+    pub maker_fill_count_fp: Option<i64>,
+    #[serde(
+        default,
+        alias = "taker_fill_count",
+        deserialize_with = "deserialize_optional_count",
+        serialize_with = "serialize_optional_count"
+    )]
+    pub taker_fill_count_fp: Option<i64>,
     #[serde(default)]
     pub expiration_time: Option<String>,
     #[serde(default)]
@@ -255,11 +624,152 @@ pub struct Order {
     pub subaccount_number: Option<i32>,
 }
 
+impl Order {
+    /// `created_time` parsed as RFC 3339, or `None` if unset or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn created_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.created_time.as_deref().and_then(parse_utc)
+    }
+
+    /// `last_update_time` parsed as RFC 3339, or `None` if unset or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn last_update_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.last_update_time.as_deref().and_then(parse_utc)
+    }
+
+    /// `expiration_time` parsed as RFC 3339, or `None` if unset or malformed.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn expiration_time_utc(&self) -> Option<DateTime<Utc>> {
+        self.expiration_time.as_deref().and_then(parse_utc)
+    }
+
+    /// Maker and taker fill counts (scaled by 100).
+    ///
+    /// If only one split is reported, the other is `fill_count_fp` minus it.
+    /// Returns `None` if neither is reported.
+    #[must_use]
+    pub fn maker_taker_fill_counts(&self) -> Option<(i64, i64)> {
+        match (self.maker_fill_count_fp, self.taker_fill_count_fp) {
+            (Some(maker), Some(taker)) => Some((maker, taker)),
+            (Some(maker), None) => Some((maker, (self.fill_count_fp - maker).max(0))),
+            (None, Some(taker)) => Some(((self.fill_count_fp - taker).max(0), taker)),
+            (None, None) => None,
+        }
+    }
+
+    /// Check if more contracts filled as maker than as taker.
+    #[must_use]
+    pub fn was_maker(&self) -> bool {
+        self.maker_taker_fill_counts()
+            .is_some_and(|(maker, taker)| maker > taker)
+    }
+
+    /// Fraction of filled contracts that filled as maker, from 0.0 to 1.0.
+    ///
+    /// Returns 0.0 when nothing has filled or the split is unknown.
+    #[must_use]
+    pub fn maker_ratio(&self) -> f64 {
+        match self.maker_taker_fill_counts() {
+            Some((maker, taker)) if maker + taker > 0 => maker as f64 / (maker + taker) as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Check if the order has fills and all of them were as maker.
+    #[must_use]
+    pub fn is_fully_maker(&self) -> bool {
+        self.maker_taker_fill_counts()
+            .is_some_and(|(maker, taker)| maker > 0 && taker == 0)
+    }
+
+    /// Order size (scaled by 100) that fills are measured against.
+    ///
+    /// While contracts remain, `fill + remaining` is the live size, which
+    /// follows amendments. Once none remain, the size is `initial_count_fp`,
+    /// so a partially filled cancel is not mistaken for a full fill. An
+    /// unreported (zero) initial count falls back to `fill + remaining`.
+    #[must_use]
+    pub fn total_count_fp(&self) -> i64 {
+        let live = self.fill_count_fp + self.remaining_count_fp;
+        if self.remaining_count_fp > 0 || self.initial_count_fp == 0 {
+            live
+        } else {
+            self.initial_count_fp.max(self.fill_count_fp)
+        }
+    }
+
+    /// Check if the order filled completely.
+    #[must_use]
+    pub fn is_filled(&self) -> bool {
+        self.status == OrderStatus::Executed
+            || (self.fill_count_fp > 0 && self.fill_count_fp >= self.total_count_fp())
+    }
+
+    /// Check if some, but not all, of the order filled.
+    #[must_use]
+    pub fn is_partially_filled(&self) -> bool {
+        self.fill_count_fp > 0 && !self.is_filled()
+    }
+
+    /// Fraction of the order that filled, from 0.0 to 1.0.
+    #[must_use]
+    pub fn fill_ratio(&self) -> f64 {
+        if self.is_filled() {
+            return 1.0;
+        }
+        match self.total_count_fp() {
+            total if total > 0 => self.fill_count_fp as f64 / total as f64,
+            _ => 0.0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateOrderResponse {
     pub order: Order,
 }
 
+impl CreateOrderResponse {
+    /// Contracts (scaled by 100) that did not execute, `initial - filled`.
+    ///
+    /// For IOC and market orders the unfilled remainder is canceled
+    /// immediately, so this is how much of the request was dropped. For
+    /// market orders this relies on `initial_count_fp` holding the requested
+    /// size.
+    #[must_use]
+    pub fn unfilled_count(&self) -> i64 {
+        (self.order.initial_count_fp - self.order.fill_count_fp).max(0)
+    }
+}
+
+/// What a market order would fill against the current book, from
+/// [`estimate_market_order`](crate::client::RestClient::estimate_market_order).
+///
+/// Prices are for the side traded, in ten-thousandths of a dollar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarketOrderEstimate {
+    /// Contracts (scaled by 100) the book can fill, at most the count asked for
+    pub fillable_fp: i64,
+    /// Total cost of a buy or proceeds of a sell
+    pub total_dollars: i64,
+    /// Volume-weighted fill price, `None` if nothing can fill
+    pub average_price: Option<f64>,
+    /// Price of the last level the order reaches, `None` if nothing can fill
+    pub worst_price_dollars: Option<i64>,
+}
+
+impl MarketOrderEstimate {
+    /// Total rounded up to whole cents, the unit of
+    /// [`CreateOrderRequest::buy_max_cost`].
+    #[must_use]
+    pub fn max_cost_cents(&self) -> i64 {
+        (self.total_dollars + 99) / 100
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct CancelOrderResponse {
     pub order: Order,
@@ -267,6 +777,17 @@ pub struct CancelOrderResponse {
     pub reduced_by_fp: i64,
 }
 
+impl CancelOrderResponse {
+    /// Contracts (scaled by 100) that filled before the cancel took effect.
+    ///
+    /// This is the exposure the order left behind; `reduced_by_fp` is the
+    /// resting quantity the cancel removed.
+    #[must_use]
+    pub fn filled_before_cancel(&self) -> i64 {
+        self.order.fill_count_fp
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct AmendOrderRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -321,13 +842,13 @@ pub struct DecreaseOrderResponse {
     pub reduced_by_fp: Option<i64>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetOrdersResponse {
     pub orders: Vec<Order>,
     pub cursor: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetOrderResponse {
     pub order: Order,
 }
@@ -337,6 +858,97 @@ pub struct BatchCreateOrdersRequest {
     pub orders: Vec<CreateOrderRequest>,
 }
 
+impl BatchCreateOrdersRequest {
+    /// Maximum number of orders the exchange accepts in one batch.
+    pub const MAX_BATCH_SIZE: usize = 20;
+
+    /// Start building a batch one order at a time.
+    #[must_use]
+    pub fn builder() -> BatchCreateOrdersRequestBuilder {
+        BatchCreateOrdersRequestBuilder::default()
+    }
+
+    /// Validate every order, collecting all failures with their indices.
+    ///
+    /// An oversized batch is reported once, at the index of the first order
+    /// past [`Self::MAX_BATCH_SIZE`].
+    ///
+    /// # Errors
+    ///
+    /// Returns every `(index, Error::InvalidOrder)` found.
+    pub fn validate(&self) -> Result<(), Vec<(usize, Error)>> {
+        let mut errors: Vec<(usize, Error)> = self
+            .orders
+            .iter()
+            .enumerate()
+            .filter_map(|(i, order)| order.validate().err().map(|e| (i, e)))
+            .collect();
+
+        if self.orders.len() > Self::MAX_BATCH_SIZE {
+            errors.push((
+                Self::MAX_BATCH_SIZE,
+                Error::InvalidOrder(format!(
+                    "batch has {} orders, maximum is {}",
+                    self.orders.len(),
+                    Self::MAX_BATCH_SIZE
+                )),
+            ));
+            errors.sort_by_key(|(i, _)| *i);
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Builder for [`BatchCreateOrdersRequest`].
+///
+/// ```rust
+/// use kalshi_trading::types::{Action, BatchCreateOrdersRequest, CreateOrderRequest, Side};
+///
+/// let batch = BatchCreateOrdersRequest::builder()
+///     .add_order(CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 4_500))
+///     .add_order(CreateOrderRequest::limit("T", Side::No, Action::Buy, 1, 5_000))
+///     .build()
+///     .unwrap();
+/// assert_eq!(batch.orders.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BatchCreateOrdersRequestBuilder {
+    orders: Vec<CreateOrderRequest>,
+}
+
+impl BatchCreateOrdersRequestBuilder {
+    /// Append an order to the batch.
+    #[must_use]
+    pub fn add_order(mut self, order: CreateOrderRequest) -> Self {
+        self.orders.push(order);
+        self
+    }
+
+    /// Finish the batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidOrder` if more than
+    /// [`BatchCreateOrdersRequest::MAX_BATCH_SIZE`] orders were added.
+    pub fn build(self) -> Result<BatchCreateOrdersRequest, Error> {
+        if self.orders.len() > BatchCreateOrdersRequest::MAX_BATCH_SIZE {
+            return Err(Error::InvalidOrder(format!(
+                "batch has {} orders, maximum is {}",
+                self.orders.len(),
+                BatchCreateOrdersRequest::MAX_BATCH_SIZE
+            )));
+        }
+        Ok(BatchCreateOrdersRequest {
+            orders: self.orders,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct BatchOrderResult {
     #[serde(default)]
@@ -371,6 +983,11 @@ pub struct BatchCancelOrdersRequest {
     pub orders: Option<Vec<BatchCancelOrdersRequestOrder>>,
 }
 
+impl BatchCancelOrdersRequest {
+    /// Maximum number of orders the exchange accepts in one batch.
+    pub const MAX_BATCH_SIZE: usize = 20;
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct BatchCancelOrdersRequestOrder {
     pub order_id: String,
@@ -407,9 +1024,59 @@ pub struct GetOrderQueuePositionsResponse {
     pub queue_positions: Vec<QueuePosition>,
 }
 
+/// Response from `RestClient::get_order_group`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetOrderGroupResponse {
+    #[serde(default)]
+    pub is_auto_cancel_enabled: bool,
+    /// IDs of the orders in the group.
+    #[serde(default)]
+    pub orders: Vec<String>,
+}
+
+/// Response from `RestClient::cancel_order_group`; the API returns an empty object.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct CancelOrderGroupResponse {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client::testing::order_json;
+
+    fn assert_wire_round_trip<T>(value: T)
+    where
+        T: fmt::Display + FromStr<Err = ParseEnumError> + Serialize + PartialEq + fmt::Debug,
+    {
+        let text = value.to_string();
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            format!("\"{}\"", text)
+        );
+        assert_eq!(text.parse::<T>(), Ok(value));
+    }
+
+    #[test]
+    fn test_display_matches_wire_format() {
+        assert_eq!(Side::Yes.to_string(), "yes");
+        assert_eq!(format!("{} {}", Action::Buy, Side::No), "buy no");
+        assert_eq!(
+            TimeInForce::GoodTillCanceled.to_string(),
+            "good_till_canceled"
+        );
+
+        assert_wire_round_trip(Side::No);
+        assert_wire_round_trip(Action::Sell);
+        assert_wire_round_trip(OrderStatus::Canceled);
+        assert_wire_round_trip(OrderType::Market);
+        assert_wire_round_trip(TimeInForce::FillOrKill);
+        assert_wire_round_trip(TimeInForce::ImmediateOrCancel);
+
+        // Parsing is exact, like serde
+        let err = "Yes".parse::<Side>().unwrap_err();
+        assert_eq!(err.value(), "Yes");
+        assert_eq!(err.to_string(), r#"invalid side: "Yes""#);
+        assert!("gtc".parse::<TimeInForce>().is_err());
+    }
 
     #[test]
     fn test_side_opposite() {
@@ -417,6 +1084,15 @@ mod tests {
         assert_eq!(Side::No.opposite(), Side::Yes);
     }
 
+    #[test]
+    fn test_order_status_filter_query() {
+        assert_eq!(OrderStatusFilter::Resting.as_query(), Some("resting"));
+        assert_eq!(OrderStatusFilter::Executed.as_query(), Some("executed"));
+        assert_eq!(OrderStatusFilter::Canceled.as_query(), Some("canceled"));
+        assert_eq!(OrderStatusFilter::Pending.as_query(), Some("pending"));
+        assert_eq!(OrderStatusFilter::All.as_query(), None);
+    }
+
     #[test]
     fn test_create_limit_order() {
         let order = CreateOrderRequest::limit("KXBTC-25JAN", Side::Yes, Action::Buy, 10, 5_500);
@@ -428,6 +1104,28 @@ mod tests {
         assert_eq!(order.yes_price_dollars, Some(5_500));
     }
 
+    #[test]
+    fn test_to_wire_json() {
+        let order = CreateOrderRequest::limit("KXBTC-25JAN", Side::Yes, Action::Buy, 10, 5_500);
+        let json: serde_json::Value = serde_json::from_str(&order.to_wire_json()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "ticker": "KXBTC-25JAN",
+                "side": "yes",
+                "action": "buy",
+                "count": 10,
+                "count_fp": "10.00",
+                "yes_price_dollars": "0.5500",
+            })
+        );
+
+        let json = order.post_only().to_wire_json();
+        assert!(json.contains(r#""post_only":true"#));
+        assert!(!json.contains("no_price"));
+        assert!(!json.contains("buy_max_cost"));
+    }
+
     #[test]
     fn test_create_market_order() {
         let order = CreateOrderRequest::market("KXBTC-25JAN", Side::No, Action::Sell, 5);
@@ -455,4 +1153,247 @@ mod tests {
         assert_eq!(order.time_in_force, Some(TimeInForce::GoodTillCanceled));
         assert_eq!(order.subaccount, Some(1));
     }
+
+    fn create_response(status: &str, initial: &str, filled: &str) -> CreateOrderResponse {
+        let mut order = order_json("o1", filled, "0.00", Some(initial));
+        order["type"] = "market".into();
+        order["status"] = status.into();
+        serde_json::from_value(serde_json::json!({ "order": order })).unwrap()
+    }
+
+    fn filled_order(maker: &str, taker: &str) -> Order {
+        let mut order = order_json("o1", "10.00", "0.00", Some("10.00"));
+        order["status"] = "executed".into();
+        order["maker_fill_count_fp"] = maker.into();
+        order["taker_fill_count_fp"] = taker.into();
+        serde_json::from_value(order).unwrap()
+    }
+
+    #[test]
+    fn test_maker_taker_summary() {
+        let maker_only = filled_order("10.00", "0.00");
+        assert!(maker_only.was_maker());
+        assert!(maker_only.is_fully_maker());
+        assert!((maker_only.maker_ratio() - 1.0).abs() < f64::EPSILON);
+
+        let taker_only = filled_order("0.00", "10.00");
+        assert!(!taker_only.was_maker());
+        assert!(!taker_only.is_fully_maker());
+        assert!(taker_only.maker_ratio().abs() < f64::EPSILON);
+
+        let mixed = filled_order("7.50", "2.50");
+        assert!(mixed.was_maker());
+        assert!(!mixed.is_fully_maker());
+        assert!((mixed.maker_ratio() - 0.75).abs() < f64::EPSILON);
+
+        // Without a split there is nothing to report
+        let mut unknown = mixed.clone();
+        unknown.maker_fill_count_fp = None;
+        unknown.taker_fill_count_fp = None;
+        assert_eq!(unknown.maker_taker_fill_counts(), None);
+        assert!(!unknown.was_maker());
+        assert!(unknown.maker_ratio().abs() < f64::EPSILON);
+
+        // One side is derived from the total fill count
+        unknown.taker_fill_count_fp = Some(250);
+        assert_eq!(unknown.maker_taker_fill_counts(), Some((750, 250)));
+    }
+
+    #[test]
+    fn test_fill_completeness() {
+        let filled = filled_order("10.00", "0.00");
+        assert!(filled.is_filled());
+        assert!(!filled.is_partially_filled());
+        assert!((filled.fill_ratio() - 1.0).abs() < f64::EPSILON);
+
+        // Resting with 4 of 10 filled
+        let mut resting = filled.clone();
+        resting.status = OrderStatus::Resting;
+        resting.fill_count_fp = 400;
+        resting.remaining_count_fp = 600;
+        assert!(!resting.is_filled());
+        assert!(resting.is_partially_filled());
+        assert!((resting.fill_ratio() - 0.4).abs() < f64::EPSILON);
+
+        // Amended down to 5: the live size wins over initial_count_fp
+        resting.remaining_count_fp = 100;
+        assert_eq!(resting.total_count_fp(), 500);
+        assert!((resting.fill_ratio() - 0.8).abs() < f64::EPSILON);
+
+        // Canceled after 4 fills: nothing remains but the order was for 10
+        let mut canceled = resting.clone();
+        canceled.status = OrderStatus::Canceled;
+        canceled.remaining_count_fp = 0;
+        assert!(canceled.is_partially_filled());
+        assert!((canceled.fill_ratio() - 0.4).abs() < f64::EPSILON);
+
+        // No initial count reported: fall back to fill + remaining
+        let mut unreported = resting.clone();
+        unreported.initial_count_fp = 0;
+        unreported.remaining_count_fp = 0;
+        assert!(unreported.is_filled());
+
+        let mut untouched = resting;
+        untouched.fill_count_fp = 0;
+        assert!(!untouched.is_filled());
+        assert!(!untouched.is_partially_filled());
+        assert!(untouched.fill_ratio().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_order_serde_round_trip() {
+        let order = filled_order("7.50", "2.50");
+
+        let json = serde_json::to_value(&order).unwrap();
+        assert_eq!(json["type"], "limit");
+        assert_eq!(json["yes_price_dollars"], "0.5000");
+        assert_eq!(json["fill_count_fp"], "10.00");
+        assert_eq!(json["maker_fill_count_fp"], "7.50");
+
+        let parsed: Order = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(parsed.order_type, OrderType::Limit);
+        assert_eq!(parsed.fill_count_fp, 1_000);
+        assert_eq!(parsed.maker_taker_fill_counts(), Some((750, 250)));
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+    }
+
+    #[test]
+    fn test_filled_before_cancel() {
+        let mut order = order_json("o1", "3.00", "0.00", Some("10.00"));
+        order["status"] = "canceled".into();
+        order["maker_fill_cost_dollars"] = "1.5000".into();
+        let response: CancelOrderResponse =
+            serde_json::from_value(serde_json::json!({ "order": order, "reduced_by_fp": "7.00" }))
+                .unwrap();
+        assert_eq!(response.order.status, OrderStatus::Canceled);
+        assert_eq!(response.filled_before_cancel(), 300);
+        assert_eq!(response.reduced_by_fp, 700);
+    }
+
+    #[test]
+    fn test_unfilled_count() {
+        let full = create_response("executed", "10.00", "10.00");
+        assert_eq!(full.order.fill_count_fp, 1_000);
+        assert_eq!(full.unfilled_count(), 0);
+
+        let partial = create_response("canceled", "10.00", "3.50");
+        assert_eq!(partial.unfilled_count(), 650);
+
+        let none = create_response("canceled", "10.00", "0.00");
+        assert_eq!(none.unfilled_count(), 1_000);
+    }
+
+    #[test]
+    fn test_post_only() {
+        let order = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000).post_only();
+        assert!(order.validate().is_ok());
+        let json = serde_json::to_value(&order).unwrap();
+        assert_eq!(json["post_only"], true);
+
+        let market = CreateOrderRequest::market("T", Side::Yes, Action::Buy, 1).post_only();
+        assert!(matches!(market.validate(), Err(Error::InvalidOrder(_))));
+
+        let ioc = order.with_time_in_force(TimeInForce::ImmediateOrCancel);
+        assert!(matches!(ioc.validate(), Err(Error::InvalidOrder(_))));
+    }
+
+    #[test]
+    fn test_validate_order() {
+        assert!(
+            CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000)
+                .validate()
+                .is_ok()
+        );
+        assert!(CreateOrderRequest::market("T", Side::Yes, Action::Buy, 1)
+            .validate()
+            .is_ok());
+
+        let zero_count = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 0, 5_000);
+        assert!(matches!(zero_count.validate(), Err(Error::InvalidOrder(_))));
+
+        let too_high = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, DOLLAR_SCALE);
+        assert!(matches!(too_high.validate(), Err(Error::InvalidOrder(_))));
+
+        let negative_count = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, -5, 5_000);
+        assert!(matches!(
+            negative_count.validate(),
+            Err(Error::InvalidOrder(_))
+        ));
+
+        let zero_price = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 0);
+        assert!(matches!(zero_price.validate(), Err(Error::InvalidOrder(_))));
+
+        let mut legacy_cents = CreateOrderRequest::market("T", Side::Yes, Action::Buy, 1);
+        legacy_cents.yes_price = Some(100);
+        assert!(matches!(
+            legacy_cents.validate(),
+            Err(Error::InvalidOrder(_))
+        ));
+
+        let mut both_sides = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000);
+        both_sides.no_price_dollars = Some(5_000);
+        match both_sides.validate() {
+            Err(Error::InvalidOrder(msg)) => {
+                assert!(msg.contains("yes_price_dollars and no_price_dollars"))
+            }
+            other => panic!("expected InvalidOrder, got {other:?}"),
+        }
+
+        let mut no_price = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000);
+        no_price.yes_price_dollars = None;
+        match no_price.validate() {
+            Err(Error::InvalidOrder(msg)) => assert!(msg.contains("got none")),
+            other => panic!("expected InvalidOrder, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_batch_reports_all_failures() {
+        let valid = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000);
+        let batch = BatchCreateOrdersRequest {
+            orders: vec![
+                valid.clone(),
+                CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 0, 5_000),
+                valid.clone(),
+                CreateOrderRequest::limit("T", Side::No, Action::Buy, 1, 0),
+            ],
+        };
+
+        let errors = batch.validate().unwrap_err();
+        let indices: Vec<_> = errors.iter().map(|(i, _)| *i).collect();
+        assert_eq!(indices, [1, 3]);
+
+        let oversized = BatchCreateOrdersRequest {
+            orders: vec![valid; BatchCreateOrdersRequest::MAX_BATCH_SIZE + 1],
+        };
+        let errors = oversized.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, BatchCreateOrdersRequest::MAX_BATCH_SIZE);
+    }
+
+    #[test]
+    fn test_batch_builder() {
+        let order = CreateOrderRequest::limit("T", Side::Yes, Action::Buy, 1, 5_000);
+
+        let batch = BatchCreateOrdersRequest::builder()
+            .add_order(order.clone())
+            .add_order(CreateOrderRequest::limit(
+                "T",
+                Side::No,
+                Action::Buy,
+                2,
+                4_000,
+            ))
+            .build()
+            .unwrap();
+        assert_eq!(batch.orders.len(), 2);
+        assert_eq!(batch.orders[1].side, Side::No);
+
+        let oversized = (0..=BatchCreateOrdersRequest::MAX_BATCH_SIZE)
+            .fold(BatchCreateOrdersRequest::builder(), |builder, _| {
+                builder.add_order(order.clone())
+            })
+            .build();
+        assert!(matches!(oversized, Err(Error::InvalidOrder(_))));
+    }
 }
@@ -0,0 +1,249 @@
+#![allow(missing_docs)]
+
+//! Request-for-quote (RFQ) types for block trading.
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{
+    deserialize_optional_count, deserialize_optional_dollars, serialize_optional_count,
+    serialize_optional_dollars, COUNT_SCALE,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RfqStatus {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum QuoteStatus {
+    Open,
+    Accepted,
+    Confirmed,
+    Executed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRfqRequest {
+    pub market_ticker: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts: Option<i64>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_count"
+    )]
+    pub contracts_fp: Option<i64>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_optional_dollars"
+    )]
+    pub target_cost_dollars: Option<i64>,
+    pub rest_remainder: bool,
+}
+
+impl CreateRfqRequest {
+    #[must_use]
+    pub fn new(market_ticker: impl Into<String>, contracts: i64) -> Self {
+        Self {
+            market_ticker: market_ticker.into(),
+            contracts: Some(contracts),
+            contracts_fp: Some(contracts * COUNT_SCALE),
+            target_cost_dollars: None,
+            rest_remainder: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_target_cost(mut self, cost_ten_thousandths: i64) -> Self {
+        self.target_cost_dollars = Some(cost_ten_thousandths);
+        self
+    }
+
+    #[must_use]
+    pub fn with_rest_remainder(mut self, rest_remainder: bool) -> Self {
+        self.rest_remainder = rest_remainder;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateRfqResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Rfq {
+    pub id: String,
+    pub market_ticker: String,
+    pub status: RfqStatus,
+    #[serde(default)]
+    pub creator_id: Option<String>,
+    #[serde(default)]
+    pub contracts: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    pub contracts_fp: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    pub target_cost_dollars: Option<i64>,
+    #[serde(default)]
+    pub rest_remainder: bool,
+    #[serde(default)]
+    pub created_ts: Option<String>,
+    #[serde(default)]
+    pub updated_ts: Option<String>,
+    #[serde(default)]
+    pub cancellation_reason: Option<String>,
+    #[serde(default)]
+    pub cancelled_ts: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct GetRfqsResponse {
+    pub rfqs: Vec<Rfq>,
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateQuoteRequest {
+    pub rfq_id: String,
+    #[serde(serialize_with = "serialize_optional_dollars")]
+    pub yes_bid: Option<i64>,
+    #[serde(serialize_with = "serialize_optional_dollars")]
+    pub no_bid: Option<i64>,
+    pub rest_remainder: bool,
+}
+
+impl CreateQuoteRequest {
+    /// Quote both sides; prices in ten-thousandths of a dollar.
+    #[must_use]
+    pub fn new(rfq_id: impl Into<String>, yes_bid: i64, no_bid: i64) -> Self {
+        Self {
+            rfq_id: rfq_id.into(),
+            yes_bid: Some(yes_bid),
+            no_bid: Some(no_bid),
+            rest_remainder: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_rest_remainder(mut self, rest_remainder: bool) -> Self {
+        self.rest_remainder = rest_remainder;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateQuoteResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Quote {
+    pub id: String,
+    pub rfq_id: String,
+    pub market_ticker: String,
+    pub status: QuoteStatus,
+    #[serde(default)]
+    pub creator_id: Option<String>,
+    #[serde(default)]
+    pub contracts: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_count")]
+    pub contracts_fp: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    pub yes_bid_dollars: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_dollars")]
+    pub no_bid_dollars: Option<i64>,
+    #[serde(default)]
+    pub accepted_side: Option<crate::types::order::Side>,
+    #[serde(default)]
+    pub created_ts: Option<String>,
+    #[serde(default)]
+    pub updated_ts: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_rfq_request_serialization() {
+        let request = CreateRfqRequest::new("KXBTC-25JAN", 500)
+            .with_target_cost(2_500_000)
+            .with_rest_remainder(true);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "market_ticker": "KXBTC-25JAN",
+                "contracts": 500,
+                "contracts_fp": "500.00",
+                "target_cost_dollars": "250.0000",
+                "rest_remainder": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_create_quote_request_serialization() {
+        let request = CreateQuoteRequest::new("rfq-1", 4_500, 5_200);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "rfq_id": "rfq-1",
+                "yes_bid": "0.4500",
+                "no_bid": "0.5200",
+                "rest_remainder": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_rfq_deserialization() {
+        let json = r#"{
+            "rfqs": [{
+                "id": "rfq-1",
+                "creator_id": "abc",
+                "market_ticker": "KXBTC-25JAN",
+                "contracts": 500,
+                "contracts_fp": "500.00",
+                "target_cost_dollars": "250.0000",
+                "status": "open",
+                "rest_remainder": false,
+                "created_ts": "2025-01-01T00:00:00Z"
+            }],
+            "cursor": ""
+        }"#;
+        let response: GetRfqsResponse = serde_json::from_str(json).unwrap();
+        let rfq = &response.rfqs[0];
+        assert_eq!(rfq.id, "rfq-1");
+        assert_eq!(rfq.status, RfqStatus::Open);
+        assert_eq!(rfq.contracts_fp, Some(50_000));
+        assert_eq!(rfq.target_cost_dollars, Some(2_500_000));
+
+        let created: CreateRfqResponse = serde_json::from_str(r#"{"id":"rfq-1"}"#).unwrap();
+        assert_eq!(created.id, "rfq-1");
+    }
+
+    #[test]
+    fn test_quote_deserialization() {
+        let json = r#"{
+            "id": "q-1",
+            "rfq_id": "rfq-1",
+            "market_ticker": "KXBTC-25JAN",
+            "yes_bid_dollars": "0.4500",
+            "no_bid_dollars": "0.5200",
+            "status": "accepted",
+            "accepted_side": "yes"
+        }"#;
+        let quote: Quote = serde_json::from_str(json).unwrap();
+        assert_eq!(quote.status, QuoteStatus::Accepted);
+        assert_eq!(quote.yes_bid_dollars, Some(4_500));
+        assert_eq!(quote.accepted_side, Some(crate::types::order::Side::Yes));
+    }
+}
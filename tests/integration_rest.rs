@@ -22,11 +22,7 @@ use kalshi_trading::{Config, KalshiClient};
 
 /// Helper to create a client from environment variables
 fn create_client() -> Option<KalshiClient> {
-    let api_key = std::env::var("KALSHI_API_KEY").ok()?;
-    let key_path = std::env::var("KALSHI_PRIVATE_KEY_PATH").ok()?;
-    let private_key = std::fs::read_to_string(&key_path).ok()?;
-
-    let config = Config::new(&api_key, &private_key).with_environment(Environment::Demo);
+    let config = Config::from_env().ok()?.with_environment(Environment::Demo);
 
     KalshiClient::new(config).ok()
 }
@@ -26,11 +26,7 @@ use tokio::time::timeout;
 
 /// Helper to create a config from environment variables
 fn create_config() -> Option<Config> {
-    let api_key = std::env::var("KALSHI_API_KEY").ok()?;
-    let key_path = std::env::var("KALSHI_PRIVATE_KEY_PATH").ok()?;
-    let private_key = std::fs::read_to_string(&key_path).ok()?;
-
-    Some(Config::new(&api_key, &private_key).with_environment(Environment::Demo))
+    Some(Config::from_env().ok()?.with_environment(Environment::Demo))
 }
 
 /// Skip test if credentials not available